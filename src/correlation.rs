@@ -1,7 +1,10 @@
-use crate::errors::EmptyInput;
+use crate::errors::{EmptyInput, MultiInputError};
+use crate::ewm::{ewm_last, Ewm, EwmState};
+use crate::maybe_nan::MaybeNan;
 use ndarray::prelude::*;
-use ndarray::Data;
-use num_traits::{Float, FromPrimitive};
+use ndarray::{Data, Zip};
+use num_traits::{Float, FromPrimitive, One, ToPrimitive, Zero};
+use std::ops::{Add, Div, Mul, Sub};
 
 /// Extension trait for `ArrayBase` providing functions
 /// to compute different correlation measures.
@@ -15,7 +18,7 @@ where
     /// Let `(r, o)` be the shape of `M`:
     /// - `r` is the number of random variables;
     /// - `o` is the number of observations we have collected
-    /// for each random variable.
+    ///   for each random variable.
     ///
     /// Every column in `M` is an experiment: a single observation for each
     /// random variable.
@@ -71,7 +74,7 @@ where
     /// Let `(r, o)` be the shape of `M`:
     /// - `r` is the number of random variables;
     /// - `o` is the number of observations we have collected
-    /// for each random variable.
+    ///   for each random variable.
     ///
     /// Every column in `M` is an experiment: a single observation for each
     /// random variable.
@@ -122,6 +125,156 @@ where
     where
         A: Float + FromPrimitive;
 
+    /// Return the exponentially weighted covariance matrix `C` for a
+    /// 2-dimensional array of observations `M`, weighting the most recent
+    /// observations (the last columns of `M`) more, as described in [`Ewm`].
+    ///
+    /// `C_ij` is the last element of the exponentially weighted moving
+    /// covariance between the rows `i` and `j` of `M` (see [`EwmExt::ewm_cov`]):
+    /// NaN values are skipped pairwise, and `C_ij` is NaN if the two rows have
+    /// fewer than `min_periods` observations in common.
+    ///
+    /// If `bias` is `false`, the covariances are corrected for the bias of
+    /// the weighted estimator.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if `A::NotNan::from_f64()` fails to convert the smoothing
+    /// factor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::arr2;
+    /// use ndarray_stats::{CorrelationExt, Ewm};
+    ///
+    /// let a = arr2(&[[1., 3., 2.],
+    ///                [2., 6., 4.]]);
+    /// let covariance = a.ewm_cov_matrix(&Ewm::with_alpha(0.5), true).unwrap();
+    /// // The weights of the observations are 1/7, 2/7 and 4/7.
+    /// assert_abs_diff_eq!(
+    ///     covariance,
+    ///     arr2(&[[20. / 49., 40. / 49.], [40. / 49., 80. / 49.]]),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    ///
+    /// [`Ewm`]: struct.Ewm.html
+    /// [`EwmExt::ewm_cov`]: trait.EwmExt.html#tymethod.ewm_cov
+    fn ewm_cov_matrix(&self, ewm: &Ewm, bias: bool) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>;
+
+    /// Return the exponentially weighted Pearson correlation coefficients for
+    /// a 2-dimensional array of observations `M`, weighting the most recent
+    /// observations (the last columns of `M`) more, as described in [`Ewm`].
+    ///
+    /// `R_ij` is the last element of the exponentially weighted moving
+    /// correlation between the rows `i` and `j` of `M` (see [`EwmExt::ewm_corr`]),
+    /// which is NaN if one of the rows is constant over their common observations.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the conversions of the smoothing factor and of the
+    /// products of the variances between `f64` and `A::NotNan` fail.
+    ///
+    /// [`Ewm`]: struct.Ewm.html
+    /// [`EwmExt::ewm_corr`]: trait.EwmExt.html#tymethod.ewm_corr
+    fn ewm_pearson_correlation(&self, ewm: &Ewm) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>;
+
+    /// Return the covariance matrix `C` for a 2-dimensional array of
+    /// observations `M`, ignoring the observations that are masked.
+    ///
+    /// An observation `M_ik` is masked if `mask_ik` is `true`. Masked
+    /// observations are ignored pairwise: `C_ij` is the covariance (see
+    /// [`cov`]) of the rows `i` and `j` of `M` over the `n_ij` columns where
+    /// neither of them is masked, with `n_ij - ddof` degrees of freedom. If
+    /// `n_ij - ddof` is not positive, `C_ij` is NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `M` is empty (either zero observations or zero random variables)
+    /// * `MultiInputError::ShapeMismatch` if `M` and `mask` don't have the same shape
+    ///
+    /// **Panics** if the type cast of `n_ij` from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = arr2(&[[1., 3., 5., 0.],
+    ///                [2., -1., 6., 4.]]);
+    /// let mask = arr2(&[[false, false, false, true],
+    ///                   [false, true, false, false]]);
+    /// let covariance = a.masked_cov(&mask, 1.).unwrap();
+    /// assert_eq!(
+    ///     covariance,
+    ///     arr2(&[[4., 8.], [8., 4.]])
+    /// );
+    /// ```
+    ///
+    /// [`cov`]: #tymethod.cov
+    fn masked_cov<S2>(
+        &self,
+        mask: &ArrayBase<S2, Ix2>,
+        ddof: A,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>;
+
+    /// Return the Pearson correlation coefficients for a 2-dimensional array
+    /// of observations `M`, ignoring the observations that are masked.
+    ///
+    /// As in [`masked_cov`], masked observations are ignored pairwise: `R_ij`
+    /// is the correlation (see [`pearson_correlation`]) of the rows `i` and
+    /// `j` of `M` over the columns where neither of them is masked. `R_ij` is
+    /// NaN if there are no such columns or if one of the rows is constant
+    /// over them.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `M` is empty (either zero observations or zero random variables)
+    /// * `MultiInputError::ShapeMismatch` if `M` and `mask` don't have the same shape
+    ///
+    /// **Panics** if the type cast of the number of observations from `usize`
+    /// to `A` fails.
+    ///
+    /// [`masked_cov`]: #tymethod.masked_cov
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    fn masked_pearson_correlation<S2>(
+        &self,
+        mask: &ArrayBase<S2, Ix2>,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>;
+
     private_decl! {}
 }
 
@@ -178,9 +331,195 @@ where
         }
     }
 
+    fn ewm_cov_matrix(&self, ewm: &Ewm, bias: bool) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+    {
+        ewm_matrix(self, ewm, |state| state.cov(bias))
+    }
+
+    fn ewm_pearson_correlation(&self, ewm: &Ewm) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+    {
+        ewm_matrix(self, ewm, EwmState::corr)
+    }
+
+    fn masked_cov<S2>(
+        &self,
+        mask: &ArrayBase<S2, Ix2>,
+        ddof: A,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+    {
+        masked_matrix(self, mask, |comoments| {
+            let dof = A::from_usize(comoments.n).unwrap() - ddof;
+            if dof > A::zero() {
+                comoments.cxy / dof
+            } else {
+                A::nan()
+            }
+        })
+    }
+
+    fn masked_pearson_correlation<S2>(
+        &self,
+        mask: &ArrayBase<S2, Ix2>,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+    {
+        masked_matrix(self, mask, |comoments| {
+            comoments.cxy / (comoments.cxx * comoments.cyy).sqrt()
+        })
+    }
+
     private_impl! {}
 }
 
+/// Returns the symmetric matrix of `statistic` of the exponentially weighted
+/// states of each pair of rows of `observations`.
+fn ewm_matrix<A, S, F>(
+    observations: &ArrayBase<S, Ix2>,
+    ewm: &Ewm,
+    statistic: F,
+) -> Result<Array2<A>, EmptyInput>
+where
+    A: MaybeNan,
+    A::NotNan: Clone
+        + PartialOrd
+        + FromPrimitive
+        + Zero
+        + One
+        + Add<Output = A::NotNan>
+        + Sub<Output = A::NotNan>
+        + Mul<Output = A::NotNan>
+        + Div<Output = A::NotNan>,
+    S: Data<Elem = A>,
+    F: Fn(&EwmState<A::NotNan>) -> Option<A::NotNan>,
+{
+    if observations.is_empty() {
+        return Err(EmptyInput);
+    }
+    let n_random_variables = observations.nrows();
+    let mut matrix = Array2::from_shape_simple_fn((n_random_variables, n_random_variables), || {
+        A::from_not_nan_opt(None)
+    });
+    for i in 0..n_random_variables {
+        for j in 0..=i {
+            let value = ewm_last(observations.row(i), observations.row(j), ewm, &statistic);
+            matrix[(i, j)] = A::from_not_nan_opt(value.clone());
+            matrix[(j, i)] = A::from_not_nan_opt(value);
+        }
+    }
+    Ok(matrix)
+}
+
+/// The number of pairs of observations of two random variables and the sums
+/// of the products of their deviations from the means.
+struct Comoments<A> {
+    n: usize,
+    cxx: A,
+    cyy: A,
+    cxy: A,
+}
+
+impl<A> Comoments<A>
+where
+    A: Float + FromPrimitive,
+{
+    /// Computes the comoments of the pairs of observations of `x` and `y`
+    /// where neither is masked, with a two-pass algorithm.
+    fn from_masked(
+        x: ArrayView1<'_, A>,
+        y: ArrayView1<'_, A>,
+        x_mask: ArrayView1<'_, bool>,
+        y_mask: ArrayView1<'_, bool>,
+    ) -> Self {
+        let pairs = Zip::from(&x).and(&y).and(&x_mask).and(&y_mask).fold(
+            Vec::new(),
+            |mut pairs, &x, &y, &x_masked, &y_masked| {
+                if !x_masked && !y_masked {
+                    pairs.push((x, y));
+                }
+                pairs
+            },
+        );
+        let n = pairs.len();
+        let (mut cxx, mut cyy, mut cxy) = (A::zero(), A::zero(), A::zero());
+        if n > 0 {
+            let n_a = A::from_usize(n).unwrap();
+            let (sum_x, sum_y) = pairs
+                .iter()
+                .fold((A::zero(), A::zero()), |(sx, sy), &(x, y)| (sx + x, sy + y));
+            let (mean_x, mean_y) = (sum_x / n_a, sum_y / n_a);
+            for &(x, y) in &pairs {
+                let (dx, dy) = (x - mean_x, y - mean_y);
+                cxx = cxx + dx * dx;
+                cyy = cyy + dy * dy;
+                cxy = cxy + dx * dy;
+            }
+        }
+        Comoments { n, cxx, cyy, cxy }
+    }
+}
+
+/// Returns the symmetric matrix of `statistic` of the comoments of the
+/// unmasked observations of each pair of rows of `observations`.
+fn masked_matrix<A, S, S2, F>(
+    observations: &ArrayBase<S, Ix2>,
+    mask: &ArrayBase<S2, Ix2>,
+    statistic: F,
+) -> Result<Array2<A>, MultiInputError>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    S2: Data<Elem = bool>,
+    F: Fn(&Comoments<A>) -> A,
+{
+    return_err_if_empty!(observations);
+    return_err_unless_same_shape!(observations, mask);
+    let n_random_variables = observations.nrows();
+    let mut matrix = Array2::zeros((n_random_variables, n_random_variables));
+    for i in 0..n_random_variables {
+        for j in 0..=i {
+            let comoments = Comoments::from_masked(
+                observations.row(i),
+                observations.row(j),
+                mask.row(i),
+                mask.row(j),
+            );
+            let value = statistic(&comoments);
+            matrix[(i, j)] = value;
+            matrix[(j, i)] = value;
+        }
+    }
+    Ok(matrix)
+}
+
 #[cfg(test)]
 mod cov_tests {
     use super::*;
@@ -367,3 +706,70 @@ mod pearson_correlation_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod masked_tests {
+    use super::*;
+    use crate::errors::ShapeMismatch;
+    use ndarray::array;
+    use ndarray::Array;
+    use ndarray_rand::rand_distr::Uniform;
+    use ndarray_rand::RandomExt;
+
+    #[test]
+    fn test_masked_with_empty_or_mismatched_mask() {
+        let a = Array2::<f64>::zeros((2, 0));
+        assert_eq!(
+            a.masked_cov(&a.mapv(|_| false), 1.),
+            Err(MultiInputError::EmptyInput)
+        );
+        let a = Array2::<f64>::zeros((2, 3));
+        assert_eq!(
+            a.masked_pearson_correlation(&Array2::from_elem((2, 2), false)),
+            Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: vec![2, 3],
+                second_shape: vec![2, 2],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_masked_without_masked_observations() {
+        let a = Array::random((3, 10), Uniform::new(-1., 1.));
+        let mask = a.mapv(|_| false);
+        assert_abs_diff_eq!(
+            a.masked_cov(&mask, 1.).unwrap(),
+            a.cov(1.).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.masked_pearson_correlation(&mask).unwrap(),
+            a.pearson_correlation().unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_masked_observations_are_ignored_pairwise() {
+        let a = array![[1., 3., 5., 100.], [2., -50., 6., 4.], [0., 1., 0., 1.]];
+        let mask = array![
+            [false, false, false, true],
+            [false, true, false, false],
+            [true, true, true, true]
+        ];
+        let cov = a.masked_cov(&mask, 1.).unwrap();
+        assert_abs_diff_eq!(cov[(0, 0)], array![1., 3., 5.].var(1.));
+        assert_abs_diff_eq!(cov[(1, 1)], array![2., 6., 4.].var(1.));
+        assert_eq!(cov[(0, 1)], cov[(1, 0)]);
+        assert_abs_diff_eq!(
+            cov[(0, 1)],
+            array![[1., 5.], [2., 6.]].cov(1.).unwrap()[(0, 1)]
+        );
+        assert!(cov.row(2).iter().all(|x| x.is_nan()));
+
+        let corr = a.masked_pearson_correlation(&mask).unwrap();
+        assert_abs_diff_eq!(corr[(0, 1)], 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(corr[(0, 0)], 1., epsilon = 1e-12);
+        assert!(corr.column(2).iter().all(|x| x.is_nan()));
+    }
+}
@@ -0,0 +1,268 @@
+use crate::errors::EmptyInput;
+use crate::maybe_nan::MaybeNan;
+use crate::quantile::interpolate::{Interpolate, Linear};
+use crate::quantile::QuantileExt;
+use crate::summary_statistics::MomentsAccumulator;
+use ndarray::{aview1, Array, Array1, ArrayBase, Axis, Data, Dimension, RemoveAxis};
+use noisy_float::types::n64;
+use num_traits::{Float, FromPrimitive};
+use std::fmt;
+
+/// A summary of the distribution of the elements of an array, as returned by
+/// [`describe`] and [`describe_axis`].
+///
+/// NaN values are counted in `nan_count` and otherwise ignored: all the other
+/// statistics are computed over the `count` non-NaN values. If there are no
+/// such values, they are all NaN.
+///
+/// The `Display` implementation renders the summary as a two-column table,
+/// honoring the precision of the formatter (e.g. `{:.3}`).
+///
+/// [`describe`]: trait.DescribeExt.html#tymethod.describe
+/// [`describe_axis`]: trait.DescribeExt.html#tymethod.describe_axis
+#[derive(Clone, Debug, PartialEq)]
+pub struct Description<A> {
+    /// The number of non-NaN values.
+    pub count: usize,
+    /// The number of NaN values.
+    pub nan_count: usize,
+    /// The arithmetic mean.
+    pub mean: A,
+    /// The sample standard deviation (with one degree of freedom removed,
+    /// so it is NaN if `count` is 1).
+    pub std: A,
+    /// The minimum.
+    pub min: A,
+    /// The 0.25-quantile.
+    pub lower_quartile: A,
+    /// The median (0.5-quantile).
+    pub median: A,
+    /// The 0.75-quantile.
+    pub upper_quartile: A,
+    /// The maximum.
+    pub max: A,
+    /// The (population) skewness, see [`skewness`].
+    ///
+    /// [`skewness`]: trait.SummaryStatisticsExt.html#tymethod.skewness
+    pub skewness: A,
+    /// The (population, non-excess) kurtosis, see [`kurtosis`].
+    ///
+    /// [`kurtosis`]: trait.SummaryStatisticsExt.html#tymethod.kurtosis
+    pub kurtosis: A,
+}
+
+impl<A> fmt::Display for Description<A>
+where
+    A: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format_value = |value: &A| match f.precision() {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => format!("{}", value),
+        };
+        let rows = [
+            ("count", self.count.to_string()),
+            ("NaN count", self.nan_count.to_string()),
+            ("mean", format_value(&self.mean)),
+            ("std", format_value(&self.std)),
+            ("min", format_value(&self.min)),
+            ("25%", format_value(&self.lower_quartile)),
+            ("50%", format_value(&self.median)),
+            ("75%", format_value(&self.upper_quartile)),
+            ("max", format_value(&self.max)),
+            ("skewness", format_value(&self.skewness)),
+            ("kurtosis", format_value(&self.kurtosis)),
+        ];
+        let label_width = rows.iter().map(|(label, _)| label.len()).max().unwrap();
+        let value_width = rows
+            .iter()
+            .map(|(_, value)| value.chars().count())
+            .max()
+            .unwrap();
+        for (i, (label, value)) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{:<label_width$}  {:>value_width$}",
+                label,
+                value,
+                label_width = label_width,
+                value_width = value_width,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Extension trait for `ArrayBase` providing a one-shot descriptive summary
+/// of floating point arrays.
+pub trait DescribeExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns a [`Description`] of all the elements in the array: count,
+    /// NaN count, mean, standard deviation, minimum, quartiles, maximum,
+    /// skewness and kurtosis.
+    ///
+    /// The moments are accumulated in a single pass over the array, which
+    /// also collects the non-NaN values; the minimum, the quartiles and the
+    /// maximum are then retrieved at once with [`quantiles_axis_mut`] (using
+    /// linear interpolation) on this copy, so `self` is not modified.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::DescribeExt;
+    ///
+    /// let a = array![4., f64::NAN, 1., 3., 2.];
+    /// let description = a.describe().unwrap();
+    /// assert_eq!(description.count, 4);
+    /// assert_eq!(description.nan_count, 1);
+    /// assert_eq!(description.mean, 2.5);
+    /// assert_eq!(description.median, 2.5);
+    /// assert_eq!(description.max, 4.);
+    /// println!("{:.3}", description);
+    /// ```
+    ///
+    /// [`Description`]: struct.Description.html
+    /// [`quantiles_axis_mut`]: trait.QuantileExt.html#tymethod.quantiles_axis_mut
+    fn describe(&self) -> Result<Description<A>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        A::NotNan: Clone + Ord,
+        Linear: Interpolate<A::NotNan>;
+
+    /// Returns a [`Description`] of each 1-dimensional lane along `axis`,
+    /// see [`describe`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::DescribeExt;
+    ///
+    /// let a = array![[1., 10.], [2., 20.], [3., f64::NAN]];
+    /// let descriptions = a.describe_axis(Axis(0)).unwrap();
+    /// assert_eq!(descriptions[0].mean, 2.);
+    /// assert_eq!(descriptions[1].mean, 15.);
+    /// assert_eq!(descriptions[1].nan_count, 1);
+    /// ```
+    ///
+    /// [`Description`]: struct.Description.html
+    /// [`describe`]: #tymethod.describe
+    fn describe_axis(&self, axis: Axis) -> Result<Array<Description<A>, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        A::NotNan: Clone + Ord,
+        D: RemoveAxis,
+        Linear: Interpolate<A::NotNan>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> DescribeExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn describe(&self) -> Result<Description<A>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        A::NotNan: Clone + Ord,
+        Linear: Interpolate<A::NotNan>,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(describe_values(self.iter()))
+    }
+
+    fn describe_axis(&self, axis: Axis) -> Result<Array<Description<A>, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        A::NotNan: Clone + Ord,
+        D: RemoveAxis,
+        Linear: Interpolate<A::NotNan>,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| describe_values(lane.iter())))
+    }
+
+    private_impl! {}
+}
+
+/// Returns the description of `values`.
+fn describe_values<'a, A, I>(values: I) -> Description<A>
+where
+    A: 'a + MaybeNan + Float + FromPrimitive,
+    A::NotNan: Clone + Ord,
+    I: Iterator<Item = &'a A>,
+    Linear: Interpolate<A::NotNan>,
+{
+    let mut moments = MomentsAccumulator::new(4);
+    let mut not_nan = Vec::new();
+    let mut nan_count = 0;
+    for x in values {
+        match x.try_as_not_nan() {
+            Some(not_nan_x) => {
+                moments.push(*x);
+                not_nan.push(not_nan_x.clone());
+            }
+            None => nan_count += 1,
+        }
+    }
+    if moments.is_empty() {
+        return Description {
+            count: 0,
+            nan_count,
+            mean: A::nan(),
+            std: A::nan(),
+            min: A::nan(),
+            lower_quartile: A::nan(),
+            median: A::nan(),
+            upper_quartile: A::nan(),
+            max: A::nan(),
+            skewness: A::nan(),
+            kurtosis: A::nan(),
+        };
+    }
+
+    let count = moments.count();
+    let n = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+    let variance = moments.central_moment(2).unwrap() * n / (n - A::one());
+    let qs = [n64(0.), n64(0.25), n64(0.5), n64(0.75), n64(1.)];
+    let quantiles: Vec<A> = Array1::from(not_nan)
+        .quantiles_axis_mut(Axis(0), &aview1(&qs), &Linear)
+        .expect("The quantiles of a non-empty array are valid.")
+        .into_iter()
+        .map(A::from_not_nan)
+        .collect();
+    Description {
+        count,
+        nan_count,
+        mean: moments.mean().unwrap(),
+        std: variance.sqrt(),
+        min: quantiles[0],
+        lower_quartile: quantiles[1],
+        median: quantiles[2],
+        upper_quartile: quantiles[3],
+        max: quantiles[4],
+        skewness: moments.skewness().unwrap(),
+        kurtosis: moments.kurtosis().unwrap(),
+    }
+}
@@ -4,6 +4,7 @@ use std::convert::Into;
 use std::ops::AddAssign;
 
 use crate::errors::MultiInputError;
+use crate::summation::Summation;
 
 /// An extension trait for `ArrayBase` providing functions
 /// to compute different deviation measures.
@@ -57,6 +58,27 @@ where
         A: AddAssign + Clone + Signed,
         T: Data<Elem = A>;
 
+    /// Computes the [squared L2 distance] between `self` and `other`, accumulating
+    /// the sum with the given [`summation`] strategy. See [`sq_l2_dist`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// [squared L2 distance]: https://en.wikipedia.org/wiki/Euclidean_distance#Squared_Euclidean_distance
+    /// [`summation`]: summation/index.html
+    /// [`sq_l2_dist`]: #tymethod.sq_l2_dist
+    fn sq_l2_dist_with<T, M>(
+        &self,
+        other: &ArrayBase<T, D>,
+        summation: &M,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Clone + Signed,
+        T: Data<Elem = A>,
+        M: Summation<A>;
+
     /// Computes the [L2 distance] between `self` and `other`.
     ///
     /// ```text
@@ -214,6 +236,140 @@ where
         A: AddAssign + Clone + Signed + ToPrimitive,
         T: Data<Elem = A>;
 
+    /// Computes the L1 distance between `self` and `other` over the indices
+    /// that are not masked, i.e. whose entry in `mask` is `false`.
+    ///
+    /// ```text
+    ///  ∑  |aᵢ - bᵢ|
+    /// i∉M
+    /// ```
+    ///
+    /// where `self` is `a`, `other` is `b` and `M` is the set of masked
+    /// indices. If all the indices are masked, the distance is zero.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self`, `other` and `mask` don't have the same shape
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::DeviationExt;
+    ///
+    /// let a = array![1., 2., -999., 4.];
+    /// let b = array![2., 4., 3., 1.];
+    /// let mask = a.mapv(|x| x == -999.);
+    /// assert_eq!(a.masked_l1_dist(&b, &mask), Ok(6.));
+    /// assert_eq!(a.masked_sq_l2_dist(&b, &mask), Ok(14.));
+    /// assert_eq!(a.masked_mean_abs_err(&b, &mask), Ok(2.));
+    /// ```
+    fn masked_l1_dist<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>;
+
+    /// Computes the squared L2 distance between `self` and `other` over the
+    /// indices that are not masked, i.e. whose entry in `mask` is `false`.
+    ///
+    /// ```text
+    ///  ∑  |aᵢ - bᵢ|²
+    /// i∉M
+    /// ```
+    ///
+    /// where `self` is `a`, `other` is `b` and `M` is the set of masked
+    /// indices. If all the indices are masked, the distance is zero.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self`, `other` and `mask` don't have the same shape
+    fn masked_sq_l2_dist<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>;
+
+    /// Computes the mean absolute error between `self` and `other` over the
+    /// indices that are not masked, i.e. the [`masked_l1_dist`] divided by
+    /// the number of unmasked indices.
+    ///
+    /// If all the indices are masked, the result is NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self`, `other` and `mask` don't have the same shape
+    ///
+    /// **Panics** if the type cast from `A` to `f64` fails.
+    ///
+    /// [`masked_l1_dist`]: #tymethod.masked_l1_dist
+    fn masked_mean_abs_err<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>;
+
+    /// Computes the mean squared error between `self` and `other` over the
+    /// indices that are not masked, i.e. the [`masked_sq_l2_dist`] divided by
+    /// the number of unmasked indices.
+    ///
+    /// If all the indices are masked, the result is NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self`, `other` and `mask` don't have the same shape
+    ///
+    /// **Panics** if the type cast from `A` to `f64` fails.
+    ///
+    /// [`masked_sq_l2_dist`]: #tymethod.masked_sq_l2_dist
+    fn masked_mean_sq_err<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>;
+
+    /// Computes the root-mean-square error between `self` and `other` over
+    /// the indices that are not masked, i.e. the square root of
+    /// [`masked_mean_sq_err`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self`, `other` and `mask` don't have the same shape
+    ///
+    /// **Panics** if the type cast from `A` to `f64` fails.
+    ///
+    /// [`masked_mean_sq_err`]: #tymethod.masked_mean_sq_err
+    fn masked_root_mean_sq_err<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>;
+
     private_decl! {}
 }
 
@@ -268,6 +424,25 @@ where
         Ok(result)
     }
 
+    fn sq_l2_dist_with<T, M>(
+        &self,
+        other: &ArrayBase<T, D>,
+        _summation: &M,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Clone + Signed,
+        T: Data<Elem = A>,
+        M: Summation<A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        Ok(M::sum(self.iter().zip(other).map(|(a, b)| {
+            let diff = a.clone() - b.clone();
+            diff.clone() * diff
+        })))
+    }
+
     fn l2_dist<T>(&self, other: &ArrayBase<T, D>) -> Result<f64, MultiInputError>
     where
         A: AddAssign + Clone + Signed + ToPrimitive,
@@ -367,11 +542,117 @@ where
         T: Data<Elem = A>,
     {
         let maxv_f = maxv.to_f64().expect("failed cast from type A to f64");
-        let msd = self.mean_sq_err(&other)?;
+        let msd = self.mean_sq_err(other)?;
         let psnr = 10. * f64::log10(maxv_f * maxv_f / msd);
 
         Ok(psnr)
     }
 
+    fn masked_l1_dist<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>,
+    {
+        masked_sum(self, other, mask, |diff| diff.abs()).map(|(sum, _)| sum)
+    }
+
+    fn masked_sq_l2_dist<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>,
+    {
+        masked_sum(self, other, mask, |diff| diff.clone() * diff).map(|(sum, _)| sum)
+    }
+
+    fn masked_mean_abs_err<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>,
+    {
+        let (l1_dist, n) = masked_sum(self, other, mask, |diff| diff.abs())?;
+        let l1_dist = l1_dist.to_f64().expect("failed cast from type A to f64");
+
+        Ok(l1_dist / n as f64)
+    }
+
+    fn masked_mean_sq_err<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>,
+    {
+        let (sq_l2_dist, n) = masked_sum(self, other, mask, |diff| diff.clone() * diff)?;
+        let sq_l2_dist = sq_l2_dist.to_f64().expect("failed cast from type A to f64");
+
+        Ok(sq_l2_dist / n as f64)
+    }
+
+    fn masked_root_mean_sq_err<T, S2>(
+        &self,
+        other: &ArrayBase<T, D>,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        S2: Data<Elem = bool>,
+    {
+        let msd = self.masked_mean_sq_err(other, mask)?;
+        Ok(msd.sqrt())
+    }
+
     private_impl! {}
 }
+
+/// Returns the sum of `f` applied to the differences between the elements of
+/// `a` and `b` whose entry in `mask` is `false`, and the number of such
+/// elements.
+fn masked_sum<A, S, T, S2, D, F>(
+    a: &ArrayBase<S, D>,
+    b: &ArrayBase<T, D>,
+    mask: &ArrayBase<S2, D>,
+    f: F,
+) -> Result<(A, usize), MultiInputError>
+where
+    A: AddAssign + Clone + Signed,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    S2: Data<Elem = bool>,
+    D: Dimension,
+    F: Fn(A) -> A,
+{
+    return_err_if_empty!(a);
+    return_err_unless_same_shape!(a, b);
+    return_err_unless_same_shape!(a, mask);
+
+    let mut result = A::zero();
+    let mut n = 0;
+
+    Zip::from(a).and(b).and(mask).for_each(|a_i, b_i, &masked| {
+        if !masked {
+            result += f(a_i.clone() - b_i.clone());
+            n += 1;
+        }
+    });
+
+    Ok((result, n))
+}
@@ -1,11 +1,140 @@
 //! Information theory (e.g. entropy, KL divergence, etc.).
 use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
-use ndarray::{Array, ArrayBase, Data, Dimension, Zip};
-use num_traits::Float;
+use crate::special::{digamma, ln_gamma, trigamma};
+use crate::FrequencyExt;
+use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, Ix2, RemoveAxis, Zip};
+use num_traits::{Float, ToPrimitive};
+use std::hash::Hash;
+
+/// Normalizations of the mutual information *I(X;Y)* by the entropies of
+/// *X* and *Y*, selectable in
+/// [`normalized_mutual_information`](trait.JointEntropyExt.html#tymethod.normalized_mutual_information).
+///
+/// All of them bound the normalized mutual information between 0 and 1.
+/// The first four match the `average_method`s of scikit-learn's
+/// [`normalized_mutual_info_score`].
+///
+/// [`normalized_mutual_info_score`]: https://scikit-learn.org/stable/modules/generated/sklearn.metrics.normalized_mutual_info_score.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MutualInformationNormalization {
+    /// `I(X;Y) / min(H(X), H(Y))`
+    Min,
+    /// `I(X;Y) / √(H(X) H(Y))`
+    Geometric,
+    /// `I(X;Y) / ((H(X) + H(Y)) / 2)`, also known as the V-measure.
+    Arithmetic,
+    /// `I(X;Y) / max(H(X), H(Y))`
+    Max,
+    /// `I(X;Y) / H(X,Y)`
+    Joint,
+}
+
+/// The base of the logarithm, i.e. the unit of information, in which to
+/// express the results of the entropy-family methods of [`EntropyExt`].
+///
+/// These methods use the natural logarithm, so their results are in nats:
+/// [`convert_nats`] converts them to another base, dividing them by
+/// `ln(base)`. This applies uniformly to every quantity defined with a
+/// logarithm (entropies, divergences, mutual information, …), while the
+/// normalized mutual information doesn't depend on the base.
+///
+/// # Example
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use ndarray_stats::{EntropyExt, LogBase};
+///
+/// let p = array![0.5, 0.25, 0.25];
+/// let nats = p.entropy().unwrap();
+/// assert_abs_diff_eq!(LogBase::Two.convert_nats(nats), 1.5);
+/// assert_abs_diff_eq!(LogBase::Custom(4.).convert_nats(nats), 0.75);
+/// ```
+///
+/// [`EntropyExt`]: trait.EntropyExt.html
+/// [`convert_nats`]: #method.convert_nats
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogBase {
+    /// The natural logarithm, measuring information in nats.
+    E,
+    /// The binary logarithm, measuring information in bits (shannons).
+    Two,
+    /// The decimal logarithm, measuring information in dits (hartleys).
+    Ten,
+    /// An arbitrary base, which must be positive and different from one.
+    Custom(f64),
+}
+
+impl LogBase {
+    /// Converts a quantity of information from nats to the unit of this base.
+    ///
+    /// **Panics** if a custom base is not positive or is one, or if
+    /// `A::from()` fails to convert it.
+    pub fn convert_nats<A: Float>(self, nats: A) -> A {
+        let base = match self {
+            LogBase::E => return nats,
+            LogBase::Two => 2.,
+            LogBase::Ten => 10.,
+            LogBase::Custom(base) => {
+                assert!(
+                    base > 0. && base != 1.,
+                    "The base of the logarithm must be positive and different from one."
+                );
+                base
+            }
+        };
+        nats / A::from(base)
+            .expect("Converting the base to `A` must not fail.")
+            .ln()
+    }
+}
+
+/// Estimators of the entropy of a categorical distribution from the counts
+/// of its observed values, selectable in
+/// [`entropy_from_counts`](trait.EntropyExt.html#tymethod.entropy_from_counts).
+///
+/// With *N* observations, *nᵢ* of them in the `i`-th of *K* categories
+/// (or bins), the plug-in estimate is biased downwards for small samples,
+/// as unobserved categories don't contribute to it. The other estimators
+/// correct this bias.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum EntropyEstimator {
+    /// The plug-in (maximum likelihood) estimator, i.e. the entropy of the
+    /// observed proportions *nᵢ/N*.
+    PlugIn,
+    /// The [Miller–Madow] estimator, which adds *(K' - 1) / 2N* to the
+    /// plug-in estimate, where *K'* is the number of observed categories.
+    ///
+    /// [Miller–Madow]: https://doi.org/10.1162/089976603321780272
+    MillerMadow,
+    /// The [Chao–Shen] estimator, which weights the coverage-adjusted
+    /// proportions by the inverse of their probability of being observed
+    /// (Horvitz–Thompson), the coverage being estimated from the number of
+    /// singletons.
+    ///
+    /// [Chao–Shen]: https://doi.org/10.1023/A:1026096204727
+    ChaoShen,
+    /// A lightweight variant of the [NSB] (Nemenman–Shafee–Bialek)
+    /// estimator: the posterior mean of the entropy under a mixture of
+    /// symmetric Dirichlet priors over the *K* categories, chosen to make the
+    /// prior on the entropy nearly uniform. The mixture is integrated over a
+    /// fixed logarithmic grid of concentrations, and the posterior variance
+    /// isn't computed.
+    ///
+    /// Unlike the other estimators, it depends on the number of categories
+    /// *K* including the unobserved ones.
+    ///
+    /// [NSB]: https://arxiv.org/abs/physics/0108025
+    Nsb,
+}
 
 /// Extension trait for `ArrayBase` providing methods
 /// to compute information theory quantities
 /// (e.g. entropy, Kullback–Leibler divergence, etc.).
+///
+/// The quantities are in nats, see [`LogBase::convert_nats`] for other units.
+///
+/// [`LogBase::convert_nats`]: enum.LogBase.html#method.convert_nats
 pub trait EntropyExt<A, S, D>
 where
     S: Data<Elem = A>,
@@ -70,160 +199,1167 @@ where
     /// computing the entropy to avoid introducing potentially
     /// unnecessary numerical errors (e.g. if the array were to be already normalised).
     ///
-    /// By definition, *pᵢ ln(qᵢ/pᵢ)* is set to 0 if *pᵢ* is 0.
+    /// By definition, *pᵢ ln(qᵢ/pᵢ)* is set to 0 if *pᵢ* is 0.
+    ///
+    /// [Kullback-Leibler divergence]: https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence
+    /// [Information Theory]: https://en.wikipedia.org/wiki/Information_theory
+    fn kl_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [cross entropy] *H(p,q)* between two arrays,
+    /// where `self`=*p*.
+    ///
+    /// The cross entropy is defined as:
+    ///
+    /// ```text
+    ///            n
+    /// H(p,q) = - ∑ pᵢ ln(qᵢ)
+    ///           i=1
+    /// ```
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if any element in *q* is negative and taking the logarithm of a negative number
+    /// is a panic cause for `A`.
+    ///
+    /// ## Remarks
+    ///
+    /// The cross entropy is a measure used in [Information Theory]
+    /// to describe the relationship between two probability distributions: it only makes sense
+    /// when each array sums to 1 with entries between 0 and 1 (extremes included).
+    ///
+    /// The array values are **not** normalised by this function before
+    /// computing the entropy to avoid introducing potentially
+    /// unnecessary numerical errors (e.g. if the array were to be already normalised).
+    ///
+    /// The cross entropy is often used as an objective/loss function in
+    /// [optimization problems], including [machine learning].
+    ///
+    /// By definition, *pᵢ ln(qᵢ)* is set to 0 if *pᵢ* is 0.
+    ///
+    /// [cross entropy]: https://en.wikipedia.org/wiki/Cross-entropy
+    /// [Information Theory]: https://en.wikipedia.org/wiki/Information_theory
+    /// [optimization problems]: https://en.wikipedia.org/wiki/Cross-entropy_method
+    /// [machine learning]: https://en.wikipedia.org/wiki/Cross_entropy#Cross-entropy_error_function_and_logistic_regression
+    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`entropy`] of each 1-dimensional lane along `axis`, e.g.
+    /// of each row of a `(batch, classes)` matrix of probabilities with
+    /// `Axis(1)`.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `ln` of any element in
+    /// the array panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// let probabilities = array![[0.5, 0.5], [1., 0.]];
+    /// let entropies = probabilities.entropy_axis(Axis(1)).unwrap();
+    /// assert_abs_diff_eq!(entropies, array![2f64.ln(), 0.]);
+    /// ```
+    ///
+    /// [`entropy`]: #tymethod.entropy
+    fn entropy_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`kl_divergence`] between each pair of 1-dimensional
+    /// lanes along `axis` of `self` and `q`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the length of `axis` is zero
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// **Panics** if `axis` is out of bounds, or if computing *ln(qᵢ/pᵢ)* is
+    /// a panic cause for `A`.
+    ///
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn kl_divergence_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`cross_entropy`] between each pair of 1-dimensional
+    /// lanes along `axis` of `self` and `q`, e.g. the cross-entropy loss of
+    /// each sample of a batch of predicted probabilities `q` given the true
+    /// probabilities `self`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the length of `axis` is zero
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// **Panics** if `axis` is out of bounds, or if any element in *q* is
+    /// negative and taking the logarithm of a negative number is a panic
+    /// cause for `A`.
+    ///
+    /// [`cross_entropy`]: #tymethod.cross_entropy
+    fn cross_entropy_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [entropy] of the empirical distribution of the array
+    /// elements, treated as raw samples of a categorical variable, i.e. the
+    /// [`entropy`] of their [`proportions`].
+    ///
+    /// This is the plug-in (maximum likelihood) estimate of the entropy of
+    /// the distribution the samples are drawn from.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// let samples = array!["a", "b", "a", "c"];
+    /// let expected = -(0.5 * 0.5f64.ln() + 2. * 0.25 * 0.25f64.ln());
+    /// assert_abs_diff_eq!(samples.empirical_entropy().unwrap(), expected);
+    /// ```
+    ///
+    /// [entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+    /// [`entropy`]: #tymethod.entropy
+    /// [`proportions`]: trait.FrequencyExt.html#tymethod.proportions
+    fn empirical_entropy(&self) -> Result<f64, EmptyInput>
+    where
+        A: Clone + Eq + Hash;
+
+    /// Estimates the [entropy] of the categorical distribution from which
+    /// the observations counted in the array are drawn, where each element
+    /// is the number of observations in a category (e.g. the [`counts`] of
+    /// a histogram), using `estimator`.
+    ///
+    /// Unlike [`entropy`], the counts don't need to be normalised. Every
+    /// element is a category for [`EntropyEstimator::Nsb`], even if its
+    /// count is zero. The result is in nats, see [`LogBase::convert_nats`] to
+    /// convert it to other units.
+    ///
+    /// If the array is empty or all the counts are zero, `Err(EmptyInput)`
+    /// is returned.
+    ///
+    /// **Panics** if a count is negative or NaN, or if `A::to_f64()` fails
+    /// to convert it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{EntropyEstimator, EntropyExt};
+    ///
+    /// let counts = array![2, 1, 1, 0];
+    /// let plug_in = counts.entropy_from_counts(EntropyEstimator::PlugIn).unwrap();
+    /// assert_abs_diff_eq!(plug_in, array![0.5, 0.25, 0.25].entropy().unwrap());
+    /// let miller_madow = counts.entropy_from_counts(EntropyEstimator::MillerMadow).unwrap();
+    /// assert_abs_diff_eq!(miller_madow, plug_in + 2. / 8.);
+    /// ```
+    ///
+    /// [entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+    /// [`counts`]: histogram/struct.Histogram.html#method.counts
+    /// [`entropy`]: #tymethod.entropy
+    /// [`EntropyEstimator::Nsb`]: enum.EntropyEstimator.html#variant.Nsb
+    /// [`LogBase::convert_nats`]: enum.LogBase.html#method.convert_nats
+    fn entropy_from_counts(&self, estimator: EntropyEstimator) -> Result<f64, EmptyInput>
+    where
+        A: ToPrimitive;
+
+    /// Estimates the [entropy] of the distribution from which the array
+    /// elements, treated as raw samples of a categorical variable, are
+    /// drawn, using `estimator` on their [`value_counts`] (see
+    /// [`entropy_from_counts`]).
+    ///
+    /// Only the observed values are known, so [`EntropyEstimator::Nsb`]
+    /// assumes that there are no other categories.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// [entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+    /// [`value_counts`]: trait.FrequencyExt.html#tymethod.value_counts
+    /// [`entropy_from_counts`]: #tymethod.entropy_from_counts
+    /// [`EntropyEstimator::Nsb`]: enum.EntropyEstimator.html#variant.Nsb
+    fn empirical_entropy_with_estimator(
+        &self,
+        estimator: EntropyEstimator,
+    ) -> Result<f64, EmptyInput>
+    where
+        A: Clone + Eq + Hash;
+
+    /// Computes the [Jensen–Shannon divergence] *JSD(p,q)* between two
+    /// arrays, where `self`=*p*:
+    ///
+    /// ```text
+    /// JSD(p,q) = ½ Dₖₗ(p,m) + ½ Dₖₗ(q,m)
+    /// ```
+    ///
+    /// where *m = (p + q) / 2*. Unlike the Kullback-Leibler divergence, it is
+    /// symmetric and bounded by *ln(2)* (one bit).
+    ///
+    /// As for [`kl_divergence`], the arrays are **not** normalised and the
+    /// terms of *Dₖₗ* whose first probability is 0 are set to 0.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{EntropyExt, LogBase};
+    ///
+    /// let p = array![1., 0.];
+    /// let q = array![0., 1.];
+    /// assert_abs_diff_eq!(p.js_divergence(&q).unwrap(), 2f64.ln());
+    /// assert_abs_diff_eq!(LogBase::Two.convert_nats(p.js_divergence(&q).unwrap()), 1.);
+    /// assert_eq!(p.js_divergence(&p).unwrap(), 0.);
+    /// ```
+    ///
+    /// [Jensen–Shannon divergence]: https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn js_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the Jensen–Shannon distance between two arrays, i.e. the
+    /// square root of their [`js_divergence`], which is a metric.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// [`js_divergence`]: #tymethod.js_divergence
+    fn js_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Hellinger distance] *H(p,q)* between two arrays, where
+    /// `self`=*p*:
+    ///
+    /// ```text
+    ///          ⎛  n              ⎞ ½
+    /// H(p,q) = ⎜½ ∑ (√pᵢ - √qᵢ)² ⎟
+    ///          ⎝ i=1             ⎠
+    /// ```
+    ///
+    /// It is a metric bounded by 1 for probability distributions.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// **Panics** if taking the square root of a negative element is a panic
+    /// cause for `A`.
+    ///
+    /// [Hellinger distance]: https://en.wikipedia.org/wiki/Hellinger_distance
+    fn hellinger_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [total variation distance] *δ(p,q)* between two arrays,
+    /// where `self`=*p*:
+    ///
+    /// ```text
+    ///           1  n
+    /// δ(p,q) =  ―  ∑ |pᵢ - qᵢ|
+    ///           2 i=1
+    /// ```
+    ///
+    /// It is a metric bounded by 1 for probability distributions.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// [total variation distance]: https://en.wikipedia.org/wiki/Total_variation_distance_of_probability_measures
+    fn total_variation_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Bhattacharyya coefficient] *BC(p,q)* between two arrays,
+    /// where `self`=*p*:
+    ///
+    /// ```text
+    ///            n
+    /// BC(p,q) =  ∑ √(pᵢ qᵢ)
+    ///           i=1
+    /// ```
+    ///
+    /// It measures the overlap of two probability distributions: it is 1 for
+    /// identical distributions and 0 for distributions with disjoint supports.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// **Panics** if taking the square root of a negative product is a panic
+    /// cause for `A`.
+    ///
+    /// [Bhattacharyya coefficient]: https://en.wikipedia.org/wiki/Bhattacharyya_distance#Bhattacharyya_coefficient
+    fn bhattacharyya_coefficient<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Bhattacharyya distance] *D_B(p,q) = -ln(BC(p,q))* between
+    /// two arrays, where *BC* is the [`bhattacharyya_coefficient`].
+    ///
+    /// The distance is infinite for distributions with disjoint supports.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// [Bhattacharyya distance]: https://en.wikipedia.org/wiki/Bhattacharyya_distance
+    /// [`bhattacharyya_coefficient`]: #tymethod.bhattacharyya_coefficient
+    fn bhattacharyya_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Rényi entropy] *Hₐ* of order *α* = `order` of the array
+    /// values:
+    ///
+    /// ```text
+    ///         1       n
+    /// Hₐ = ――――― ln  ∑ xᵢᵅ
+    ///      1 - α    i=1
+    /// ```
+    ///
+    /// Its limits are the Hartley entropy *ln|{i : xᵢ > 0}|* for *α = 0*,
+    /// the Shannon [`entropy`] for *α = 1* and the min-entropy
+    /// *-ln(max xᵢ)* for *α = ∞*, which are returned for these orders.
+    ///
+    /// As for [`entropy`], the array values are **not** normalised and the
+    /// terms whose *xᵢ* is 0 are set to 0 (even for *α = 0*).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `order` is negative or NaN.
+    ///
+    /// [Rényi entropy]: https://en.wikipedia.org/wiki/R%C3%A9nyi_entropy
+    /// [`entropy`]: #tymethod.entropy
+    fn renyi_entropy(&self, order: A) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [Rényi divergence] *Dₐ(p,q)* of order *α* = `order`
+    /// between two arrays, where `self`=*p*:
+    ///
+    /// ```text
+    ///              1       n
+    /// Dₐ(p,q) = ――――― ln  ∑ pᵢᵅ qᵢ¹⁻ᵅ
+    ///           α - 1    i=1
+    /// ```
+    ///
+    /// Its limits are the [`kl_divergence`] for *α = 1* and
+    /// *ln(max pᵢ/qᵢ)* for *α = ∞*, which are returned for these orders.
+    ///
+    /// As for [`kl_divergence`], the arrays are **not** normalised and the
+    /// terms whose *pᵢ* is 0 are set to 0.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// **Panics** if `order` is negative or NaN.
+    ///
+    /// [Rényi divergence]: https://en.wikipedia.org/wiki/R%C3%A9nyi_entropy#R%C3%A9nyi_divergence
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn renyi_divergence<S2>(&self, q: &ArrayBase<S2, D>, order: A) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Tsallis entropy] *Sₐ* of order *α* = `order` of the
+    /// array values:
+    ///
+    /// ```text
+    ///         1   ⎛     n     ⎞
+    /// Sₐ = ――――― ⎜1 -  ∑ xᵢᵅ ⎟
+    ///      α - 1  ⎝    i=1    ⎠
+    /// ```
+    ///
+    /// Its limit for *α = 1* is the Shannon [`entropy`], which is returned for
+    /// this order. The terms whose *xᵢ* is 0 are set to 0, and the array
+    /// values are **not** normalised.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `order` is negative or NaN.
+    ///
+    /// [Tsallis entropy]: https://en.wikipedia.org/wiki/Tsallis_entropy
+    /// [`entropy`]: #tymethod.entropy
+    fn tsallis_entropy(&self, order: A) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the Tsallis divergence *Dₐ(p,q)* of order *α* = `order`
+    /// between two arrays, where `self`=*p*:
+    ///
+    /// ```text
+    ///              1   ⎛  n               ⎞
+    /// Dₐ(p,q) = ――――― ⎜  ∑ pᵢᵅ qᵢ¹⁻ᵅ - 1 ⎟
+    ///           α - 1  ⎝ i=1              ⎠
+    /// ```
+    ///
+    /// Its limit for *α = 1* is the [`kl_divergence`], which is returned for
+    /// this order. As for [`kl_divergence`], the arrays are **not**
+    /// normalised and the terms whose *pᵢ* is 0 are set to 0.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the arrays are empty
+    /// * `MultiInputError::ShapeMismatch` if the array shapes are not identical
+    ///
+    /// **Panics** if `order` is negative or NaN.
+    ///
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn tsallis_divergence<S2>(&self, q: &ArrayBase<S2, D>, order: A) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    private_decl! {}
+}
+
+impl<A, S, D> EntropyExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn entropy(&self) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        if self.is_empty() {
+            Err(EmptyInput)
+        } else {
+            let entropy = -self
+                .mapv(|x| {
+                    if x == A::zero() {
+                        A::zero()
+                    } else {
+                        x * x.ln()
+                    }
+                })
+                .sum();
+            Ok(entropy)
+        }
+    }
+
+    fn kl_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        S2: Data<Elem = A>,
+    {
+        if self.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.shape() != q.shape() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: q.shape().to_vec(),
+            }
+            .into());
+        }
+
+        let mut temp = Array::zeros(self.raw_dim());
+        Zip::from(&mut temp)
+            .and(self)
+            .and(q)
+            .for_each(|result, &p, &q| {
+                *result = {
+                    if p == A::zero() {
+                        A::zero()
+                    } else {
+                        p * (q / p).ln()
+                    }
+                }
+            });
+        let kl_divergence = -temp.sum();
+        Ok(kl_divergence)
+    }
+
+    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        if self.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.shape() != q.shape() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: q.shape().to_vec(),
+            }
+            .into());
+        }
+
+        let mut temp = Array::zeros(self.raw_dim());
+        Zip::from(&mut temp)
+            .and(self)
+            .and(q)
+            .for_each(|result, &p, &q| {
+                *result = {
+                    if p == A::zero() {
+                        A::zero()
+                    } else {
+                        p * q.ln()
+                    }
+                }
+            });
+        let cross_entropy = -temp.sum();
+        Ok(cross_entropy)
+    }
+
+    fn entropy_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            -lane.fold(A::zero(), |acc, &x| {
+                if x == A::zero() {
+                    acc
+                } else {
+                    acc + x * x.ln()
+                }
+            })
+        }))
+    }
+
+    fn kl_divergence_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis,
+    {
+        fold_lanes_axis(self, q, axis, |p, q| p * (q / p).ln())
+    }
+
+    fn cross_entropy_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis,
+    {
+        fold_lanes_axis(self, q, axis, |p, q| p * q.ln())
+    }
+
+    fn empirical_entropy(&self) -> Result<f64, EmptyInput>
+    where
+        A: Clone + Eq + Hash,
+    {
+        let proportions: Array1<f64> = self.proportions()?.values().cloned().collect();
+        proportions.entropy()
+    }
+
+    fn entropy_from_counts(&self, estimator: EntropyEstimator) -> Result<f64, EmptyInput>
+    where
+        A: ToPrimitive,
+    {
+        let counts: Vec<f64> = self
+            .iter()
+            .map(|count| {
+                let count = count
+                    .to_f64()
+                    .expect("Converting a count to `f64` must not fail.");
+                assert!(count >= 0., "The counts must be non-negative numbers.");
+                count
+            })
+            .filter(|&count| count > 0.)
+            .collect();
+        if counts.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(estimate_entropy(&counts, self.len(), estimator))
+    }
+
+    fn empirical_entropy_with_estimator(
+        &self,
+        estimator: EntropyEstimator,
+    ) -> Result<f64, EmptyInput>
+    where
+        A: Clone + Eq + Hash,
+    {
+        let counts: Array1<usize> = self.value_counts().values().cloned().collect();
+        counts.entropy_from_counts(estimator)
+    }
+
+    fn js_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, q);
+        let two = A::one() + A::one();
+        let relative_entropy = |p: A, m: A| {
+            if p == A::zero() {
+                A::zero()
+            } else {
+                p * (p / m).ln()
+            }
+        };
+        let sum = Zip::from(self).and(q).fold(A::zero(), |acc, &p, &q| {
+            let m = (p + q) / two;
+            acc + relative_entropy(p, m) + relative_entropy(q, m)
+        });
+        Ok(sum / two)
+    }
+
+    fn js_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        // Rounding errors may make the divergence of close arrays slightly negative
+        Ok(self.js_divergence(q)?.max(A::zero()).sqrt())
+    }
+
+    fn hellinger_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, q);
+        let sum = Zip::from(self).and(q).fold(A::zero(), |acc, &p, &q| {
+            let diff = p.sqrt() - q.sqrt();
+            acc + diff * diff
+        });
+        Ok((sum / (A::one() + A::one())).sqrt())
+    }
+
+    fn total_variation_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, q);
+        let sum = Zip::from(self)
+            .and(q)
+            .fold(A::zero(), |acc, &p, &q| acc + (p - q).abs());
+        Ok(sum / (A::one() + A::one()))
+    }
+
+    fn bhattacharyya_coefficient<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, q);
+        Ok(Zip::from(self)
+            .and(q)
+            .fold(A::zero(), |acc, &p, &q| acc + (p * q).sqrt()))
+    }
+
+    fn bhattacharyya_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(-self.bhattacharyya_coefficient(q)?.ln())
+    }
+
+    fn renyi_entropy(&self, order: A) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        check_order(order);
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        if order == A::one() {
+            return self.entropy();
+        }
+        let max = self.fold(A::neg_infinity(), |max, &x| nan_max(max, x));
+        if order.is_infinite() {
+            return Ok(-max.ln());
+        }
+        // Scaling by the maximum avoids underflowing the powers for large orders
+        let scale = if max > A::zero() && max.is_finite() {
+            max
+        } else {
+            A::one()
+        };
+        let sum = self.fold(A::zero(), |acc, &x| {
+            if x == A::zero() {
+                acc
+            } else {
+                acc + (x / scale).powf(order)
+            }
+        });
+        Ok((order * scale.ln() + sum.ln()) / (A::one() - order))
+    }
+
+    fn renyi_divergence<S2>(&self, q: &ArrayBase<S2, D>, order: A) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        check_order(order);
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, q);
+        if order == A::one() {
+            return self.kl_divergence(q);
+        }
+        if order.is_infinite() {
+            let max = Zip::from(self)
+                .and(q)
+                .fold(A::neg_infinity(), |max, &p, &q| {
+                    if p == A::zero() {
+                        max
+                    } else {
+                        nan_max(max, p / q)
+                    }
+                });
+            return Ok(max.ln());
+        }
+        Ok(power_sum(self, q, order).ln() / (order - A::one()))
+    }
+
+    fn tsallis_entropy(&self, order: A) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        check_order(order);
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        if order == A::one() {
+            return self.entropy();
+        }
+        let sum = self.fold(A::zero(), |acc, &x| {
+            if x == A::zero() {
+                acc
+            } else {
+                acc + x.powf(order)
+            }
+        });
+        Ok((A::one() - sum) / (order - A::one()))
+    }
+
+    fn tsallis_divergence<S2>(&self, q: &ArrayBase<S2, D>, order: A) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        check_order(order);
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, q);
+        if order == A::one() {
+            return self.kl_divergence(q);
+        }
+        Ok((power_sum(self, q, order) - A::one()) / (order - A::one()))
+    }
+
+    private_impl! {}
+}
+
+/// Extension trait for 2-dimensional `ArrayBase`s of joint probabilities,
+/// providing methods to compute information theory quantities of two
+/// variables (e.g. joint entropy, mutual information, etc.).
+///
+/// The quantities are in nats, see [`LogBase::convert_nats`] for other units.
+///
+/// [`LogBase::convert_nats`]: enum.LogBase.html#method.convert_nats
+pub trait JointEntropyExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Computes the [joint entropy] *H(X,Y)* of the array of joint
+    /// probabilities, where the element at `(i, j)` is the probability that
+    /// *X* takes its `i`-th value and *Y* its `j`-th value:
+    ///
+    /// ```text
+    ///              n   m
+    /// H(X,Y) = -   ∑   ∑ pᵢⱼ ln(pᵢⱼ)
+    ///             i=1 j=1
+    /// ```
+    ///
+    /// As for [`entropy`], the probabilities are **not** normalised and
+    /// *pᵢⱼ ln(pᵢⱼ)* is set to 0 if *pᵢⱼ* is 0.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// [joint entropy]: https://en.wikipedia.org/wiki/Joint_entropy
+    /// [`entropy`]: trait.EntropyExt.html#tymethod.entropy
+    fn joint_entropy(&self) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [conditional entropy] *H(Y|X)* of the array of joint
+    /// probabilities (see [`joint_entropy`]), i.e. the entropy of
+    /// the variable *Y* along the columns given the variable *X* along the
+    /// rows:
+    ///
+    /// ```text
+    /// H(Y|X) = H(X,Y) - H(X)
+    /// ```
+    ///
+    /// where the marginal probabilities of *X* are the sums of the rows. Use
+    /// the transposed array for *H(X|Y)*.
     ///
-    /// [Kullback-Leibler divergence]: https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence
-    /// [Information Theory]: https://en.wikipedia.org/wiki/Information_theory
-    fn kl_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// [conditional entropy]: https://en.wikipedia.org/wiki/Conditional_entropy
+    /// [`joint_entropy`]: #tymethod.joint_entropy
+    fn conditional_entropy(&self) -> Result<A, EmptyInput>
     where
-        S2: Data<Elem = A>,
         A: Float;
 
-    /// Computes the [cross entropy] *H(p,q)* between two arrays,
-    /// where `self`=*p*.
-    ///
-    /// The cross entropy is defined as:
+    /// Computes the [mutual information] *I(X;Y)* of the array of joint
+    /// probabilities (see [`joint_entropy`]):
     ///
     /// ```text
-    ///            n
-    /// H(p,q) = - ∑ pᵢ ln(qᵢ)
-    ///           i=1
+    ///             n   m
+    /// I(X;Y) =    ∑   ∑ pᵢⱼ ln(pᵢⱼ / (pᵢ pⱼ))
+    ///            i=1 j=1
     /// ```
     ///
-    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
-    /// If the array shapes are not identical,
-    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    /// where *pᵢ* and *pⱼ* are the marginal probabilities of *X* and *Y*,
+    /// i.e. the sums of the rows and of the columns. It is equal to
+    /// *H(X) + H(Y) - H(X,Y)*.
     ///
-    /// **Panics** if any element in *q* is negative and taking the logarithm of a negative number
-    /// is a panic cause for `A`.
+    /// By definition, *pᵢⱼ ln(pᵢⱼ / (pᵢ pⱼ))* is set to 0 if *pᵢⱼ* is 0.
     ///
-    /// ## Remarks
+    /// If the array is empty, `Err(EmptyInput)` is returned.
     ///
-    /// The cross entropy is a measure used in [Information Theory]
-    /// to describe the relationship between two probability distributions: it only makes sense
-    /// when each array sums to 1 with entries between 0 and 1 (extremes included).
+    /// # Example
     ///
-    /// The array values are **not** normalised by this function before
-    /// computing the entropy to avoid introducing potentially
-    /// unnecessary numerical errors (e.g. if the array were to be already normalised).
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::JointEntropyExt;
     ///
-    /// The cross entropy is often used as an objective/loss function in
-    /// [optimization problems], including [machine learning].
+    /// // Y is a copy of X, which is uniform over two values
+    /// let p = array![[0.5, 0.], [0., 0.5]];
+    /// assert_abs_diff_eq!(p.mutual_information().unwrap(), 2f64.ln());
+    /// assert_abs_diff_eq!(p.conditional_entropy().unwrap(), 0.);
+    /// // X and Y are independent
+    /// let p = array![[0.1, 0.3], [0.15, 0.45]];
+    /// assert_abs_diff_eq!(p.mutual_information().unwrap(), 0., epsilon = 1e-15);
+    /// ```
     ///
-    /// By definition, *pᵢ ln(qᵢ)* is set to 0 if *pᵢ* is 0.
+    /// [mutual information]: https://en.wikipedia.org/wiki/Mutual_information
+    /// [`joint_entropy`]: #tymethod.joint_entropy
+    fn mutual_information(&self) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the mutual information of the array of joint probabilities
+    /// (see [`mutual_information`]), normalized by the
+    /// entropies of the marginal distributions as specified by
+    /// `normalization`.
     ///
-    /// [cross entropy]: https://en.wikipedia.org/wiki/Cross-entropy
-    /// [Information Theory]: https://en.wikipedia.org/wiki/Information_theory
-    /// [optimization problems]: https://en.wikipedia.org/wiki/Cross-entropy_method
-    /// [machine learning]: https://en.wikipedia.org/wiki/Cross_entropy#Cross-entropy_error_function_and_logistic_regression
-    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    /// The result is NaN if the normalizing entropy is zero, i.e. if one of
+    /// the variables (or both, depending on `normalization`) is constant.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// See [`Histogram::normalized_mutual_information`] to compute it from a
+    /// 2-dimensional histogram of the observations.
+    ///
+    /// [`mutual_information`]: #tymethod.mutual_information
+    /// [`Histogram::normalized_mutual_information`]: histogram/struct.Histogram.html#method.normalized_mutual_information
+    fn normalized_mutual_information(
+        &self,
+        normalization: MutualInformationNormalization,
+    ) -> Result<A, EmptyInput>
     where
-        S2: Data<Elem = A>,
         A: Float;
 
     private_decl! {}
 }
 
-impl<A, S, D> EntropyExt<A, S, D> for ArrayBase<S, D>
+impl<A, S> JointEntropyExt<A, S> for ArrayBase<S, Ix2>
 where
     S: Data<Elem = A>,
-    D: Dimension,
 {
-    fn entropy(&self) -> Result<A, EmptyInput>
+    fn joint_entropy(&self) -> Result<A, EmptyInput>
     where
         A: Float,
     {
-        if self.is_empty() {
-            Err(EmptyInput)
-        } else {
-            let entropy = -self
-                .mapv(|x| {
-                    if x == A::zero() {
-                        A::zero()
-                    } else {
-                        x * x.ln()
-                    }
-                })
-                .sum();
-            Ok(entropy)
-        }
+        self.entropy()
     }
 
-    fn kl_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    fn conditional_entropy(&self) -> Result<A, EmptyInput>
     where
         A: Float,
-        S2: Data<Elem = A>,
     {
-        if self.is_empty() {
-            return Err(MultiInputError::EmptyInput);
-        }
-        if self.shape() != q.shape() {
-            return Err(ShapeMismatch {
-                first_shape: self.shape().to_vec(),
-                second_shape: q.shape().to_vec(),
-            }
-            .into());
-        }
-
-        let mut temp = Array::zeros(self.raw_dim());
-        Zip::from(&mut temp)
-            .and(self)
-            .and(q)
-            .for_each(|result, &p, &q| {
-                *result = {
-                    if p == A::zero() {
-                        A::zero()
-                    } else {
-                        p * (q / p).ln()
-                    }
-                }
-            });
-        let kl_divergence = -temp.sum();
-        Ok(kl_divergence)
+        let h_xy = self.entropy()?;
+        let h_x = self.sum_axis(Axis(1)).entropy()?;
+        Ok(h_xy - h_x)
     }
 
-    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    fn mutual_information(&self) -> Result<A, EmptyInput>
     where
-        S2: Data<Elem = A>,
         A: Float,
     {
         if self.is_empty() {
-            return Err(MultiInputError::EmptyInput);
+            return Err(EmptyInput);
         }
-        if self.shape() != q.shape() {
-            return Err(ShapeMismatch {
-                first_shape: self.shape().to_vec(),
-                second_shape: q.shape().to_vec(),
+        let p_x = self.sum_axis(Axis(1));
+        let p_y = self.sum_axis(Axis(0));
+        let mut mutual_information = A::zero();
+        for ((i, j), &p_xy) in self.indexed_iter() {
+            if p_xy != A::zero() {
+                mutual_information = mutual_information + p_xy * (p_xy / (p_x[i] * p_y[j])).ln();
             }
-            .into());
         }
+        Ok(mutual_information)
+    }
 
-        let mut temp = Array::zeros(self.raw_dim());
-        Zip::from(&mut temp)
-            .and(self)
-            .and(q)
-            .for_each(|result, &p, &q| {
-                *result = {
+    fn normalized_mutual_information(
+        &self,
+        normalization: MutualInformationNormalization,
+    ) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        let mutual_information = self.mutual_information()?;
+        let h_x = self.sum_axis(Axis(1)).entropy()?;
+        let h_y = self.sum_axis(Axis(0)).entropy()?;
+        let normalizer = match normalization {
+            MutualInformationNormalization::Min => h_x.min(h_y),
+            MutualInformationNormalization::Geometric => (h_x * h_y).sqrt(),
+            MutualInformationNormalization::Arithmetic => (h_x + h_y) / A::from(2.).unwrap(),
+            MutualInformationNormalization::Max => h_x.max(h_y),
+            MutualInformationNormalization::Joint => self.entropy()?,
+        };
+        Ok(mutual_information / normalizer)
+    }
+
+    private_impl! {}
+}
+
+/// Returns `-∑ f(pᵢ, qᵢ)` over the non-zero `pᵢ` of each pair of
+/// 1-dimensional lanes along `axis` of `p` and `q`.
+fn fold_lanes_axis<A, S, S2, D, F>(
+    p: &ArrayBase<S, D>,
+    q: &ArrayBase<S2, D>,
+    axis: Axis,
+    f: F,
+) -> Result<Array<A, D::Smaller>, MultiInputError>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: RemoveAxis,
+    F: Fn(A, A) -> A,
+{
+    if p.len_of(axis) == 0 {
+        return Err(MultiInputError::EmptyInput);
+    }
+    if p.shape() != q.shape() {
+        return Err(ShapeMismatch {
+            first_shape: p.shape().to_vec(),
+            second_shape: q.shape().to_vec(),
+        }
+        .into());
+    }
+    Ok(Zip::from(p.lanes(axis))
+        .and(q.lanes(axis))
+        .map_collect(|p, q| {
+            -Zip::from(p).and(q).fold(
+                A::zero(),
+                |acc, &p, &q| {
                     if p == A::zero() {
-                        A::zero()
+                        acc
                     } else {
-                        p * q.ln()
+                        acc + f(p, q)
                     }
-                }
-            });
-        let cross_entropy = -temp.sum();
-        Ok(cross_entropy)
+                },
+            )
+        }))
+}
+
+/// Checks that the order of a Rényi or Tsallis measure is valid.
+///
+/// **Panics** if `order` is negative or NaN.
+fn check_order<A: Float>(order: A) {
+    assert!(
+        order >= A::zero(),
+        "The order must be a non-negative number."
+    );
+}
+
+/// Returns the maximum of `max` and `x`, or NaN if either of them is NaN
+/// (unlike `Float::max`, which ignores NaN).
+fn nan_max<A: Float>(max: A, x: A) -> A {
+    if x.is_nan() || x > max {
+        x
+    } else {
+        max
     }
+}
 
-    private_impl! {}
+/// Returns the sum of `pᵢᵅ qᵢ¹⁻ᵅ` over the non-zero `pᵢ`, where `α` is `order`.
+fn power_sum<A, S, S2, D>(p: &ArrayBase<S, D>, q: &ArrayBase<S2, D>, order: A) -> A
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    Zip::from(p).and(q).fold(A::zero(), |acc, &p, &q| {
+        if p == A::zero() {
+            acc
+        } else {
+            acc + p.powf(order) * q.powf(A::one() - order)
+        }
+    })
+}
+
+/// Estimates the entropy from the non-zero counts of `n_categories`
+/// categories.
+fn estimate_entropy(counts: &[f64], n_categories: usize, estimator: EntropyEstimator) -> f64 {
+    let n: f64 = counts.iter().sum();
+    let plug_in = || -> f64 {
+        -counts
+            .iter()
+            .map(|&count| count / n * (count / n).ln())
+            .sum::<f64>()
+    };
+    match estimator {
+        EntropyEstimator::PlugIn => plug_in(),
+        EntropyEstimator::MillerMadow => plug_in() + (counts.len() - 1) as f64 / (2. * n),
+        EntropyEstimator::ChaoShen => {
+            let singletons = counts.iter().filter(|&&count| count == 1.).count() as f64;
+            // The coverage would be zero if all the observations were singletons
+            let coverage = 1. - singletons.min(n - 1.) / n;
+            -counts
+                .iter()
+                .map(|&count| {
+                    let p = coverage * count / n;
+                    p * p.ln() / (1. - (1. - p).powf(n))
+                })
+                .sum::<f64>()
+        }
+        EntropyEstimator::Nsb => nsb_entropy(counts, n_categories as f64),
+    }
+}
+
+/// The bounds and the number of points of the grid of `ln(α)` over which
+/// the NSB estimator integrates the Dirichlet concentrations `α`.
+const NSB_LN_CONCENTRATION_RANGE: (f64, f64) = (-20., 20.);
+const NSB_GRID_SIZE: usize = 401;
+
+/// Returns the NSB estimate of the entropy from the non-zero counts of `k`
+/// categories.
+fn nsb_entropy(counts: &[f64], k: f64) -> f64 {
+    if k <= 1. {
+        return 0.;
+    }
+    let n: f64 = counts.iter().sum();
+    let n_unobserved = k - counts.len() as f64;
+    let (ln_min, ln_max) = NSB_LN_CONCENTRATION_RANGE;
+    let step = (ln_max - ln_min) / (NSB_GRID_SIZE - 1) as f64;
+    let mut ln_weights = Vec::with_capacity(NSB_GRID_SIZE);
+    let mut entropies = Vec::with_capacity(NSB_GRID_SIZE);
+    for i in 0..NSB_GRID_SIZE {
+        let ln_alpha = ln_min + i as f64 * step;
+        let alpha = ln_alpha.exp();
+        let total = n + k * alpha;
+        // The derivative of the prior mean of the entropy, which makes the
+        // mixture nearly uniform over the entropy
+        let prior = k * trigamma(k * alpha + 1.) - trigamma(alpha + 1.);
+        let ln_evidence = ln_gamma(k * alpha) - ln_gamma(total)
+            + counts
+                .iter()
+                .map(|&count| ln_gamma(count + alpha) - ln_gamma(alpha))
+                .sum::<f64>();
+        // The grid is uniform over ln(α), hence the extra α factor
+        ln_weights.push(if prior > 0. {
+            prior.ln() + ln_evidence + ln_alpha
+        } else {
+            f64::NEG_INFINITY
+        });
+        let posterior_mean = digamma(total + 1.)
+            - (counts
+                .iter()
+                .map(|&count| (count + alpha) * digamma(count + alpha + 1.))
+                .sum::<f64>()
+                + n_unobserved * alpha * digamma(alpha + 1.))
+                / total;
+        entropies.push(posterior_mean);
+    }
+    let max = ln_weights.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let (sum, sum_weights) = ln_weights.iter().zip(&entropies).fold(
+        (0., 0.),
+        |(sum, sum_weights), (&ln_weight, &entropy)| {
+            let weight = (ln_weight - max).exp();
+            (sum + weight * entropy, sum_weights + weight)
+        },
+    );
+    sum / sum_weights
 }
 
 #[cfg(test)]
 mod tests {
-    use super::EntropyExt;
-    use crate::errors::{EmptyInput, MultiInputError};
+    use super::{
+        EntropyEstimator, EntropyExt, JointEntropyExt, LogBase, MutualInformationNormalization,
+    };
+    use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
     use approx::assert_abs_diff_eq;
-    use ndarray::{array, Array1};
+    use ndarray::{array, Array, Array1, Array2, Axis, Ix2, Zip};
+    use ndarray_rand::rand_distr::Uniform;
+    use ndarray_rand::RandomExt;
     use noisy_float::types::n64;
     use std::f64;
 
@@ -396,4 +1532,489 @@ mod tests {
         assert_abs_diff_eq!(p.kl_divergence(&q)?, expected_kl, epsilon = 1e-6);
         Ok(())
     }
+
+    #[test]
+    fn test_joint_entropies_with_empty_array() {
+        let p: Array2<f64> = Array2::zeros((0, 3));
+        assert_eq!(p.joint_entropy(), Err(EmptyInput));
+        assert_eq!(p.conditional_entropy(), Err(EmptyInput));
+        assert_eq!(p.mutual_information(), Err(EmptyInput));
+        assert_eq!(
+            p.normalized_mutual_information(MutualInformationNormalization::Max),
+            Err(EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_joint_entropies_identities() -> Result<(), EmptyInput> {
+        let p: Array2<f64> = array![[0.1, 0.05, 0.], [0.2, 0.05, 0.15], [0.05, 0.3, 0.1]];
+        let h_x = p.sum_axis(Axis(1)).entropy()?;
+        let h_y = p.sum_axis(Axis(0)).entropy()?;
+        let h_xy = p.joint_entropy()?;
+        let mi = p.mutual_information()?;
+        assert_abs_diff_eq!(h_xy, p.entropy()?);
+        assert_abs_diff_eq!(p.conditional_entropy()?, h_xy - h_x, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.t().conditional_entropy()?, h_xy - h_y, epsilon = 1e-12);
+        assert_abs_diff_eq!(mi, h_x + h_y - h_xy, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.t().mutual_information()?, mi, epsilon = 1e-12);
+        assert!(mi > 0.);
+
+        let normalized = |normalization| p.normalized_mutual_information(normalization);
+        assert_abs_diff_eq!(
+            normalized(MutualInformationNormalization::Min)?,
+            mi / h_x.min(h_y)
+        );
+        assert_abs_diff_eq!(
+            normalized(MutualInformationNormalization::Geometric)?,
+            mi / (h_x * h_y).sqrt()
+        );
+        assert_abs_diff_eq!(
+            normalized(MutualInformationNormalization::Arithmetic)?,
+            2. * mi / (h_x + h_y)
+        );
+        assert_abs_diff_eq!(
+            normalized(MutualInformationNormalization::Max)?,
+            mi / h_x.max(h_y)
+        );
+        assert_abs_diff_eq!(
+            normalized(MutualInformationNormalization::Joint)?,
+            mi / h_xy
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_mutual_information_from_histogram() {
+        use crate::histogram::{errors::JointHistogramError, Bins, Edges, Grid, Histogram};
+        use crate::HistogramExt;
+
+        let bins = Bins::new(Edges::from(vec![n64(0.), n64(1.), n64(2.), n64(3.)]));
+        let grid = Grid::from(vec![bins.clone(), bins.clone()]);
+        let empty = Histogram::new(grid.clone());
+        assert_eq!(
+            empty.mutual_information(),
+            Err(JointHistogramError::EmptyInput)
+        );
+        let histogram = array![[0.5], [1.5]]
+            .mapv(n64)
+            .histogram(Grid::from(vec![bins]));
+        assert_eq!(
+            histogram.mutual_information(),
+            Err(JointHistogramError::NotTwoDimensional(1))
+        );
+        assert_eq!(
+            histogram.normalized_mutual_information(MutualInformationNormalization::Max),
+            Err(JointHistogramError::NotTwoDimensional(1))
+        );
+
+        let observations = array![
+            [0.5, 0.5],
+            [0.5, 1.5],
+            [1.5, 1.5],
+            [2.5, 0.5],
+            [2.5, 2.5],
+            [2.5, 2.5]
+        ]
+        .mapv(n64);
+        let histogram = observations.histogram(grid);
+        let p = histogram
+            .counts()
+            .mapv(|count| count as f64 / 6.)
+            .into_dimensionality::<Ix2>()
+            .unwrap();
+        assert_abs_diff_eq!(
+            histogram.mutual_information().unwrap(),
+            p.mutual_information().unwrap()
+        );
+        assert_abs_diff_eq!(
+            histogram
+                .normalized_mutual_information(MutualInformationNormalization::Geometric)
+                .unwrap(),
+            p.normalized_mutual_information(MutualInformationNormalization::Geometric)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_log_bases_convert_nats() {
+        assert_eq!(LogBase::E.convert_nats(1.5), 1.5);
+        for &(base, ln_base) in &[
+            (LogBase::Two, f64::consts::LN_2),
+            (LogBase::Ten, f64::consts::LN_10),
+            (LogBase::Custom(3.), 3f64.ln()),
+        ] {
+            assert_abs_diff_eq!(base.convert_nats(1.5), 1.5 / ln_base);
+        }
+        // A fair coin flip carries one bit of information
+        let nats = array![0.5, 0.5].entropy().unwrap();
+        assert_eq!(LogBase::Two.convert_nats(nats), 1.);
+        assert_eq!(LogBase::Two.convert_nats(2f32.ln()), 1.);
+    }
+
+    #[test]
+    #[should_panic(expected = "The base of the logarithm must be positive and different from one.")]
+    fn test_convert_nats_with_invalid_base() {
+        LogBase::Custom(1.).convert_nats(1.);
+    }
+
+    #[test]
+    fn test_divergences_with_array_of_different_shape() {
+        let p = array![0.5, 0.5];
+        let q = array![0.5, 0.3, 0.2];
+        let mismatch = |r| {
+            assert_eq!(
+                r,
+                Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                    first_shape: vec![2],
+                    second_shape: vec![3],
+                }))
+            )
+        };
+        mismatch(p.js_divergence(&q));
+        mismatch(p.js_distance(&q));
+        mismatch(p.hellinger_distance(&q));
+        mismatch(p.total_variation_distance(&q));
+        mismatch(p.bhattacharyya_coefficient(&q));
+        mismatch(p.bhattacharyya_distance(&q));
+        mismatch(p.renyi_divergence(&q, 2.));
+        mismatch(p.tsallis_divergence(&q, 2.));
+    }
+
+    #[test]
+    fn test_divergences_with_empty_array() {
+        let p: Array1<f64> = array![];
+        let q: Array1<f64> = array![];
+        assert_eq!(p.js_divergence(&q), Err(MultiInputError::EmptyInput));
+        assert_eq!(p.hellinger_distance(&q), Err(MultiInputError::EmptyInput));
+        assert_eq!(
+            p.total_variation_distance(&q),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            p.bhattacharyya_coefficient(&q),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            p.renyi_divergence(&q, 0.5),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            p.tsallis_divergence(&q, 0.5),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(p.renyi_entropy(2.), Err(EmptyInput));
+        assert_eq!(p.tsallis_entropy(2.), Err(EmptyInput));
+    }
+
+    #[test]
+    fn test_symmetric_divergences() -> Result<(), MultiInputError> {
+        let p: Array2<f64> = Array::random((4, 5), Uniform::new(0., 1.));
+        let p = &p / p.sum();
+        let q: Array2<f64> = Array::random((4, 5), Uniform::new(0., 1.));
+        let q = &q / q.sum();
+
+        let js = p.js_divergence(&q)?;
+        assert_abs_diff_eq!(js, q.js_divergence(&p)?, epsilon = 1e-12);
+        assert!(js > 0. && js <= 2f64.ln());
+        let m = (&p + &q) / 2.;
+        assert_abs_diff_eq!(
+            js,
+            (p.kl_divergence(&m)? + q.kl_divergence(&m)?) / 2.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(p.js_distance(&q)?, js.sqrt());
+
+        let hellinger = p.hellinger_distance(&q)?;
+        let tv = p.total_variation_distance(&q)?;
+        let bc = p.bhattacharyya_coefficient(&q)?;
+        assert_abs_diff_eq!(hellinger, q.hellinger_distance(&p)?, epsilon = 1e-12);
+        assert_abs_diff_eq!(tv, q.total_variation_distance(&p)?, epsilon = 1e-12);
+        assert_abs_diff_eq!(bc, q.bhattacharyya_coefficient(&p)?, epsilon = 1e-12);
+        // H² = 1 - BC and H² ≤ δ ≤ √2 H for probability distributions
+        assert_abs_diff_eq!(hellinger.powi(2), 1. - bc, epsilon = 1e-12);
+        assert!(hellinger.powi(2) <= tv && tv <= 2f64.sqrt() * hellinger);
+        assert!(tv <= 1.);
+        assert_abs_diff_eq!(p.bhattacharyya_distance(&q)?, -bc.ln());
+
+        assert_abs_diff_eq!(p.bhattacharyya_coefficient(&p)?, 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(p.js_divergence(&p)?, 0., epsilon = 1e-12);
+        assert_eq!(p.hellinger_distance(&p)?, 0.);
+        assert_eq!(p.total_variation_distance(&p)?, 0.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_divergences_with_disjoint_supports() -> Result<(), MultiInputError> {
+        let p = array![0.5, 0.5, 0., 0.];
+        let q = array![0., 0., 0.25, 0.75];
+        assert_abs_diff_eq!(p.js_divergence(&q)?, 2f64.ln());
+        assert_abs_diff_eq!(LogBase::Two.convert_nats(p.js_divergence(&q)?), 1.);
+        assert_abs_diff_eq!(p.hellinger_distance(&q)?, 1.);
+        assert_abs_diff_eq!(p.total_variation_distance(&q)?, 1.);
+        assert_eq!(p.bhattacharyya_coefficient(&q)?, 0.);
+        assert_eq!(p.bhattacharyya_distance(&q)?, f64::INFINITY);
+        assert_eq!(p.renyi_divergence(&q, 2.)?, f64::INFINITY);
+        Ok(())
+    }
+
+    #[test]
+    fn test_renyi_and_tsallis_entropies() -> Result<(), EmptyInput> {
+        let p = array![0.5, 0.25, 0.125, 0.125, 0.];
+        assert_abs_diff_eq!(p.renyi_entropy(0.)?, 4f64.ln());
+        assert_abs_diff_eq!(p.renyi_entropy(1.)?, p.entropy()?);
+        assert_abs_diff_eq!(p.renyi_entropy(1. + 1e-9)?, p.entropy()?, epsilon = 1e-7);
+        assert_abs_diff_eq!(
+            p.renyi_entropy(2.)?,
+            -(0.25f64 + 0.0625 + 0.015625 + 0.015625).ln()
+        );
+        assert_abs_diff_eq!(p.renyi_entropy(f64::INFINITY)?, 2f64.ln());
+        assert_abs_diff_eq!(p.renyi_entropy(1e6)?, 2f64.ln(), epsilon = 1e-5);
+        assert_abs_diff_eq!(LogBase::Two.convert_nats(p.renyi_entropy(0.)?), 2.);
+
+        assert_abs_diff_eq!(p.tsallis_entropy(1.)?, p.entropy()?);
+        assert_abs_diff_eq!(p.tsallis_entropy(1. - 1e-9)?, p.entropy()?, epsilon = 1e-7);
+        assert_abs_diff_eq!(p.tsallis_entropy(0.)?, 3.);
+        assert_abs_diff_eq!(
+            p.tsallis_entropy(2.)?,
+            1. - (0.25 + 0.0625 + 0.015625 + 0.015625)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_renyi_and_tsallis_divergences() -> Result<(), MultiInputError> {
+        let p: Array2<f64> = Array::random((3, 4), Uniform::new(0., 1.));
+        let p = &p / p.sum();
+        let q: Array2<f64> = Array::random((3, 4), Uniform::new(0., 1.));
+        let q = &q / q.sum();
+
+        let kl = p.kl_divergence(&q)?;
+        assert_abs_diff_eq!(p.renyi_divergence(&q, 1.)?, kl);
+        assert_abs_diff_eq!(p.renyi_divergence(&q, 1. + 1e-9)?, kl, epsilon = 1e-6);
+        assert_abs_diff_eq!(p.tsallis_divergence(&q, 1.)?, kl);
+        assert_abs_diff_eq!(p.tsallis_divergence(&q, 1. - 1e-9)?, kl, epsilon = 1e-6);
+        // Dₐ(p,q) = -2 ln BC(p,q) for α = ½
+        assert_abs_diff_eq!(
+            p.renyi_divergence(&q, 0.5)?,
+            2. * p.bhattacharyya_distance(&q)?,
+            epsilon = 1e-12
+        );
+        let max_ratio = Zip::from(&p)
+            .and(&q)
+            .fold(f64::NEG_INFINITY, |max, &p, &q| max.max(p / q));
+        assert_abs_diff_eq!(p.renyi_divergence(&q, f64::INFINITY)?, max_ratio.ln());
+        // The Rényi divergence is non-decreasing in α
+        let orders = [0., 0.5, 1., 2., 10., f64::INFINITY];
+        let divergences = orders
+            .iter()
+            .map(|&order| p.renyi_divergence(&q, order))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert!(divergences.windows(2).all(|w| w[0] <= w[1] + 1e-12));
+        assert_abs_diff_eq!(p.renyi_divergence(&p, 2.)?, 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(p.tsallis_divergence(&p, 2.)?, 0., epsilon = 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_renyi_measures_of_infinite_order_with_nan() -> Result<(), MultiInputError> {
+        for p in &[array![f64::NAN, 0.5, 0.25], array![0.5, 0.25, f64::NAN]] {
+            assert!(p.renyi_entropy(f64::INFINITY)?.is_nan());
+            let q = array![0.25, 0.25, 0.5];
+            assert!(p.renyi_divergence(&q, f64::INFINITY)?.is_nan());
+            assert!(q.renyi_divergence(p, f64::INFINITY)?.is_nan());
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_renyi_entropy_with_negative_order() {
+        let _ = array![0.5, 0.5].renyi_entropy(-1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tsallis_divergence_with_nan_order() {
+        let _ = array![0.5, 0.5].tsallis_divergence(&array![0.5, 0.5], f64::NAN);
+    }
+
+    #[test]
+    fn test_entropy_axis_eq_entropy_of_lanes() -> Result<(), MultiInputError> {
+        let p: Array2<f64> = Array::random((5, 3), Uniform::new(0., 1.));
+        let p = &p / &p.sum_axis(Axis(1)).insert_axis(Axis(1));
+        let mut q: Array2<f64> = Array::random((5, 3), Uniform::new(0., 1.));
+        q /= &q.sum_axis(Axis(1)).insert_axis(Axis(1));
+        let mut p_with_zeros = p.clone();
+        p_with_zeros.column_mut(1).fill(0.);
+
+        for &axis in &[Axis(0), Axis(1)] {
+            for p in &[&p, &p_with_zeros] {
+                let entropies = p.entropy_axis(axis)?;
+                let kl_divergences = p.kl_divergence_axis(&q, axis)?;
+                let cross_entropies = p.cross_entropy_axis(&q, axis)?;
+                assert_eq!(entropies.len(), p.len() / p.len_of(axis));
+                for (i, (p, q)) in p.lanes(axis).into_iter().zip(q.lanes(axis)).enumerate() {
+                    assert_abs_diff_eq!(entropies[i], p.entropy()?, epsilon = 1e-12);
+                    assert_abs_diff_eq!(kl_divergences[i], p.kl_divergence(&q)?, epsilon = 1e-12);
+                    assert_abs_diff_eq!(cross_entropies[i], p.cross_entropy(&q)?, epsilon = 1e-12);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_axis_with_empty_axis() {
+        let p: Array2<f64> = Array2::zeros((3, 0));
+        assert_eq!(p.entropy_axis(Axis(1)), Err(EmptyInput));
+        assert_eq!(
+            p.kl_divergence_axis(&p, Axis(1)),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            p.cross_entropy_axis(&p, Axis(1)),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(p.entropy_axis(Axis(0)), Ok(array![]));
+        assert_eq!(p.cross_entropy_axis(&p, Axis(0)), Ok(array![]));
+    }
+
+    #[test]
+    fn test_divergences_axis_with_array_of_different_shape() {
+        let p: Array2<f64> = Array2::from_elem((2, 3), 1. / 3.);
+        let q: Array2<f64> = Array2::from_elem((3, 3), 1. / 3.);
+        let mismatch = Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2, 3],
+            second_shape: vec![3, 3],
+        }));
+        assert_eq!(p.kl_divergence_axis(&q, Axis(1)), mismatch);
+        assert_eq!(p.cross_entropy_axis(&q, Axis(1)), mismatch);
+    }
+
+    #[test]
+    fn test_entropy_from_counts() -> Result<(), EmptyInput> {
+        let counts = array![3, 1, 1, 0, 0];
+        let plug_in = -(0.6 * 0.6f64.ln() + 2. * 0.2 * 0.2f64.ln());
+        assert_abs_diff_eq!(
+            counts.entropy_from_counts(EntropyEstimator::PlugIn)?,
+            plug_in,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.entropy_from_counts(EntropyEstimator::MillerMadow)?,
+            plug_in + 0.2,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.entropy_from_counts(EntropyEstimator::ChaoShen)?,
+            1.489_524_654_318_191,
+            epsilon = 1e-12
+        );
+        // Only singletons: the coverage is estimated as 1/N
+        assert_abs_diff_eq!(
+            array![1., 1., 1.].entropy_from_counts(EntropyEstimator::ChaoShen)?,
+            2.460_486_508_261_295,
+            epsilon = 1e-12
+        );
+        // The reference values are computed by adaptive quadrature
+        assert_abs_diff_eq!(
+            counts.entropy_from_counts(EntropyEstimator::Nsb)?,
+            1.219_877_669_695_508,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            array![[10u8, 4, 3, 2, 1], [1, 0, 0, 0, 0]]
+                .entropy_from_counts(EntropyEstimator::Nsb)?,
+            1.642_552_729_626_414,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            array![5, 5].entropy_from_counts(EntropyEstimator::Nsb)?,
+            0.659_453_773_362_095_5,
+            epsilon = 1e-6
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_estimators_with_single_category() -> Result<(), EmptyInput> {
+        for &estimator in &[
+            EntropyEstimator::PlugIn,
+            EntropyEstimator::MillerMadow,
+            EntropyEstimator::ChaoShen,
+            EntropyEstimator::Nsb,
+        ] {
+            assert_eq!(array![7].entropy_from_counts(estimator)?, 0.);
+            assert_eq!(
+                array!["a", "a"].empirical_entropy_with_estimator(estimator)?,
+                0.
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_estimators_converge_to_plug_in() -> Result<(), EmptyInput> {
+        let p = array![0.5, 0.25, 0.125, 0.125];
+        let counts = p.mapv(|p| p * 1e6);
+        for &estimator in &[
+            EntropyEstimator::MillerMadow,
+            EntropyEstimator::ChaoShen,
+            EntropyEstimator::Nsb,
+        ] {
+            assert_abs_diff_eq!(
+                counts.entropy_from_counts(estimator)?,
+                p.entropy()?,
+                epsilon = 1e-5
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_empirical_entropy_with_estimator() -> Result<(), EmptyInput> {
+        let samples = array![1, 2, 2, 3, 3, 3, 3, 3];
+        let counts = array![1, 2, 5];
+        for &estimator in &[
+            EntropyEstimator::PlugIn,
+            EntropyEstimator::MillerMadow,
+            EntropyEstimator::ChaoShen,
+            EntropyEstimator::Nsb,
+        ] {
+            assert_eq!(
+                samples.empirical_entropy_with_estimator(estimator)?,
+                counts.entropy_from_counts(estimator)?
+            );
+        }
+        assert_abs_diff_eq!(
+            samples.empirical_entropy_with_estimator(EntropyEstimator::PlugIn)?,
+            samples.empirical_entropy()?,
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_from_counts_without_observations() {
+        let counts: Array1<u32> = array![];
+        assert_eq!(
+            counts.entropy_from_counts(EntropyEstimator::PlugIn),
+            Err(EmptyInput)
+        );
+        assert_eq!(
+            array![0, 0].entropy_from_counts(EntropyEstimator::Nsb),
+            Err(EmptyInput)
+        );
+        let samples: Array1<u32> = array![];
+        assert_eq!(
+            samples.empirical_entropy_with_estimator(EntropyEstimator::ChaoShen),
+            Err(EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_entropy_from_negative_counts() {
+        let _ = array![3, -1].entropy_from_counts(EntropyEstimator::PlugIn);
+    }
 }
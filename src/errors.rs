@@ -46,7 +46,7 @@ impl From<EmptyInput> for MinMaxError {
 /// An error used by methods and functions that take two arrays as argument and
 /// expect them to have exactly the same shape
 /// (e.g. `ShapeMismatch` is raised when `a.shape() == b.shape()` evaluates to `False`).
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ShapeMismatch {
     pub first_shape: Vec<usize>,
     pub second_shape: Vec<usize>,
@@ -76,18 +76,12 @@ pub enum MultiInputError {
 impl MultiInputError {
     /// Returns whether `self` is the `EmptyInput` variant.
     pub fn is_empty_input(&self) -> bool {
-        match self {
-            MultiInputError::EmptyInput => true,
-            _ => false,
-        }
+        matches!(self, MultiInputError::EmptyInput)
     }
 
     /// Returns whether `self` is the `ShapeMismatch` variant.
     pub fn is_shape_mismatch(&self) -> bool {
-        match self {
-            MultiInputError::ShapeMismatch(_) => true,
-            _ => false,
-        }
+        matches!(self, MultiInputError::ShapeMismatch(_))
     }
 }
 
@@ -121,6 +115,9 @@ pub enum QuantileError {
     EmptyInput,
     /// The `q` was not between `0.` and `1.` (inclusive).
     InvalidQuantile(N64),
+    /// The proportion to cut from each tail was not between `0.` (inclusive)
+    /// and `0.5` (exclusive).
+    InvalidProportion(N64),
 }
 
 impl fmt::Display for QuantileError {
@@ -130,6 +127,11 @@ impl fmt::Display for QuantileError {
             QuantileError::InvalidQuantile(q) => {
                 write!(f, "{:} is not between 0. and 1. (inclusive).", q)
             }
+            QuantileError::InvalidProportion(p) => write!(
+                f,
+                "{:} is not between 0. (inclusive) and 0.5 (exclusive).",
+                p
+            ),
         }
     }
 }
@@ -141,3 +143,226 @@ impl From<EmptyInput> for QuantileError {
         QuantileError::EmptyInput
     }
 }
+
+/// An error computing a quantile of the unmasked elements of an array.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MaskedQuantileError {
+    /// The input was empty.
+    EmptyInput,
+    /// The `q` was not between `0.` and `1.` (inclusive).
+    InvalidQuantile(N64),
+    /// The mask did not have the same shape as the array.
+    ShapeMismatch(ShapeMismatch),
+}
+
+impl fmt::Display for MaskedQuantileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaskedQuantileError::EmptyInput => write!(f, "Empty input."),
+            MaskedQuantileError::InvalidQuantile(q) => {
+                write!(f, "{:} is not between 0. and 1. (inclusive).", q)
+            }
+            MaskedQuantileError::ShapeMismatch(e) => write!(f, "Shape mismatch: {}", e),
+        }
+    }
+}
+
+impl Error for MaskedQuantileError {}
+
+impl From<EmptyInput> for MaskedQuantileError {
+    fn from(_: EmptyInput) -> MaskedQuantileError {
+        MaskedQuantileError::EmptyInput
+    }
+}
+
+impl From<ShapeMismatch> for MaskedQuantileError {
+    fn from(err: ShapeMismatch) -> MaskedQuantileError {
+        MaskedQuantileError::ShapeMismatch(err)
+    }
+}
+
+/// An error computing an estimator that is only defined for a minimum number
+/// of elements.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EstimatorError {
+    /// The input was empty.
+    EmptyInput,
+    /// The input had fewer elements than the estimator requires.
+    NotEnoughElements {
+        /// The minimum number of elements required by the estimator.
+        min_len: usize,
+        /// The number of elements of the input.
+        len: usize,
+    },
+}
+
+impl fmt::Display for EstimatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EstimatorError::EmptyInput => write!(f, "Empty input."),
+            EstimatorError::NotEnoughElements { min_len, len } => write!(
+                f,
+                "The estimator requires at least {} elements, but the input has {}.",
+                min_len, len
+            ),
+        }
+    }
+}
+
+impl Error for EstimatorError {}
+
+impl From<EmptyInput> for EstimatorError {
+    fn from(_: EmptyInput) -> EstimatorError {
+        EstimatorError::EmptyInput
+    }
+}
+
+/// An error computing a confidence interval.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfidenceIntervalError {
+    /// The input was empty.
+    EmptyInput,
+    /// The input had fewer elements than the interval requires.
+    NotEnoughElements {
+        /// The minimum number of elements required by the interval.
+        min_len: usize,
+        /// The number of elements of the input.
+        len: usize,
+    },
+    /// The input and the weights did not have the same shape.
+    ShapeMismatch(ShapeMismatch),
+    /// The confidence level was not strictly between zero and one.
+    InvalidLevel,
+}
+
+impl ConfidenceIntervalError {
+    /// Returns whether `self` is the `EmptyInput` variant.
+    pub fn is_empty_input(&self) -> bool {
+        matches!(self, ConfidenceIntervalError::EmptyInput)
+    }
+
+    /// Returns whether `self` is the `ShapeMismatch` variant.
+    pub fn is_shape_mismatch(&self) -> bool {
+        matches!(self, ConfidenceIntervalError::ShapeMismatch(_))
+    }
+
+    /// Returns whether `self` is the `InvalidLevel` variant.
+    pub fn is_invalid_level(&self) -> bool {
+        matches!(self, ConfidenceIntervalError::InvalidLevel)
+    }
+}
+
+impl fmt::Display for ConfidenceIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfidenceIntervalError::EmptyInput => write!(f, "Empty input."),
+            ConfidenceIntervalError::NotEnoughElements { min_len, len } => write!(
+                f,
+                "The interval requires at least {} elements, but the input has {}.",
+                min_len, len
+            ),
+            ConfidenceIntervalError::ShapeMismatch(e) => write!(f, "Shape mismatch: {}", e),
+            ConfidenceIntervalError::InvalidLevel => write!(
+                f,
+                "The confidence level must be strictly between zero and one."
+            ),
+        }
+    }
+}
+
+impl Error for ConfidenceIntervalError {}
+
+impl From<EstimatorError> for ConfidenceIntervalError {
+    fn from(err: EstimatorError) -> Self {
+        match err {
+            EstimatorError::EmptyInput => ConfidenceIntervalError::EmptyInput,
+            EstimatorError::NotEnoughElements { min_len, len } => {
+                ConfidenceIntervalError::NotEnoughElements { min_len, len }
+            }
+        }
+    }
+}
+
+impl From<MultiInputError> for ConfidenceIntervalError {
+    fn from(err: MultiInputError) -> Self {
+        match err {
+            MultiInputError::EmptyInput => ConfidenceIntervalError::EmptyInput,
+            MultiInputError::ShapeMismatch(e) => ConfidenceIntervalError::ShapeMismatch(e),
+        }
+    }
+}
+
+/// An error computing a generalized (power) mean.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PowerMeanError {
+    /// The input was empty.
+    EmptyInput,
+    /// The input and the weights did not have the same shape.
+    ShapeMismatch(ShapeMismatch),
+    /// The input contained a value outside of the domain of the mean
+    /// (a negative value, or a zero for a non-positive exponent).
+    NonPositiveValue,
+    /// The weights contained a negative value.
+    NegativeWeight,
+}
+
+impl PowerMeanError {
+    /// Returns whether `self` is the `EmptyInput` variant.
+    pub fn is_empty_input(&self) -> bool {
+        matches!(self, PowerMeanError::EmptyInput)
+    }
+
+    /// Returns whether `self` is the `ShapeMismatch` variant.
+    pub fn is_shape_mismatch(&self) -> bool {
+        matches!(self, PowerMeanError::ShapeMismatch(_))
+    }
+
+    /// Returns whether `self` is the `NonPositiveValue` variant.
+    pub fn is_non_positive_value(&self) -> bool {
+        matches!(self, PowerMeanError::NonPositiveValue)
+    }
+
+    /// Returns whether `self` is the `NegativeWeight` variant.
+    pub fn is_negative_weight(&self) -> bool {
+        matches!(self, PowerMeanError::NegativeWeight)
+    }
+}
+
+impl fmt::Display for PowerMeanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerMeanError::EmptyInput => write!(f, "Empty input."),
+            PowerMeanError::ShapeMismatch(e) => write!(f, "Shape mismatch: {}", e),
+            PowerMeanError::NonPositiveValue => {
+                write!(
+                    f,
+                    "The input contains values outside of the domain of the mean."
+                )
+            }
+            PowerMeanError::NegativeWeight => write!(f, "The weights contain negative values."),
+        }
+    }
+}
+
+impl Error for PowerMeanError {}
+
+impl From<EmptyInput> for PowerMeanError {
+    fn from(_: EmptyInput) -> Self {
+        PowerMeanError::EmptyInput
+    }
+}
+
+impl From<MultiInputError> for PowerMeanError {
+    fn from(err: MultiInputError) -> Self {
+        match err {
+            MultiInputError::EmptyInput => PowerMeanError::EmptyInput,
+            MultiInputError::ShapeMismatch(e) => PowerMeanError::ShapeMismatch(e),
+        }
+    }
+}
+
+impl From<ShapeMismatch> for PowerMeanError {
+    fn from(err: ShapeMismatch) -> Self {
+        PowerMeanError::ShapeMismatch(err)
+    }
+}
@@ -0,0 +1,699 @@
+use crate::errors::MultiInputError;
+use crate::maybe_nan::MaybeNan;
+use ndarray::{Array, ArrayBase, ArrayView1, ArrayViewMut1, Axis, Data, Dimension, Zip};
+use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The parameters of the exponentially weighted statistics of [`EwmExt`]
+/// (and of [`ewm_cov_matrix`] and [`ewm_pearson_correlation`]).
+///
+/// The weights decay by a factor `1 - α` per observation, where the smoothing
+/// factor `α` can be given directly or derived from a span, a center of mass
+/// or a half-life. As in pandas' `ewm`:
+///
+/// * in the *adjusted* mode (the default), the statistics at index `t` are
+///   computed with the weights `(1 - α)ᵗ⁻ⁱ` of the observations `xᵢ` for
+///   `i <= t`, normalized by their sum:
+///
+///   ```text
+///         xₜ + (1 - α)xₜ₋₁ + (1 - α)²xₜ₋₂ + … + (1 - α)ᵗx₀
+///   yₜ = ――――――――――――――――――――――――――――――――――――――――――――――――
+///            1 + (1 - α) + (1 - α)² + … + (1 - α)ᵗ
+///   ```
+///
+/// * otherwise, they follow the recursion `y₀ = x₀`, `yₜ = (1 - α)yₜ₋₁ + αxₜ`.
+///
+/// NaN values are not observations: the statistics are carried over them.
+/// By default, the weights still decay with the absolute position of the
+/// observations (i.e. the weight of an observation decays across NaN values
+/// too); with [`ignore_nan`], they decay with the relative position of the
+/// observations instead. A value is produced once at least
+/// [`min_periods`] observations have been seen (one, by default).
+///
+/// # Example
+///
+/// ```
+/// use ndarray_stats::Ewm;
+///
+/// assert_eq!(Ewm::with_span(3.).alpha(), 0.5);
+/// assert_eq!(Ewm::with_center_of_mass(1.).alpha(), 0.5);
+/// assert_eq!(Ewm::with_halflife(1.).alpha(), 0.5);
+/// let ewm = Ewm::with_alpha(0.5).adjust(false).min_periods(3);
+/// ```
+///
+/// [`EwmExt`]: trait.EwmExt.html
+/// [`ewm_cov_matrix`]: trait.CorrelationExt.html#tymethod.ewm_cov_matrix
+/// [`ewm_pearson_correlation`]: trait.CorrelationExt.html#tymethod.ewm_pearson_correlation
+/// [`ignore_nan`]: #method.ignore_nan
+/// [`min_periods`]: #method.min_periods
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ewm {
+    alpha: f64,
+    adjust: bool,
+    ignore_nan: bool,
+    min_periods: usize,
+}
+
+impl Ewm {
+    /// Returns the parameters for the smoothing factor `alpha`.
+    ///
+    /// **Panics** if `alpha` is not in the interval `(0, 1]`.
+    pub fn with_alpha(alpha: f64) -> Self {
+        assert!(
+            alpha > 0. && alpha <= 1.,
+            "The smoothing factor must be in the interval (0, 1]."
+        );
+        Ewm {
+            alpha,
+            adjust: true,
+            ignore_nan: false,
+            min_periods: 1,
+        }
+    }
+
+    /// Returns the parameters for the span `s`, i.e. `α = 2 / (s + 1)`.
+    ///
+    /// **Panics** if `span` is smaller than 1.
+    pub fn with_span(span: f64) -> Self {
+        assert!(span >= 1., "The span must be at least 1.");
+        Self::with_alpha(2. / (span + 1.))
+    }
+
+    /// Returns the parameters for the center of mass `c`, i.e. `α = 1 / (1 + c)`.
+    ///
+    /// **Panics** if `center_of_mass` is negative.
+    pub fn with_center_of_mass(center_of_mass: f64) -> Self {
+        assert!(
+            center_of_mass >= 0.,
+            "The center of mass must not be negative."
+        );
+        Self::with_alpha(1. / (1. + center_of_mass))
+    }
+
+    /// Returns the parameters for the half-life `h`, i.e. the number of
+    /// observations after which the weights are halved: `α = 1 - exp(-ln(2) / h)`.
+    ///
+    /// **Panics** if `halflife` is not positive.
+    pub fn with_halflife(halflife: f64) -> Self {
+        assert!(halflife > 0., "The half-life must be positive.");
+        Self::with_alpha(1. - (-std::f64::consts::LN_2 / halflife).exp())
+    }
+
+    /// Sets whether the weights are normalized by their sum (the default) or
+    /// the statistics follow the recursive definition.
+    pub fn adjust(mut self, adjust: bool) -> Self {
+        self.adjust = adjust;
+        self
+    }
+
+    /// Sets whether the weights decay with the relative position of the
+    /// observations, ignoring the NaN values, instead of their absolute
+    /// position (the default).
+    pub fn ignore_nan(mut self, ignore_nan: bool) -> Self {
+        self.ignore_nan = ignore_nan;
+        self
+    }
+
+    /// Sets the minimum number of observations required to produce a value.
+    ///
+    /// At least one observation is always required.
+    pub fn min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    /// Returns the smoothing factor.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+/// Extension trait for `ArrayBase` providing exponentially weighted moving
+/// statistics along an axis.
+///
+/// All methods return an array of the same shape as the input, whose lanes
+/// along `axis` hold the statistic of the observations up to each element,
+/// weighted as described in [`Ewm`] (NaN until [`min_periods`] observations
+/// have been seen).
+///
+/// The elements can be of any [`MaybeNan`] type, e.g. floats or `Option`s of
+/// numbers: the NaN values (the `None`s) are not observations, and the
+/// statistics are computed with `A::NotNan`.
+///
+/// [`Ewm`]: struct.Ewm.html
+/// [`min_periods`]: struct.Ewm.html#method.min_periods
+/// [`MaybeNan`]: trait.MaybeNan.html
+pub trait EwmExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the exponentially weighted moving mean along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::NotNan::from_f64()`
+    /// fails to convert the smoothing factor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{Ewm, EwmExt};
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![1., 2., f64::NAN, 4.];
+    /// let ewm = Ewm::with_alpha(0.5);
+    /// // The last mean is (0.125 * 1 + 0.25 * 2 + 4) / (0.125 + 0.25 + 1)
+    /// assert_abs_diff_eq!(
+    ///     a.ewm_mean(Axis(0), &ewm),
+    ///     array![1., 5. / 3., 5. / 3., 37. / 11.],
+    ///     epsilon = 1e-12
+    /// );
+    /// let ewm = ewm.adjust(false).ignore_nan(true);
+    /// assert_eq!(a.ewm_mean(Axis(0), &ewm), array![1., 1.5, 1.5, 2.75]);
+    /// let b = array![Some(n64(1.)), Some(n64(2.)), None, Some(n64(4.))];
+    /// assert_eq!(
+    ///     b.ewm_mean(Axis(0), &ewm),
+    ///     array![Some(n64(1.)), Some(n64(1.5)), Some(n64(1.5)), Some(n64(2.75))]
+    /// );
+    /// ```
+    fn ewm_mean(&self, axis: Axis, ewm: &Ewm) -> Array<A, D>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>;
+
+    /// Returns the exponentially weighted moving variance along `axis`.
+    ///
+    /// If `bias` is `false`, the variance is corrected for the bias of the
+    /// weighted estimator, dividing by `1 - ∑ wᵢ² / (∑ wᵢ)²` (so that it's NaN
+    /// after a single observation); otherwise it is the weighted mean of the
+    /// squared deviations from the weighted mean.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::NotNan::from_f64()`
+    /// fails to convert the smoothing factor.
+    fn ewm_var(&self, axis: Axis, ewm: &Ewm, bias: bool) -> Array<A, D>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>;
+
+    /// Returns the exponentially weighted moving standard deviation along
+    /// `axis`, i.e. the square root of [`ewm_var`] (taken in `f64`).
+    ///
+    /// **Panics** if `axis` is out of bounds or if the conversions of the
+    /// smoothing factor and of the variances between `f64` and `A::NotNan`
+    /// fail.
+    ///
+    /// [`ewm_var`]: #tymethod.ewm_var
+    fn ewm_std(&self, axis: Axis, ewm: &Ewm, bias: bool) -> Array<A, D>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>;
+
+    /// Returns the exponentially weighted moving covariance between the lanes
+    /// of `self` and `other` along `axis`.
+    ///
+    /// Only the pairs of elements that are both non-NaN are observations.
+    /// See [`ewm_var`] for the meaning of `bias`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::NotNan::from_f64()`
+    /// fails to convert the smoothing factor.
+    ///
+    /// [`ewm_var`]: #tymethod.ewm_var
+    fn ewm_cov<S2>(
+        &self,
+        other: &ArrayBase<S2, D>,
+        axis: Axis,
+        ewm: &Ewm,
+        bias: bool,
+    ) -> Result<Array<A, D>, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        S2: Data<Elem = A>;
+
+    /// Returns the exponentially weighted moving Pearson correlation between
+    /// the lanes of `self` and `other` along `axis`.
+    ///
+    /// Only the pairs of elements that are both non-NaN are observations: the
+    /// variances are computed from the same observations as the covariance,
+    /// so that the correlation is always between -1 and 1. It is NaN while
+    /// either variance is zero (e.g. after a single observation).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the conversions of the
+    /// smoothing factor and of the product of the variances between `f64` and
+    /// `A::NotNan` fail.
+    fn ewm_corr<S2>(
+        &self,
+        other: &ArrayBase<S2, D>,
+        axis: Axis,
+        ewm: &Ewm,
+    ) -> Result<Array<A, D>, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        S2: Data<Elem = A>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> EwmExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn ewm_mean(&self, axis: Axis, ewm: &Ewm) -> Array<A, D>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+    {
+        ewm_pairwise(self, self, axis, ewm, |state| Some(state.mean_x()))
+    }
+
+    fn ewm_var(&self, axis: Axis, ewm: &Ewm, bias: bool) -> Array<A, D>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+    {
+        ewm_pairwise(self, self, axis, ewm, |state| state.cov(bias))
+    }
+
+    fn ewm_std(&self, axis: Axis, ewm: &Ewm, bias: bool) -> Array<A, D>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+    {
+        ewm_pairwise(self, self, axis, ewm, |state| state.cov(bias).map(sqrt))
+    }
+
+    fn ewm_cov<S2>(
+        &self,
+        other: &ArrayBase<S2, D>,
+        axis: Axis,
+        ewm: &Ewm,
+        bias: bool,
+    ) -> Result<Array<A, D>, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        S2: Data<Elem = A>,
+    {
+        return_err_unless_same_shape!(self, other);
+        Ok(ewm_pairwise(self, other, axis, ewm, |state| {
+            state.cov(bias)
+        }))
+    }
+
+    fn ewm_corr<S2>(
+        &self,
+        other: &ArrayBase<S2, D>,
+        axis: Axis,
+        ewm: &Ewm,
+    ) -> Result<Array<A, D>, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + PartialOrd
+            + FromPrimitive
+            + ToPrimitive
+            + Zero
+            + One
+            + Add<Output = A::NotNan>
+            + Sub<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        S2: Data<Elem = A>,
+    {
+        return_err_unless_same_shape!(self, other);
+        Ok(ewm_pairwise(self, other, axis, ewm, EwmState::corr))
+    }
+
+    private_impl! {}
+}
+
+/// Applies `statistic` to the exponentially weighted state of each pair of
+/// lanes of `x` and `y` along `axis`, which must have the same shape.
+fn ewm_pairwise<A, S, S2, D, F>(
+    x: &ArrayBase<S, D>,
+    y: &ArrayBase<S2, D>,
+    axis: Axis,
+    ewm: &Ewm,
+    statistic: F,
+) -> Array<A, D>
+where
+    A: MaybeNan,
+    A::NotNan: Clone
+        + PartialOrd
+        + FromPrimitive
+        + Zero
+        + One
+        + Add<Output = A::NotNan>
+        + Sub<Output = A::NotNan>
+        + Mul<Output = A::NotNan>
+        + Div<Output = A::NotNan>,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    F: Fn(&EwmState<A::NotNan>) -> Option<A::NotNan>,
+{
+    debug_assert_eq!(x.shape(), y.shape());
+    let mut result = Array::from_shape_simple_fn(x.raw_dim(), || A::from_not_nan_opt(None));
+    Zip::from(result.lanes_mut(axis))
+        .and(x.lanes(axis))
+        .and(y.lanes(axis))
+        .for_each(|output, x, y| ewm_lane(x, y, output, ewm, &statistic));
+    result
+}
+
+/// Writes `statistic` of the exponentially weighted state after each pair of
+/// elements of `x` and `y` to `output`.
+fn ewm_lane<A, F>(
+    x: ArrayView1<'_, A>,
+    y: ArrayView1<'_, A>,
+    mut output: ArrayViewMut1<'_, A>,
+    ewm: &Ewm,
+    statistic: F,
+) where
+    A: MaybeNan,
+    A::NotNan: Clone
+        + PartialOrd
+        + FromPrimitive
+        + Zero
+        + One
+        + Add<Output = A::NotNan>
+        + Sub<Output = A::NotNan>
+        + Mul<Output = A::NotNan>
+        + Div<Output = A::NotNan>,
+    F: Fn(&EwmState<A::NotNan>) -> Option<A::NotNan>,
+{
+    let mut state = EwmState::new(ewm);
+    Zip::from(&mut output)
+        .and(&x)
+        .and(&y)
+        .for_each(|output, x, y| {
+            state.push(x.try_as_not_nan(), y.try_as_not_nan());
+            if state.has_min_periods() {
+                *output = A::from_not_nan_opt(statistic(&state));
+            }
+        });
+}
+
+/// Returns `statistic` of the exponentially weighted state after all the
+/// pairs of elements of `x` and `y`, or `None` if there are fewer than
+/// `min_periods` observations.
+pub(crate) fn ewm_last<A, F>(
+    x: ArrayView1<'_, A>,
+    y: ArrayView1<'_, A>,
+    ewm: &Ewm,
+    statistic: F,
+) -> Option<A::NotNan>
+where
+    A: MaybeNan,
+    A::NotNan: Clone
+        + PartialOrd
+        + FromPrimitive
+        + Zero
+        + One
+        + Add<Output = A::NotNan>
+        + Sub<Output = A::NotNan>
+        + Mul<Output = A::NotNan>
+        + Div<Output = A::NotNan>,
+    F: Fn(&EwmState<A::NotNan>) -> Option<A::NotNan>,
+{
+    let mut state = EwmState::new(ewm);
+    Zip::from(&x)
+        .and(&y)
+        .for_each(|x, y| state.push(x.try_as_not_nan(), y.try_as_not_nan()));
+    if state.has_min_periods() {
+        statistic(&state)
+    } else {
+        None
+    }
+}
+
+/// Returns the square root of `x`, taken in `f64`.
+pub(crate) fn sqrt<T>(x: T) -> T
+where
+    T: FromPrimitive + ToPrimitive,
+{
+    let x = x.to_f64().expect("Converting to `f64` must not fail.");
+    T::from_f64(x.sqrt()).expect("Converting from `f64` must not fail.")
+}
+
+/// The exponentially weighted moments of a pair of series of non-NaN values,
+/// updated one element at a time (the same recursion as pandas' `ewmcov`).
+pub(crate) struct EwmState<T> {
+    decay: T,
+    new_weight: T,
+    adjust: bool,
+    ignore_nan: bool,
+    min_periods: usize,
+    n_observations: usize,
+    /// The weight of the previous observations.
+    old_weight: T,
+    /// The sum of the weights and of their squares, relative to `old_weight`.
+    sum_weights: T,
+    sum_sq_weights: T,
+    mean_x: T,
+    mean_y: T,
+    /// The weighted means of the products of the deviations from the means.
+    cov_xy: T,
+    var_x: T,
+    var_y: T,
+}
+
+impl<T> EwmState<T>
+where
+    T: Clone
+        + PartialOrd
+        + FromPrimitive
+        + Zero
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    fn new(ewm: &Ewm) -> Self {
+        let alpha = T::from_f64(ewm.alpha)
+            .expect("Converting the smoothing factor to `A::NotNan` must not fail.");
+        EwmState {
+            decay: T::one() - alpha.clone(),
+            new_weight: if ewm.adjust { T::one() } else { alpha },
+            adjust: ewm.adjust,
+            ignore_nan: ewm.ignore_nan,
+            min_periods: ewm.min_periods.max(1),
+            n_observations: 0,
+            old_weight: T::one(),
+            sum_weights: T::one(),
+            sum_sq_weights: T::one(),
+            mean_x: T::zero(),
+            mean_y: T::zero(),
+            cov_xy: T::zero(),
+            var_x: T::zero(),
+            var_y: T::zero(),
+        }
+    }
+
+    /// Updates the state with a pair of elements, which is an observation
+    /// if neither is NaN (`None`).
+    fn push(&mut self, x: Option<&T>, y: Option<&T>) {
+        let observation = match (x, y) {
+            (Some(x), Some(y)) => Some((x.clone(), y.clone())),
+            _ => None,
+        };
+        if self.n_observations == 0 {
+            if let Some((x, y)) = observation {
+                self.n_observations = 1;
+                self.mean_x = x;
+                self.mean_y = y;
+            }
+            return;
+        }
+        if observation.is_none() && self.ignore_nan {
+            return;
+        }
+        let decay = self.decay.clone();
+        self.sum_weights = self.sum_weights.clone() * decay.clone();
+        self.sum_sq_weights = self.sum_sq_weights.clone() * decay.clone() * decay.clone();
+        self.old_weight = self.old_weight.clone() * decay;
+        let (x, y) = match observation {
+            Some(observation) => observation,
+            None => return,
+        };
+        self.n_observations += 1;
+
+        let (old_weight, new_weight) = (self.old_weight.clone(), self.new_weight.clone());
+        let total_weight = old_weight.clone() + new_weight.clone();
+        let update_mean = |mean: T, x: T| {
+            // Avoids rounding errors for constant series
+            if mean == x {
+                mean
+            } else {
+                (old_weight.clone() * mean + new_weight.clone() * x) / total_weight.clone()
+            }
+        };
+        let (old_mean_x, old_mean_y) = (self.mean_x.clone(), self.mean_y.clone());
+        self.mean_x = update_mean(old_mean_x.clone(), x.clone());
+        self.mean_y = update_mean(old_mean_y.clone(), y.clone());
+        let shift_x = old_mean_x - self.mean_x.clone();
+        let shift_y = old_mean_y - self.mean_y.clone();
+        let dev_x = x - self.mean_x.clone();
+        let dev_y = y - self.mean_y.clone();
+        let update_moment = |moment: T, shift_product: T, dev_product: T| {
+            (old_weight.clone() * (moment + shift_product) + new_weight.clone() * dev_product)
+                / total_weight.clone()
+        };
+        self.cov_xy = update_moment(
+            self.cov_xy.clone(),
+            shift_x.clone() * shift_y.clone(),
+            dev_x.clone() * dev_y.clone(),
+        );
+        self.var_x = update_moment(
+            self.var_x.clone(),
+            shift_x.clone() * shift_x,
+            dev_x.clone() * dev_x,
+        );
+        self.var_y = update_moment(
+            self.var_y.clone(),
+            shift_y.clone() * shift_y,
+            dev_y.clone() * dev_y,
+        );
+
+        self.sum_weights = self.sum_weights.clone() + new_weight.clone();
+        self.sum_sq_weights = self.sum_sq_weights.clone() + new_weight.clone() * new_weight;
+        self.old_weight = total_weight.clone();
+        if !self.adjust {
+            self.sum_weights = self.sum_weights.clone() / total_weight.clone();
+            self.sum_sq_weights =
+                self.sum_sq_weights.clone() / (total_weight.clone() * total_weight);
+            self.old_weight = T::one();
+        }
+    }
+
+    fn has_min_periods(&self) -> bool {
+        self.n_observations >= self.min_periods
+    }
+
+    /// Returns the exponentially weighted mean of the first series.
+    pub(crate) fn mean_x(&self) -> T {
+        self.mean_x.clone()
+    }
+
+    /// Returns the exponentially weighted covariance, with the bias
+    /// correction unless `bias` is `true`, or `None` if the correction is
+    /// undefined.
+    pub(crate) fn cov(&self, bias: bool) -> Option<T> {
+        if bias {
+            return Some(self.cov_xy.clone());
+        }
+        let sq_sum_weights = self.sum_weights.clone() * self.sum_weights.clone();
+        let denominator = sq_sum_weights.clone() - self.sum_sq_weights.clone();
+        if denominator > T::zero() {
+            Some(self.cov_xy.clone() * sq_sum_weights / denominator)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the exponentially weighted Pearson correlation, or `None` if
+    /// either variance is zero.
+    pub(crate) fn corr(&self) -> Option<T>
+    where
+        T: ToPrimitive,
+    {
+        let denominator = sqrt(self.var_x.clone() * self.var_y.clone());
+        if denominator > T::zero() {
+            Some(self.cov_xy.clone() / denominator)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,585 @@
+use crate::errors::{EmptyInput, MultiInputError};
+use crate::maybe_nan::MaybeNan;
+use indexmap::IndexMap;
+use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, RemoveAxis, Zip};
+use num_traits::{Float, Zero};
+use std::hash::Hash;
+use std::ops::AddAssign;
+
+/// Extension trait for `ArrayBase` providing methods to compute
+/// statistics based on the frequencies of the array elements (e.g. the mode,
+/// frequency tables).
+///
+/// The elements are compared by value, so they must implement `Eq` and `Hash`:
+/// this is typically the case for integers, categorical codes or strings.
+/// Floating point arrays can use the `_skipnan` variants through
+/// [`MaybeNan`], whose non-NaN counterparts (e.g. `N64`) are hashable.
+///
+/// [`MaybeNan`]: trait.MaybeNan.html
+pub trait FrequencyExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the [mode] of the array, i.e. its most frequent element.
+    ///
+    /// If several elements are equally frequent, the one that is found first
+    /// (in the logical order of the array) is returned.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::FrequencyExt;
+    ///
+    /// let a = array![[3, 1, 2], [2, 1, 4]];
+    /// assert_eq!(a.mode(), Ok(&1));
+    /// assert_eq!(a.multimode(), Ok(vec![&1, &2]));
+    /// ```
+    ///
+    /// [mode]: https://en.wikipedia.org/wiki/Mode_(statistics)
+    fn mode(&self) -> Result<&A, EmptyInput>
+    where
+        A: Eq + Hash;
+
+    /// Returns all the elements of the array that are as frequent as its
+    /// [mode], in the order in which they are first found (in the logical
+    /// order of the array).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// [mode]: #tymethod.mode
+    fn multimode(&self) -> Result<Vec<&A>, EmptyInput>
+    where
+        A: Eq + Hash;
+
+    /// Returns the [mode] of the array, skipping NaN values.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty or none of the values
+    /// in the array are non-NaN values.
+    ///
+    /// [mode]: #tymethod.mode
+    fn mode_skipnan(&self) -> Result<&A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Eq + Hash;
+
+    /// Returns all the elements of the array that are as frequent as its
+    /// [mode], skipping NaN values, see [`multimode`] for more details.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty or none of the values
+    /// in the array are non-NaN values.
+    ///
+    /// [mode]: #tymethod.mode
+    /// [`multimode`]: #tymethod.multimode
+    fn multimode_skipnan(&self) -> Result<Vec<&A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Eq + Hash;
+
+    /// Returns the [mode] of each 1-dimensional lane along `axis`.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [mode]: #tymethod.mode
+    fn mode_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis;
+
+    /// Returns the [mode] of each 1-dimensional lane along `axis`, skipping NaN values.
+    ///
+    /// Lanes made only of NaN values have a NaN mode.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [mode]: #tymethod.mode
+    fn mode_axis_skipnan(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Clone + MaybeNan,
+        A::NotNan: Eq + Hash,
+        D: RemoveAxis;
+
+    /// Returns the number of occurrences of each distinct element of the array,
+    /// in the order in which the elements are first found (in the logical
+    /// order of the array).
+    ///
+    /// The returned map can be sorted by value with [`IndexMap::sort_keys`], or
+    /// by count with [`IndexMap::sort_by`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::FrequencyExt;
+    ///
+    /// let a = array![3, 1, 3, 2, 3, 1];
+    /// let mut counts = a.value_counts();
+    /// assert_eq!(counts.iter().collect::<Vec<_>>(), vec![(&3, &3), (&1, &2), (&2, &1)]);
+    /// counts.sort_keys();
+    /// assert_eq!(counts.iter().collect::<Vec<_>>(), vec![(&1, &2), (&2, &1), (&3, &3)]);
+    /// ```
+    ///
+    /// [`IndexMap::sort_keys`]: https://docs.rs/indexmap/1/indexmap/map/struct.IndexMap.html#method.sort_keys
+    /// [`IndexMap::sort_by`]: https://docs.rs/indexmap/1/indexmap/map/struct.IndexMap.html#method.sort_by
+    fn value_counts(&self) -> IndexMap<A, usize>
+    where
+        A: Clone + Eq + Hash;
+
+    /// Returns the [`value_counts`] of each 1-dimensional lane along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::FrequencyExt;
+    ///
+    /// let a = array![[1, 2, 1], [3, 3, 3]];
+    /// let counts = a.value_counts_axis(Axis(1));
+    /// assert_eq!(counts[0].iter().collect::<Vec<_>>(), vec![(&1, &2), (&2, &1)]);
+    /// assert_eq!(counts[1].iter().collect::<Vec<_>>(), vec![(&3, &3)]);
+    /// ```
+    ///
+    /// [`value_counts`]: #tymethod.value_counts
+    fn value_counts_axis(&self, axis: Axis) -> Array<IndexMap<A, usize>, D::Smaller>
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis;
+
+    /// Returns the sum of the weights of each distinct element of the array,
+    /// in the order in which the elements are first found, see [`value_counts`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// [`value_counts`]: #tymethod.value_counts
+    fn weighted_value_counts<S2, W>(
+        &self,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<IndexMap<A, W>, MultiInputError>
+    where
+        A: Clone + Eq + Hash,
+        S2: Data<Elem = W>,
+        W: Clone + AddAssign + Zero;
+
+    /// Returns the proportion of the elements of the array that are equal to
+    /// each distinct element, in the order in which the elements are first
+    /// found, see [`value_counts`].
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// [`value_counts`]: #tymethod.value_counts
+    fn proportions(&self) -> Result<IndexMap<A, f64>, EmptyInput>
+    where
+        A: Clone + Eq + Hash;
+
+    /// Returns the [`proportions`] of each 1-dimensional lane along `axis`.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`proportions`]: #tymethod.proportions
+    fn proportions_axis(
+        &self,
+        axis: Axis,
+    ) -> Result<Array<IndexMap<A, f64>, D::Smaller>, EmptyInput>
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis;
+
+    /// Returns the proportion of the total weight carried by each distinct
+    /// element of the array, in the order in which the elements are first
+    /// found, see [`value_counts`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// [`value_counts`]: #tymethod.value_counts
+    fn weighted_proportions<S2, W>(
+        &self,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<IndexMap<A, W>, MultiInputError>
+    where
+        A: Clone + Eq + Hash,
+        S2: Data<Elem = W>,
+        W: Float + AddAssign;
+
+    /// Returns the number of occurrences of each of the given `levels` in the
+    /// array, in the order of `levels`.
+    ///
+    /// Elements of the array that are not in `levels` are not counted, so an
+    /// integer range can be used to tabulate integer codes, including the ones
+    /// that don't occur. Repeated levels are ignored after their first
+    /// occurrence, so the result has one count per distinct level.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::FrequencyExt;
+    ///
+    /// let a = array![3, 1, 3, 0, 7];
+    /// assert_eq!(a.level_counts(0..5), array![1, 1, 0, 2, 0]);
+    /// ```
+    fn level_counts<I>(&self, levels: I) -> Array1<usize>
+    where
+        A: Eq + Hash,
+        I: IntoIterator<Item = A>;
+
+    /// Returns the sum of the weights of the elements of the array equal to
+    /// each of the given `levels`, in the order of `levels`, see [`level_counts`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// [`level_counts`]: #tymethod.level_counts
+    fn weighted_level_counts<I, S2, W>(
+        &self,
+        levels: I,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<Array1<W>, MultiInputError>
+    where
+        A: Eq + Hash,
+        I: IntoIterator<Item = A>,
+        S2: Data<Elem = W>,
+        W: Clone + AddAssign + Zero;
+
+    /// Returns the number of occurrences of each of the given `levels` in each
+    /// 1-dimensional lane along `axis`, see [`level_counts`].
+    ///
+    /// The result has the same shape as the array, except along `axis`, whose
+    /// length is the number of levels: the subview of the result along `axis`
+    /// at index `i` holds the counts of `levels[i]`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`level_counts`]: #tymethod.level_counts
+    fn level_counts_axis<I>(&self, axis: Axis, levels: I) -> Array<usize, D>
+    where
+        A: Eq + Hash,
+        D: RemoveAxis,
+        I: IntoIterator<Item = A>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> FrequencyExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn mode(&self) -> Result<&A, EmptyInput>
+    where
+        A: Eq + Hash,
+    {
+        first_mode(self.iter().map(|x| (x, x))).ok_or(EmptyInput)
+    }
+
+    fn multimode(&self) -> Result<Vec<&A>, EmptyInput>
+    where
+        A: Eq + Hash,
+    {
+        return_err_if_no_mode(multimode(self.iter().map(|x| (x, x))))
+    }
+
+    fn mode_skipnan(&self) -> Result<&A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Eq + Hash,
+    {
+        first_mode(not_nan_keys(self.iter())).ok_or(EmptyInput)
+    }
+
+    fn multimode_skipnan(&self) -> Result<Vec<&A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Eq + Hash,
+    {
+        return_err_if_no_mode(multimode(not_nan_keys(self.iter())))
+    }
+
+    fn mode_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            first_mode(lane.into_iter().map(|x| (x, x)))
+                .unwrap()
+                .clone()
+        }))
+    }
+
+    fn mode_axis_skipnan(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Clone + MaybeNan,
+        A::NotNan: Eq + Hash,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            match first_mode(not_nan_keys(lane.into_iter())) {
+                Some(mode) => mode.clone(),
+                None => A::from_not_nan_opt(None),
+            }
+        }))
+    }
+
+    fn value_counts(&self) -> IndexMap<A, usize>
+    where
+        A: Clone + Eq + Hash,
+    {
+        let mut counts = IndexMap::new();
+        for x in self.iter() {
+            add_to_table(&mut counts, x, 1);
+        }
+        counts
+    }
+
+    fn value_counts_axis(&self, axis: Axis) -> Array<IndexMap<A, usize>, D::Smaller>
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.value_counts())
+    }
+
+    fn weighted_value_counts<S2, W>(
+        &self,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<IndexMap<A, W>, MultiInputError>
+    where
+        A: Clone + Eq + Hash,
+        S2: Data<Elem = W>,
+        W: Clone + AddAssign + Zero,
+    {
+        return_err_unless_same_shape!(self, weights);
+        let mut counts = IndexMap::new();
+        Zip::from(self)
+            .and(weights)
+            .for_each(|x, w| add_to_table(&mut counts, x, w.clone()));
+        Ok(counts)
+    }
+
+    fn proportions(&self) -> Result<IndexMap<A, f64>, EmptyInput>
+    where
+        A: Clone + Eq + Hash,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let n = self.len() as f64;
+        Ok(self
+            .value_counts()
+            .into_iter()
+            .map(|(x, count)| (x, count as f64 / n))
+            .collect())
+    }
+
+    fn proportions_axis(
+        &self,
+        axis: Axis,
+    ) -> Result<Array<IndexMap<A, f64>, D::Smaller>, EmptyInput>
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            lane.proportions()
+                .expect("The lanes along a non-empty axis are not empty.")
+        }))
+    }
+
+    fn weighted_proportions<S2, W>(
+        &self,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<IndexMap<A, W>, MultiInputError>
+    where
+        A: Clone + Eq + Hash,
+        S2: Data<Elem = W>,
+        W: Float + AddAssign,
+    {
+        return_err_if_empty!(self);
+        let mut counts = self.weighted_value_counts(weights)?;
+        let total = weights.sum();
+        for count in counts.values_mut() {
+            *count = *count / total;
+        }
+        Ok(counts)
+    }
+
+    fn level_counts<I>(&self, levels: I) -> Array1<usize>
+    where
+        A: Eq + Hash,
+        I: IntoIterator<Item = A>,
+    {
+        let levels = level_indices(levels);
+        let mut counts = Array1::zeros(levels.len());
+        for x in self.iter() {
+            if let Some(&i) = levels.get(x) {
+                counts[i] += 1;
+            }
+        }
+        counts
+    }
+
+    fn weighted_level_counts<I, S2, W>(
+        &self,
+        levels: I,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<Array1<W>, MultiInputError>
+    where
+        A: Eq + Hash,
+        I: IntoIterator<Item = A>,
+        S2: Data<Elem = W>,
+        W: Clone + AddAssign + Zero,
+    {
+        return_err_unless_same_shape!(self, weights);
+        let levels = level_indices(levels);
+        let mut counts = Array1::zeros(levels.len());
+        Zip::from(self).and(weights).for_each(|x, w| {
+            if let Some(&i) = levels.get(x) {
+                counts[i] += w.clone();
+            }
+        });
+        Ok(counts)
+    }
+
+    fn level_counts_axis<I>(&self, axis: Axis, levels: I) -> Array<usize, D>
+    where
+        A: Eq + Hash,
+        D: RemoveAxis,
+        I: IntoIterator<Item = A>,
+    {
+        let levels = level_indices(levels);
+        let mut shape = self.raw_dim();
+        shape[axis.index()] = levels.len();
+        let mut counts = Array::zeros(shape);
+        Zip::from(counts.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut counts, lane| {
+                for x in lane {
+                    if let Some(&i) = levels.get(x) {
+                        counts[i] += 1;
+                    }
+                }
+            });
+        counts
+    }
+
+    private_impl! {}
+}
+
+/// Adds `count` to the entry of `x` in `table`, inserting it if needed.
+fn add_to_table<A, W>(table: &mut IndexMap<A, W>, x: &A, count: W)
+where
+    A: Clone + Eq + Hash,
+    W: AddAssign + Zero,
+{
+    match table.get_mut(x) {
+        Some(total) => *total += count,
+        None => {
+            table.insert(x.clone(), count);
+        }
+    }
+}
+
+/// Maps each distinct level to its position among the distinct `levels`.
+fn level_indices<A, I>(levels: I) -> IndexMap<A, usize>
+where
+    A: Eq + Hash,
+    I: IntoIterator<Item = A>,
+{
+    let mut indices = IndexMap::new();
+    for level in levels {
+        let next_index = indices.len();
+        indices.entry(level).or_insert(next_index);
+    }
+    indices
+}
+
+/// Pairs each non-NaN value with its `NotNan` counterpart, which is used as key.
+fn not_nan_keys<'a, A>(
+    values: impl Iterator<Item = &'a A>,
+) -> impl Iterator<Item = (&'a A::NotNan, &'a A)>
+where
+    A: 'a + MaybeNan,
+{
+    values.filter_map(|x| x.try_as_not_nan().map(|key| (key, x)))
+}
+
+/// Counts the occurrences of each key, in the order in which the keys are
+/// first found, together with the first value found for each key.
+fn counts<'a, K, T>(values: impl Iterator<Item = (&'a K, &'a T)>) -> IndexMap<&'a K, (&'a T, usize)>
+where
+    K: 'a + Eq + Hash,
+    T: 'a,
+{
+    let mut counts = IndexMap::new();
+    for (key, value) in values {
+        counts.entry(key).or_insert((value, 0)).1 += 1;
+    }
+    counts
+}
+
+/// Returns the first value with the highest count, or `None` if there are no values.
+fn first_mode<'a, K, T>(values: impl Iterator<Item = (&'a K, &'a T)>) -> Option<&'a T>
+where
+    K: 'a + Eq + Hash,
+    T: 'a,
+{
+    let mut mode = None;
+    let mut max_count = 0;
+    for (_, (value, count)) in counts(values) {
+        // Strict inequality: ties are resolved in favor of the first value found.
+        if count > max_count {
+            mode = Some(value);
+            max_count = count;
+        }
+    }
+    mode
+}
+
+/// Returns all the values with the highest count, in the order in which they are first found.
+fn multimode<'a, K, T>(values: impl Iterator<Item = (&'a K, &'a T)>) -> Vec<&'a T>
+where
+    K: 'a + Eq + Hash,
+    T: 'a,
+{
+    let counts = counts(values);
+    let max_count = counts.values().map(|&(_, count)| count).max().unwrap_or(0);
+    counts
+        .into_iter()
+        .filter(|&(_, (_, count))| count == max_count)
+        .map(|(_, (value, _))| value)
+        .collect()
+}
+
+fn return_err_if_no_mode<T>(modes: Vec<T>) -> Result<Vec<T>, EmptyInput> {
+    if modes.is_empty() {
+        Err(EmptyInput)
+    } else {
+        Ok(modes)
+    }
+}
@@ -0,0 +1,432 @@
+//! Grouped statistics.
+use crate::errors::{QuantileError, ShapeMismatch};
+use crate::quantile::interpolate::{higher_index, lower_index, Interpolate, Linear};
+use ndarray::{Array, Array1, ArrayBase, ArrayView, ArrayView1, Axis, Data, Dimension, Ix1};
+use ndarray::{RemoveAxis, Zip};
+use noisy_float::types::{n64, N64};
+use num_traits::{Float, FromPrimitive};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The groups of the subviews of an array along an axis, as returned by
+/// [`group_by`].
+///
+/// Each subview along the axis belongs to the group of its label. The groups
+/// are ordered by the first appearance of their label, see [`keys`].
+///
+/// All the statistics are returned stacked: the results have the shape of
+/// the grouped array, except that the length of the axis is the number of
+/// groups, and the subview at index `g` along the axis holds the statistic
+/// of the lanes of the `g`-th group. The sums, means, variances and extrema
+/// are computed in a single pass over the array.
+///
+/// As for [`RollingExt`], NaN values are propagated: the statistic of a
+/// lane of a group containing a NaN value is NaN.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::{array, Axis};
+/// use ndarray_stats::GroupByExt;
+///
+/// let a = array![[1., 2.], [3., 4.], [5., 6.], [7., 9.]];
+/// let labels = array!["b", "a", "b", "b"];
+/// let groups = a.group_by(Axis(0), &labels).unwrap();
+/// assert_eq!(groups.keys(), &["b", "a"]);
+/// assert_eq!(groups.count(), array![3, 1]);
+/// assert_eq!(groups.mean(), array![[13. / 3., 17. / 3.], [3., 4.]]);
+/// assert_eq!(groups.max(), array![[7., 9.], [3., 4.]]);
+/// ```
+///
+/// [`group_by`]: trait.GroupByExt.html#tymethod.group_by
+/// [`keys`]: #method.keys
+/// [`RollingExt`]: trait.RollingExt.html
+#[derive(Clone, Debug)]
+pub struct GroupBy<'a, A, D: Dimension, K> {
+    data: ArrayView<'a, A, D>,
+    axis: Axis,
+    keys: Vec<K>,
+    /// The index of the group of each subview along `axis`.
+    codes: Vec<usize>,
+    /// The number of subviews along `axis` in each group.
+    counts: Vec<usize>,
+}
+
+impl<'a, A, D, K> GroupBy<'a, A, D, K>
+where
+    D: RemoveAxis,
+{
+    /// Returns the axis along which the subviews are grouped.
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// Returns the keys of the groups, in the order of their first
+    /// appearance in the labels.
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Returns the number of groups.
+    pub fn n_groups(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the number of subviews along the axis in each group.
+    pub fn count(&self) -> Array1<usize> {
+        Array1::from(self.counts.clone())
+    }
+
+    /// Returns the sums of the groups.
+    pub fn sum(&self) -> Array<A, D>
+    where
+        A: Float,
+    {
+        self.fold(A::zero(), |sum, &x, _| *sum = *sum + x)
+    }
+
+    /// Returns the arithmetic means of the groups.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// elements of a group.
+    pub fn mean(&self) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        let mut means = self.sum();
+        for (mut means, &count) in means.axis_iter_mut(self.axis).zip(&self.counts) {
+            let count = from_usize::<A>(count);
+            means.mapv_inplace(|sum| sum / count);
+        }
+        means
+    }
+
+    /// Returns the variances of the groups, computed with Welford's algorithm.
+    ///
+    /// The variance of a group of `n` elements is computed with `n - ddof`
+    /// degrees of freedom (see [`var_axis`]). It is NaN if `n - ddof` is not
+    /// positive.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// elements of a group.
+    ///
+    /// [`var_axis`]: https://docs.rs/ndarray/0.15/ndarray/struct.ArrayBase.html#method.var_axis
+    pub fn var(&self, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        let moments = self.fold((A::zero(), A::zero()), |(mean, sum_sq_dev), &x, n| {
+            let delta = x - *mean;
+            *mean = *mean + delta / from_usize(n);
+            *sum_sq_dev = *sum_sq_dev + delta * (x - *mean);
+        });
+        let mut vars = moments.mapv(|(_, sum_sq_dev)| sum_sq_dev);
+        for (mut vars, &count) in vars.axis_iter_mut(self.axis).zip(&self.counts) {
+            let dof = from_usize::<A>(count) - ddof;
+            if dof > A::zero() {
+                vars.mapv_inplace(|sum_sq_dev| sum_sq_dev / dof);
+            } else {
+                vars.fill(A::nan());
+            }
+        }
+        vars
+    }
+
+    /// Returns the standard deviations of the groups, i.e. the square roots
+    /// of their [`var`].
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// elements of a group.
+    ///
+    /// [`var`]: #method.var
+    pub fn std(&self, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        self.var(ddof).mapv_into(A::sqrt)
+    }
+
+    /// Returns the minima of the groups.
+    pub fn min(&self) -> Array<A, D>
+    where
+        A: Float,
+    {
+        self.fold(A::nan(), |min, &x, n| {
+            if n == 1 || x < *min || x.is_nan() {
+                *min = x;
+            }
+        })
+    }
+
+    /// Returns the maxima of the groups.
+    pub fn max(&self) -> Array<A, D>
+    where
+        A: Float,
+    {
+        self.fold(A::nan(), |max, &x, n| {
+            if n == 1 || x > *max || x.is_nan() {
+                *max = x;
+            }
+        })
+    }
+
+    /// Returns the medians of the groups, i.e. their 0.5-quantiles with
+    /// linear interpolation (see [`quantile`]).
+    ///
+    /// [`quantile`]: #method.quantile
+    pub fn median(&self) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        self.quantile(n64(0.5), &Linear)
+            .expect("0.5 is a valid quantile.")
+    }
+
+    /// Returns the `q`-quantiles of the groups, using the interpolation
+    /// strategy `I` (see [`quantile_axis_mut`] for the definition).
+    ///
+    /// The elements of each lane of each group are copied and sorted.
+    ///
+    /// Returns `Err(QuantileError::InvalidQuantile(q))` if `q` is not between
+    /// `0.` and `1.` (inclusive).
+    ///
+    /// [`quantile_axis_mut`]: trait.QuantileExt.html#tymethod.quantile_axis_mut
+    pub fn quantile<I>(&self, q: N64, _interpolate: &I) -> Result<Array<A, D>, QuantileError>
+    where
+        A: Float,
+        I: Interpolate<A>,
+    {
+        check_quantile(q)?;
+        Ok(self
+            .sorted_lanes()
+            .map(|values| sorted_quantile::<A, I>(values, q)))
+    }
+
+    /// A bulk version of [`quantile`], which sorts the lanes of the groups
+    /// only once for all the quantiles `qs`.
+    ///
+    /// The results are stacked along a new leading axis: the subview at
+    /// index `k` along `Axis(0)` holds the `qs[k]`-quantiles of the groups.
+    ///
+    /// Returns `Err(QuantileError::InvalidQuantile(q))` if any `q` in `qs` is
+    /// not between `0.` and `1.` (inclusive).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{interpolate::Linear, GroupByExt};
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![[1., 2.], [3., 4.], [5., 6.], [7., 9.]];
+    /// let labels = array!["b", "a", "b", "b"];
+    /// let groups = a.group_by(Axis(0), &labels).unwrap();
+    /// let quantiles = groups.quantiles(&array![n64(0.), n64(0.75)], &Linear).unwrap();
+    /// assert_eq!(
+    ///     quantiles,
+    ///     array![[[1., 2.], [3., 4.]], [[6., 7.5], [3., 4.]]]
+    /// );
+    /// ```
+    ///
+    /// [`quantile`]: #method.quantile
+    pub fn quantiles<S, I>(
+        &self,
+        qs: &ArrayBase<S, Ix1>,
+        _interpolate: &I,
+    ) -> Result<Array<A, D::Larger>, QuantileError>
+    where
+        A: Float,
+        S: Data<Elem = N64>,
+        I: Interpolate<A>,
+    {
+        for &q in qs {
+            check_quantile(q)?;
+        }
+        let sorted = self.sorted_lanes();
+        let mut dim = self.results_dim().insert_axis(Axis(0));
+        dim[0] = qs.len();
+        let quantiles = qs
+            .iter()
+            .flat_map(|&q| {
+                sorted
+                    .iter()
+                    .map(move |values| sorted_quantile::<A, I>(values, q))
+            })
+            .collect();
+        Ok(Array::from_shape_vec(dim, quantiles)
+            .expect("There is a quantile for each `q` and each lane of each group."))
+    }
+
+    /// Returns the sorted elements of each lane of each group, stacked as
+    /// the results, or `None` for the lanes containing NaN.
+    fn sorted_lanes(&self) -> Array<Option<Vec<A>>, D>
+    where
+        A: Float,
+    {
+        let mut group_indices = vec![Vec::new(); self.keys.len()];
+        for (i, &code) in self.codes.iter().enumerate() {
+            group_indices[code].push(i);
+        }
+        let mut sorted = Array::from_shape_simple_fn(self.results_dim(), || None);
+        for (mut sorted, indices) in sorted.axis_iter_mut(self.axis).zip(&group_indices) {
+            let group = self.data.select(self.axis, indices);
+            Zip::from(&mut sorted)
+                .and(group.lanes(self.axis))
+                .for_each(|sorted, lane| *sorted = sort_lane(lane));
+        }
+        sorted
+    }
+
+    /// Returns the dimension of the stacked results.
+    fn results_dim(&self) -> D {
+        let mut dim = self.data.raw_dim();
+        dim[self.axis.index()] = self.keys.len();
+        dim
+    }
+
+    /// Folds the elements of each lane of each group with `f`, in a single
+    /// pass over the subviews along the axis.
+    ///
+    /// `f` is called with the accumulator, the element and the number of
+    /// elements of the group folded so far (including this one).
+    fn fold<B, F>(&self, init: B, mut f: F) -> Array<B, D>
+    where
+        B: Clone,
+        F: FnMut(&mut B, &A, usize),
+    {
+        let mut results = Array::from_elem(self.results_dim(), init);
+        let mut seen = vec![0; self.keys.len()];
+        for (&code, subview) in self.codes.iter().zip(self.data.axis_iter(self.axis)) {
+            seen[code] += 1;
+            let n = seen[code];
+            Zip::from(results.index_axis_mut(self.axis, code))
+                .and(&subview)
+                .for_each(|acc, x| f(acc, x, n));
+        }
+        results
+    }
+}
+
+/// Extension trait for `ArrayBase` providing statistics of the groups of its
+/// subviews along an axis, keyed by a label array.
+pub trait GroupByExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Groups the subviews of the array along `axis` by `labels`, where
+    /// `labels[i]` is the label of the `i`-th subview: subviews with equal
+    /// labels belong to the same group. See [`GroupBy`] for the statistics
+    /// of the groups.
+    ///
+    /// Returns `Err(ShapeMismatch)` if the length of `labels` is not the
+    /// length of `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`GroupBy`]: struct.GroupBy.html
+    fn group_by<K, SK>(
+        &self,
+        axis: Axis,
+        labels: &ArrayBase<SK, Ix1>,
+    ) -> Result<GroupBy<'_, A, D, K>, ShapeMismatch>
+    where
+        K: Eq + Hash + Clone,
+        SK: Data<Elem = K>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> GroupByExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn group_by<K, SK>(
+        &self,
+        axis: Axis,
+        labels: &ArrayBase<SK, Ix1>,
+    ) -> Result<GroupBy<'_, A, D, K>, ShapeMismatch>
+    where
+        K: Eq + Hash + Clone,
+        SK: Data<Elem = K>,
+    {
+        if labels.len() != self.len_of(axis) {
+            return Err(ShapeMismatch {
+                first_shape: vec![self.len_of(axis)],
+                second_shape: labels.shape().to_vec(),
+            });
+        }
+        let mut indices = HashMap::new();
+        let mut keys = Vec::new();
+        let mut counts = Vec::new();
+        let codes = labels
+            .iter()
+            .map(|label| {
+                let code = *indices.entry(label).or_insert_with(|| {
+                    keys.push(label.clone());
+                    counts.push(0);
+                    keys.len() - 1
+                });
+                counts[code] += 1;
+                code
+            })
+            .collect();
+        Ok(GroupBy {
+            data: self.view(),
+            axis,
+            keys,
+            codes,
+            counts,
+        })
+    }
+
+    private_impl! {}
+}
+
+/// Returns the sorted elements of a lane, or `None` if it contains NaN.
+fn sort_lane<A: Float>(lane: ArrayView1<'_, A>) -> Option<Vec<A>> {
+    if lane.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+    let mut values = lane.to_vec();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Some(values)
+}
+
+/// Returns the `q`-quantile of the sorted elements of a non-empty lane, or
+/// NaN if the lane contains NaN.
+fn sorted_quantile<A, I>(values: &Option<Vec<A>>, q: N64) -> A
+where
+    A: Float,
+    I: Interpolate<A>,
+{
+    let values = match values {
+        Some(values) => values,
+        None => return A::nan(),
+    };
+    let len = values.len();
+    let lower = if I::needs_lower(q, len) {
+        Some(values[lower_index(q, len)])
+    } else {
+        None
+    };
+    let higher = if I::needs_higher(q, len) {
+        Some(values[higher_index(q, len)])
+    } else {
+        None
+    };
+    I::interpolate(lower, higher, q, len)
+}
+
+fn check_quantile(q: N64) -> Result<(), QuantileError> {
+    if (0. ..=1.).contains(&q.raw()) {
+        Ok(())
+    } else {
+        Err(QuantileError::InvalidQuantile(q))
+    }
+}
+
+fn from_usize<A: FromPrimitive>(n: usize) -> A {
+    A::from_usize(n).expect("Converting number of elements to `A` must not fail.")
+}
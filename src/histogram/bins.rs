@@ -194,7 +194,7 @@ impl<A: Ord> Edges<A> {
     ///
     /// That is to say, it returns
     /// - `Some((left, right))`, where `left` and `right` are the indices of two consecutive edges
-    /// in `self` and `right == left + 1`, if `self[left] <= value < self[right]`;
+    ///   in `self` and `right == left + 1`, if `self[left] <= value < self[right]`;
     /// - `None`, otherwise.
     ///
     /// # Examples
@@ -434,7 +434,6 @@ mod edges_tests {
     use super::{Array1, Edges};
     use quickcheck_macros::quickcheck;
     use std::collections::BTreeSet;
-    use std::iter::FromIterator;
 
     #[quickcheck]
     fn check_sorted_from_vec(v: Vec<i32>) -> bool {
@@ -492,10 +491,10 @@ mod edges_tests {
     #[quickcheck]
     #[allow(clippy::needless_pass_by_value)]
     fn edges_are_deduped(v: Vec<i32>) -> bool {
-        let unique_elements = BTreeSet::from_iter(v.iter());
+        let unique_elements = v.iter().collect::<BTreeSet<_>>();
         let edges = Edges::from(v.clone());
         let view = edges.as_array_view();
-        let unique_edges = BTreeSet::from_iter(view.iter());
+        let unique_edges = view.iter().collect::<BTreeSet<_>>();
         unique_edges == unique_elements
     }
 }
@@ -505,7 +504,7 @@ mod bins_tests {
     use super::{Bins, Edges};
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "index out of bounds")]
     #[allow(unused_must_use)]
     fn get_panics_for_out_of_bounds_indexes() {
         let edges = Edges::from(vec![0]);
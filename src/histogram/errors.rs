@@ -32,18 +32,12 @@ pub enum BinsBuildError {
 impl BinsBuildError {
     /// Returns whether `self` is the `EmptyInput` variant.
     pub fn is_empty_input(&self) -> bool {
-        match self {
-            BinsBuildError::EmptyInput => true,
-            _ => false,
-        }
+        matches!(self, BinsBuildError::EmptyInput)
     }
 
     /// Returns whether `self` is the `Strategy` variant.
     pub fn is_strategy(&self) -> bool {
-        match self {
-            BinsBuildError::Strategy => true,
-            _ => false,
-        }
+        matches!(self, BinsBuildError::Strategy)
     }
 }
 
@@ -73,3 +67,46 @@ impl From<MinMaxError> for BinsBuildError {
         }
     }
 }
+
+/// Error computing a measure of the two variables of a histogram, e.g. their
+/// mutual information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JointHistogramError {
+    /// The histogram had no observations.
+    EmptyInput,
+    /// The histogram was not 2-dimensional; holds its number of dimensions.
+    NotTwoDimensional(usize),
+}
+
+impl JointHistogramError {
+    /// Returns whether `self` is the `EmptyInput` variant.
+    pub fn is_empty_input(&self) -> bool {
+        matches!(self, JointHistogramError::EmptyInput)
+    }
+
+    /// Returns whether `self` is the `NotTwoDimensional` variant.
+    pub fn is_not_two_dimensional(&self) -> bool {
+        matches!(self, JointHistogramError::NotTwoDimensional(_))
+    }
+}
+
+impl fmt::Display for JointHistogramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JointHistogramError::EmptyInput => write!(f, "Empty input."),
+            JointHistogramError::NotTwoDimensional(ndim) => write!(
+                f,
+                "The histogram must be 2-dimensional, but it has {} dimensions.",
+                ndim
+            ),
+        }
+    }
+}
+
+impl error::Error for JointHistogramError {}
+
+impl From<EmptyInput> for JointHistogramError {
+    fn from(_: EmptyInput) -> Self {
+        JointHistogramError::EmptyInput
+    }
+}
@@ -360,7 +360,11 @@ where
     /// [`from_array`]: #method.from_array.html
     #[must_use]
     pub fn build(&self) -> Grid<A> {
-        let projections: Vec<_> = self.bin_builders.iter().map(|b| b.build()).collect();
+        let projections: Vec<_> = self
+            .bin_builders
+            .iter()
+            .map(BinsBuildingStrategy::build)
+            .collect();
         Grid::from(projections)
     }
 }
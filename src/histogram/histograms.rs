@@ -1,5 +1,9 @@
-use super::errors::BinNotFound;
+use super::errors::{BinNotFound, JointHistogramError};
 use super::grid::Grid;
+use crate::entropy::{
+    EntropyEstimator, EntropyExt, JointEntropyExt, MutualInformationNormalization,
+};
+use crate::errors::EmptyInput;
 use ndarray::prelude::*;
 use ndarray::Data;
 
@@ -73,6 +77,132 @@ impl<A: Ord> Histogram<A> {
     pub fn grid(&self) -> &Grid<A> {
         &self.grid
     }
+
+    /// Returns the proportions of the observations in each bin, i.e. the
+    /// [`counts`] normalised by the number of observations.
+    ///
+    /// If the histogram has no observations, `Err(EmptyInput)` is returned.
+    ///
+    /// [`counts`]: #method.counts
+    pub fn proportions(&self) -> Result<ArrayD<f64>, EmptyInput> {
+        let n_observations: usize = self.counts.sum();
+        if n_observations == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self
+            .counts
+            .mapv(|count| count as f64 / n_observations as f64))
+    }
+
+    /// Returns an estimate of the [entropy] of the distribution of the
+    /// observations over the bins, computed from the [`counts`] with
+    /// `estimator` (see [`EntropyExt::entropy_from_counts`]).
+    ///
+    /// The result is in nats (see [`LogBase::convert_nats`] for other units),
+    /// and all the bins of the grid are taken into account by
+    /// [`EntropyEstimator::Nsb`], even the empty ones.
+    ///
+    /// If the histogram has no observations, `Err(EmptyInput)` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{
+    ///     histogram::{Bins, Edges, Grid},
+    ///     EntropyEstimator, EntropyExt, HistogramExt,
+    /// };
+    /// use noisy_float::types::n64;
+    ///
+    /// let bins = Bins::new(Edges::from(vec![n64(0.), n64(1.), n64(2.), n64(3.)]));
+    /// let observations = array![[0.5], [1.5], [0.2], [0.1]].mapv(n64);
+    /// let histogram = observations.histogram(Grid::from(vec![bins]));
+    /// let plug_in = histogram.entropy(EntropyEstimator::PlugIn).unwrap();
+    /// assert_abs_diff_eq!(
+    ///     plug_in,
+    ///     histogram.proportions().unwrap().entropy().unwrap()
+    /// );
+    /// let miller_madow = histogram.entropy(EntropyEstimator::MillerMadow).unwrap();
+    /// assert_abs_diff_eq!(miller_madow, plug_in + 1. / 8.);
+    /// ```
+    ///
+    /// [entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+    /// [`counts`]: #method.counts
+    /// [`EntropyExt::entropy_from_counts`]: ../trait.EntropyExt.html#tymethod.entropy_from_counts
+    /// [`EntropyEstimator::Nsb`]: ../enum.EntropyEstimator.html#variant.Nsb
+    /// [`LogBase::convert_nats`]: ../enum.LogBase.html#method.convert_nats
+    pub fn entropy(&self, estimator: EntropyEstimator) -> Result<f64, EmptyInput> {
+        self.counts.entropy_from_counts(estimator)
+    }
+
+    /// Returns the [mutual information] of the two variables of a
+    /// 2-dimensional histogram, estimated from the proportions of the
+    /// observations in each bin (see [`JointEntropyExt::mutual_information`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `JointHistogramError::EmptyInput` if the histogram has no observations
+    /// * `JointHistogramError::NotTwoDimensional` if the histogram is not 2-dimensional
+    ///
+    /// [mutual information]: https://en.wikipedia.org/wiki/Mutual_information
+    /// [`JointEntropyExt::mutual_information`]: ../trait.JointEntropyExt.html#tymethod.mutual_information
+    pub fn mutual_information(&self) -> Result<f64, JointHistogramError> {
+        Ok(self.joint_probabilities()?.mutual_information()?)
+    }
+
+    /// Returns the normalized mutual information of the two variables of a
+    /// 2-dimensional histogram, estimated from the proportions of the
+    /// observations in each bin (see [`JointEntropyExt::normalized_mutual_information`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `JointHistogramError::EmptyInput` if the histogram has no observations
+    /// * `JointHistogramError::NotTwoDimensional` if the histogram is not 2-dimensional
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{
+    ///     histogram::{Bins, Edges, Grid},
+    ///     HistogramExt, MutualInformationNormalization,
+    /// };
+    /// use noisy_float::types::n64;
+    ///
+    /// let bins = Bins::new(Edges::from(vec![n64(0.), n64(1.), n64(2.)]));
+    /// let grid = Grid::from(vec![bins.clone(), bins]);
+    /// // The second coordinate is always in the same bin as the first one
+    /// let observations = array![[0.5, 0.1], [1.5, 1.9], [0.2, 0.7], [1.1, 1.3]].mapv(n64);
+    /// let histogram = observations.histogram(grid);
+    /// let nmi = histogram
+    ///     .normalized_mutual_information(MutualInformationNormalization::Arithmetic)
+    ///     .unwrap();
+    /// assert_abs_diff_eq!(nmi, 1., epsilon = 1e-12);
+    /// ```
+    ///
+    /// [`JointEntropyExt::normalized_mutual_information`]: ../trait.JointEntropyExt.html#tymethod.normalized_mutual_information
+    pub fn normalized_mutual_information(
+        &self,
+        normalization: MutualInformationNormalization,
+    ) -> Result<f64, JointHistogramError> {
+        Ok(self
+            .joint_probabilities()?
+            .normalized_mutual_information(normalization)?)
+    }
+
+    /// Returns the proportions of the observations in each bin of a
+    /// 2-dimensional histogram.
+    fn joint_probabilities(&self) -> Result<Array2<f64>, JointHistogramError> {
+        if self.ndim() != 2 {
+            return Err(JointHistogramError::NotTwoDimensional(self.ndim()));
+        }
+        Ok(self
+            .proportions()?
+            .into_dimensionality()
+            .expect("The histogram is 2-dimensional."))
+    }
 }
 
 /// Extension trait for `ArrayBase` providing methods to compute histograms.
@@ -86,7 +216,7 @@ where
     /// Let `(n, d)` be the shape of `M`:
     /// - `n` is the number of points;
     /// - `d` is the number of dimensions of the space those points belong to.
-    /// It follows that every column in `M` is a `d`-dimensional point.
+    ///   It follows that every column in `M` is a `d`-dimensional point.
     ///
     /// For example: a (3, 4) matrix `M` is a collection of 3 points in a
     /// 4-dimensional space.
@@ -51,7 +51,6 @@ use crate::{
     quantile::{interpolate::Nearest, Quantile1dExt, QuantileExt},
 };
 use ndarray::{prelude::*, Data};
-use noisy_float::types::n64;
 use num_traits::{FromPrimitive, NumOps, Zero};
 
 /// A trait implemented by all strategies to build [`Bins`] with parameters inferred from
@@ -410,10 +409,7 @@ where
             return Err(BinsBuildError::EmptyInput);
         }
 
-        let mut a_copy = a.to_owned();
-        let first_quartile = a_copy.quantile_mut(n64(0.25), &Nearest).unwrap();
-        let third_quartile = a_copy.quantile_mut(n64(0.75), &Nearest).unwrap();
-        let iqr = third_quartile - first_quartile;
+        let iqr = a.iqr(&Nearest).unwrap();
 
         let bin_width = FreedmanDiaconis::compute_bin_width(n_points, iqr);
         let min = a.min()?;
@@ -462,8 +458,8 @@ where
     where
         S: Data<Elem = Self::Elem>,
     {
-        let fd_builder = FreedmanDiaconis::from_array(&a);
-        let sturges_builder = Sturges::from_array(&a);
+        let fd_builder = FreedmanDiaconis::from_array(a);
+        let sturges_builder = Sturges::from_array(a);
         match (fd_builder, sturges_builder) {
             (Err(_), Ok(sturges_builder)) => {
                 let builder = SturgesOrFD::Sturges(sturges_builder);
@@ -4,9 +4,16 @@
 //! Currently available routines include:
 //! - [order statistics] (minimum, maximum, median, quantiles, etc.);
 //! - [summary statistics] (mean, skewness, kurtosis, central moments, etc.)
+//! - [descriptive summaries] (count, mean, std, quartiles, etc. in one call);
+//! - [scaling transforms] (standardization, min-max and robust scaling);
+//! - [frequency statistics] (mode, frequency tables, etc.);
+//! - [grouped statistics] (count, mean, variance, quantiles, etc. of the groups of a label array);
 //! - [partitioning];
+//! - [moving-window statistics] (rolling mean, variance, extrema, quantiles, etc.);
 //! - [correlation analysis] (covariance, pearson correlation);
-//! - [measures from information theory] (entropy, KL divergence, etc.);
+//! - [exponentially weighted statistics] (moving mean, variance, covariance, correlation);
+//! - [measures from information theory] (entropy, KL divergence, etc.), including
+//!   [measures of two variables] (joint entropy, mutual information, etc.);
 //! - [measures of deviation] (count equal, L1, L2 distances, mean squared err etc.)
 //! - [histogram computation].
 //!
@@ -20,23 +27,40 @@
 //! [`ndarray`]: https://github.com/rust-ndarray/ndarray
 //! [order statistics]: trait.QuantileExt.html
 //! [partitioning]: trait.Sort1dExt.html
+//! [moving-window statistics]: trait.RollingExt.html
 //! [summary statistics]: trait.SummaryStatisticsExt.html
+//! [descriptive summaries]: trait.DescribeExt.html
+//! [scaling transforms]: trait.ScalingExt.html
+//! [frequency statistics]: trait.FrequencyExt.html
+//! [grouped statistics]: trait.GroupByExt.html
 //! [correlation analysis]: trait.CorrelationExt.html
+//! [exponentially weighted statistics]: trait.EwmExt.html
 //! [measures of deviation]: trait.DeviationExt.html
 //! [measures from information theory]: trait.EntropyExt.html
+//! [measures of two variables]: trait.JointEntropyExt.html
 //! [histogram computation]: histogram/index.html
 //! [here]: https://github.com/rust-ndarray/ndarray-stats/issues/1
 //! [`NumPy`]: https://docs.scipy.org/doc/numpy-1.14.1/reference/routines.statistics.html
 //! [`StatsBase.jl`]: https://juliastats.github.io/StatsBase.jl/latest/
 
 pub use crate::correlation::CorrelationExt;
+pub use crate::describe::{DescribeExt, Description};
 pub use crate::deviation::DeviationExt;
-pub use crate::entropy::EntropyExt;
+pub use crate::entropy::{
+    EntropyEstimator, EntropyExt, JointEntropyExt, LogBase, MutualInformationNormalization,
+};
+pub use crate::ewm::{Ewm, EwmExt};
+pub use crate::frequency::FrequencyExt;
+pub use crate::group_by::{GroupBy, GroupByExt};
 pub use crate::histogram::HistogramExt;
 pub use crate::maybe_nan::{MaybeNan, MaybeNanExt};
 pub use crate::quantile::{interpolate, Quantile1dExt, QuantileExt};
+pub use crate::rolling::{RollingExt, RollingWindow};
+pub use crate::scaling::{MinMaxScaling, RobustScaling, ScalingExt, Standardization};
 pub use crate::sort::Sort1dExt;
-pub use crate::summary_statistics::SummaryStatisticsExt;
+pub use crate::summary_statistics::{
+    weights, KurtosisEstimator, MomentsAccumulator, SkewnessEstimator, SummaryStatisticsExt,
+};
 
 #[cfg(test)]
 #[macro_use]
@@ -52,7 +76,7 @@ mod multi_input_error_macros {
         };
     }
     macro_rules! return_err_unless_same_shape {
-        ($arr_a:expr, $arr_b:expr) => {
+        ($arr_a:expr, $arr_b:expr) => {{
             use crate::errors::{MultiInputError, ShapeMismatch};
             if $arr_a.shape() != $arr_b.shape() {
                 return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
@@ -61,7 +85,7 @@ mod multi_input_error_macros {
                 })
                 .into());
             }
-        };
+        }};
     }
 }
 
@@ -99,11 +123,19 @@ mod private {
 }
 
 mod correlation;
+mod describe;
 mod deviation;
 mod entropy;
 pub mod errors;
+mod ewm;
+mod frequency;
+mod group_by;
 pub mod histogram;
 mod maybe_nan;
 mod quantile;
+mod rolling;
+mod scaling;
 mod sort;
+mod special;
 mod summary_statistics;
+pub mod summation;
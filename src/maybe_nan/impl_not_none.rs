@@ -1,7 +1,8 @@
 use super::NotNone;
-use num_traits::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
 use std::cmp;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Deref, DerefMut, Div, Mul, Rem, Sub};
 
 impl<T> Deref for NotNone<T> {
@@ -37,6 +38,12 @@ impl<T: PartialEq> PartialEq for NotNone<T> {
     }
 }
 
+impl<T: Hash> Hash for NotNone<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
 impl<T: Ord> Ord for NotNone<T> {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         self.deref().cmp(other)
@@ -101,6 +108,24 @@ impl<T: Rem> Rem for NotNone<T> {
     }
 }
 
+impl<T: Zero> Zero for NotNone<T> {
+    #[inline]
+    fn zero() -> Self {
+        NotNone::new(T::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.deref().is_zero()
+    }
+}
+
+impl<T: One> One for NotNone<T> {
+    #[inline]
+    fn one() -> Self {
+        NotNone::new(T::one())
+    }
+}
+
 impl<T: ToPrimitive> ToPrimitive for NotNone<T> {
     #[inline]
     fn to_isize(&self) -> Option<isize> {
@@ -88,14 +88,14 @@ macro_rules! impl_maybenan_for_fxx {
 
             fn from_not_nan_opt(value: Option<$Nxx>) -> $fxx {
                 match value {
-                    None => ::std::$fxx::NAN,
+                    None => $fxx::NAN,
                     Some(num) => num.raw(),
                 }
             }
 
             fn from_not_nan_ref_opt(value: Option<&$Nxx>) -> &$fxx {
                 match value {
-                    None => &::std::$fxx::NAN,
+                    None => &$fxx::NAN,
                     Some(num) => num.as_ref(),
                 }
             }
@@ -1,12 +1,14 @@
 use self::interpolate::{higher_index, lower_index, Interpolate};
 use super::sort::get_many_from_sorted_mut_unchecked;
-use crate::errors::QuantileError;
-use crate::errors::{EmptyInput, MinMaxError, MinMaxError::UndefinedOrder};
+use crate::errors::{EmptyInput, MinMaxError, MinMaxError::UndefinedOrder, ShapeMismatch};
+use crate::errors::{MaskedQuantileError, QuantileError};
 use crate::{MaybeNan, MaybeNanExt};
 use ndarray::prelude::*;
-use ndarray::{Data, DataMut, RemoveAxis, Zip};
-use noisy_float::types::N64;
+use ndarray::{s, Data, DataMut, RemoveAxis, Zip};
+use noisy_float::types::{n64, N64};
+use num_traits::{Float, FromPrimitive, ToPrimitive, Zero};
 use std::cmp;
+use std::ops::{Add, Div, Mul, Sub};
 
 /// Quantile methods for `ArrayBase`.
 pub trait QuantileExt<A, S, D>
@@ -186,7 +188,7 @@ where
     /// - `q=0.` returns the minimum along each 1-dimensional lane;
     /// - `q=0.5` returns the median along each 1-dimensional lane;
     /// - `q=1.` returns the maximum along each 1-dimensional lane.
-    /// (`q=0` and `q=1` are considered improper quantiles)
+    ///   (`q=0` and `q=1` are considered improper quantiles)
     ///
     /// The array is shuffled **in place** along each 1-dimensional lane in
     /// order to produce the required quantile without allocating a copy
@@ -198,7 +200,7 @@ where
     /// Complexity ([quickselect](https://en.wikipedia.org/wiki/Quickselect)):
     /// - average case: O(`m`);
     /// - worst case: O(`m`^2);
-    /// where `m` is the number of elements in the array.
+    ///   where `m` is the number of elements in the array.
     ///
     /// Returns `Err(EmptyInput)` when the specified axis has length 0.
     ///
@@ -278,6 +280,261 @@ where
         S: DataMut,
         I: Interpolate<A::NotNan>;
 
+    /// Returns the [trimmed mean] of each 1-dimensional lane along `axis`,
+    /// i.e. the mean of the lane after discarding the `⌊proportion * n⌋`
+    /// smallest and the `⌊proportion * n⌋` largest elements, where `n` is
+    /// the length of `axis`.
+    ///
+    /// The array is shuffled **in place** along each 1-dimensional lane, using
+    /// [`get_many_from_sorted_mut`] to find the discarded elements without
+    /// sorting the lanes. No assumptions should be made on the ordering of
+    /// the array elements after this computation.
+    ///
+    /// Returns `Err(EmptyInput)` when the specified axis has length 0.
+    ///
+    /// Returns `Err(InvalidProportion(proportion))` if `proportion` is not
+    /// between `0.` (inclusive) and `0.5` (exclusive).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the number of retained elements.
+    ///
+    /// [trimmed mean]: https://en.wikipedia.org/wiki/Truncated_mean
+    /// [`get_many_from_sorted_mut`]: trait.Sort1dExt.html#tymethod.get_many_from_sorted_mut
+    fn trimmed_mean_axis_mut(
+        &mut self,
+        axis: Axis,
+        proportion: N64,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut;
+
+    /// Returns the [winsorized mean] of each 1-dimensional lane along `axis`,
+    /// i.e. the mean of the lane after replacing the `⌊proportion * n⌋`
+    /// smallest elements with the smallest retained element and the
+    /// `⌊proportion * n⌋` largest elements with the largest retained element,
+    /// where `n` is the length of `axis`.
+    ///
+    /// The array is shuffled **in place** along each 1-dimensional lane (see
+    /// [`trimmed_mean_axis_mut`]), but its values are not modified.
+    ///
+    /// Returns `Err(EmptyInput)` when the specified axis has length 0.
+    ///
+    /// Returns `Err(InvalidProportion(proportion))` if `proportion` is not
+    /// between `0.` (inclusive) and `0.5` (exclusive).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [winsorized mean]: https://en.wikipedia.org/wiki/Winsorized_mean
+    /// [`trimmed_mean_axis_mut`]: #tymethod.trimmed_mean_axis_mut
+    fn winsorized_mean_axis_mut(
+        &mut self,
+        axis: Axis,
+        proportion: N64,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut;
+
+    /// Returns the winsorized variance of each 1-dimensional lane along `axis`,
+    /// i.e. the variance of the winsorized lane (see [`winsorized_mean_axis_mut`]).
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom". For
+    /// example, to calculate the population variance, use `ddof = 0`, or to
+    /// calculate the sample variance, use `ddof = 1`.
+    ///
+    /// The array is shuffled **in place** along each 1-dimensional lane (see
+    /// [`trimmed_mean_axis_mut`]), but its values are not modified.
+    ///
+    /// Returns `Err(EmptyInput)` when the specified axis has length 0.
+    ///
+    /// Returns `Err(InvalidProportion(proportion))` if `proportion` is not
+    /// between `0.` (inclusive) and `0.5` (exclusive).
+    ///
+    /// **Panics** if `axis` is out of bounds, if `ddof` is less than zero or
+    /// greater than the length of `axis`, or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`winsorized_mean_axis_mut`]: #tymethod.winsorized_mean_axis_mut
+    /// [`trimmed_mean_axis_mut`]: #tymethod.trimmed_mean_axis_mut
+    fn winsorized_var_axis_mut(
+        &mut self,
+        axis: Axis,
+        proportion: N64,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Float + FromPrimitive,
+        S: DataMut;
+
+    /// Returns the [interquartile range] `q₃ - q₁` of each 1-dimensional lane
+    /// along `axis`, where the quartiles are computed with the `interpolate`
+    /// strategy (see [`quantile_axis_mut`]).
+    ///
+    /// Unlike [`quantile_axis_mut`], the array is not modified: each lane is
+    /// copied before retrieving its quartiles.
+    ///
+    /// Returns `Err(EmptyInput)` when the specified axis has length 0.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{interpolate::Linear, QuantileExt};
+    ///
+    /// let a = array![[1, 2, 3, 4, 100], [7, 5, 1, 3, 9]];
+    /// assert_eq!(a.iqr_axis(Axis(1), &Linear).unwrap(), array![2, 4]);
+    /// ```
+    ///
+    /// [interquartile range]: https://en.wikipedia.org/wiki/Interquartile_range
+    /// [`quantile_axis_mut`]: #tymethod.quantile_axis_mut
+    fn iqr_axis<I>(&self, axis: Axis, interpolate: &I) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A>,
+        I: Interpolate<A>;
+
+    /// Returns the interquartile range of each 1-dimensional lane along
+    /// `axis`, skipping NaN values.
+    ///
+    /// The interquartile range of a lane containing only NaN values is NaN.
+    ///
+    /// Returns `Err(EmptyInput)` when the specified axis has length 0.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// See [`iqr_axis`](#tymethod.iqr_axis) for details.
+    fn iqr_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the [median absolute deviation] of each 1-dimensional lane
+    /// along `axis`, i.e. the median of the absolute deviations of the
+    /// elements from the median of the lane, multiplied by `scale`:
+    ///
+    /// ```text
+    /// MAD = scale × median(|xᵢ - median(x)|)
+    /// ```
+    ///
+    /// Both medians are computed with the `interpolate` strategy (see
+    /// [`quantile_axis_mut`]). With a `scale` of `1.4826` (i.e. `1 / Φ⁻¹(3/4)`),
+    /// the MAD is a consistent estimator of the standard deviation of normally
+    /// distributed data; use a `scale` of one for the raw MAD.
+    ///
+    /// Unlike [`quantile_axis_mut`], the array is not modified: each lane is
+    /// copied before retrieving its median.
+    ///
+    /// Returns `Err(EmptyInput)` when the specified axis has length 0.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{interpolate::Midpoint, QuantileExt};
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![[1., 2., 3., 4., 100.], [7., 5., 1., 3., 9.]].mapv(n64);
+    /// let mad = a.median_abs_deviation_axis(Axis(1), n64(1.), &Midpoint).unwrap();
+    /// assert_eq!(mad, array![n64(1.), n64(2.)]);
+    /// ```
+    ///
+    /// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+    /// [`quantile_axis_mut`]: #tymethod.quantile_axis_mut
+    fn median_abs_deviation_axis<I>(
+        &self,
+        axis: Axis,
+        scale: A,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + Mul<Output = A>,
+        I: Interpolate<A>;
+
+    /// Returns the median absolute deviation of each 1-dimensional lane along
+    /// `axis`, skipping NaN values.
+    ///
+    /// The median absolute deviation of a lane containing only NaN values is NaN.
+    ///
+    /// Returns `Err(EmptyInput)` when the specified axis has length 0.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `scale` is NaN.
+    ///
+    /// See [`median_abs_deviation_axis`](#tymethod.median_abs_deviation_axis) for details.
+    fn median_abs_deviation_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        scale: A,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Mul<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the `q`th quantile of the unmasked elements of each
+    /// 1-dimensional lane along `axis`.
+    ///
+    /// An element is masked, and ignored, if its entry in `mask` is `true`.
+    /// Each lane may have a different number of unmasked elements; the
+    /// quantile of a lane whose elements are all masked is `None`. See
+    /// [`quantile_axis_mut`] for the definition of the quantile and of the
+    /// `interpolate` strategies.
+    ///
+    /// Unlike [`quantile_axis_mut`], the array is not modified: the unmasked
+    /// elements of each lane are copied before retrieving their quantile.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MaskedQuantileError::ShapeMismatch` if `mask` doesn't have the same shape as `self`
+    /// * `MaskedQuantileError::InvalidQuantile(q)` if `q` is not between `0.` and `1.` (inclusive)
+    /// * `MaskedQuantileError::EmptyInput` if the specified axis has length 0
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{interpolate::Lower, QuantileExt};
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![[3, -999, 1, 2], [-999, -999, 5, 4], [-999, -999, -999, -999]];
+    /// let mask = a.mapv(|x| x == -999);
+    /// let medians = a.masked_quantile_axis(Axis(1), &mask, n64(0.5), &Lower).unwrap();
+    /// assert_eq!(medians, array![Some(2), Some(4), None]);
+    /// ```
+    ///
+    /// [`quantile_axis_mut`]: #tymethod.quantile_axis_mut
+    fn masked_quantile_axis<S2, I>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+        q: N64,
+        interpolate: &I,
+    ) -> Result<Array<Option<A>, D::Smaller>, MaskedQuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone,
+        S2: Data<Elem = bool>,
+        I: Interpolate<A>;
+
     private_decl! {}
 }
 
@@ -446,7 +703,7 @@ where
             I: Interpolate<A>,
         {
             for &q in qs {
-                if !((q >= 0.) && (q <= 1.)) {
+                if !(0. ..=1.).contains(&q.raw()) {
                     return Err(QuantileError::InvalidQuantile(q));
                 }
             }
@@ -529,7 +786,7 @@ where
         S: DataMut,
         I: Interpolate<A::NotNan>,
     {
-        if !((q >= 0.) && (q <= 1.)) {
+        if !(0. ..=1.).contains(&q.raw()) {
             return Err(QuantileError::InvalidQuantile(q));
         }
 
@@ -553,9 +810,316 @@ where
         Ok(quantile)
     }
 
+    fn trimmed_mean_axis_mut(
+        &mut self,
+        axis: Axis,
+        proportion: N64,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut,
+    {
+        let n = check_trim_args(self.len_of(axis), proportion)?;
+        Ok(self.map_axis_mut(axis, |mut lane| {
+            let (n_cut, _, _) = partition_tails_mut(&mut lane, n, proportion);
+            lane.slice(s![n_cut..n - n_cut]).mean().unwrap()
+        }))
+    }
+
+    fn winsorized_mean_axis_mut(
+        &mut self,
+        axis: Axis,
+        proportion: N64,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut,
+    {
+        let n = check_trim_args(self.len_of(axis), proportion)?;
+        Ok(self.map_axis_mut(axis, |mut lane| winsorized_mean(&mut lane, n, proportion)))
+    }
+
+    fn winsorized_var_axis_mut(
+        &mut self,
+        axis: Axis,
+        proportion: N64,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Float + FromPrimitive,
+        S: DataMut,
+    {
+        let n = check_trim_args(self.len_of(axis), proportion)?;
+        let n_elements =
+            A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+        assert!(
+            !(ddof < A::zero() || ddof > n_elements),
+            "`ddof` must not be less than zero or greater than the length of the axis",
+        );
+        Ok(self.map_axis_mut(axis, |mut lane| {
+            let (n_cut, lower, higher) = partition_tails_mut(&mut lane, n, proportion);
+            let mean = winsorized(lane.view(), n_cut, lower, higher)
+                .fold(A::zero(), |acc, x| acc + x)
+                / n_elements;
+            let sum_sq = winsorized(lane.view(), n_cut, lower, higher)
+                .fold(A::zero(), |acc, x| acc + (x - mean).powi(2));
+            sum_sq / (n_elements - ddof)
+        }))
+    }
+
+    fn iqr_axis<I>(&self, axis: Axis, interpolate: &I) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A>,
+        I: Interpolate<A>,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| iqr(lane.to_owned(), interpolate)))
+    }
+
+    fn iqr_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            A::from_not_nan_opt(not_nan(lane).map(|lane| iqr(lane, interpolate)))
+        }))
+    }
+
+    fn median_abs_deviation_axis<I>(
+        &self,
+        axis: Axis,
+        scale: A,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + Mul<Output = A>,
+        I: Interpolate<A>,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            median_abs_deviation(lane.to_owned(), scale.clone(), interpolate)
+        }))
+    }
+
+    fn median_abs_deviation_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        scale: A,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Mul<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let scale = scale.try_as_not_nan().expect("The scale must not be NaN.");
+        Ok(self.map_axis(axis, |lane| {
+            A::from_not_nan_opt(
+                not_nan(lane).map(|lane| median_abs_deviation(lane, scale.clone(), interpolate)),
+            )
+        }))
+    }
+
+    fn masked_quantile_axis<S2, I>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+        q: N64,
+        interpolate: &I,
+    ) -> Result<Array<Option<A>, D::Smaller>, MaskedQuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone,
+        S2: Data<Elem = bool>,
+        I: Interpolate<A>,
+    {
+        if self.shape() != mask.shape() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: mask.shape().to_vec(),
+            }
+            .into());
+        }
+        if !(0. ..=1.).contains(&q.raw()) {
+            return Err(MaskedQuantileError::InvalidQuantile(q));
+        }
+        if self.len_of(axis) == 0 {
+            return Err(MaskedQuantileError::EmptyInput);
+        }
+        let mut results = Array::from_elem(self.raw_dim().remove_axis(axis), None);
+        Zip::from(&mut results)
+            .and(self.lanes(axis))
+            .and(mask.lanes(axis))
+            .for_each(|result, lane, mask| {
+                let mut unmasked: Array1<A> = lane
+                    .iter()
+                    .zip(mask)
+                    .filter(|(_, &masked)| !masked)
+                    .map(|(x, _)| x.clone())
+                    .collect();
+                if !unmasked.is_empty() {
+                    *result = Some(
+                        unmasked
+                            .quantile_mut(q, interpolate)
+                            .expect("The quantile of a non-empty lane is valid."),
+                    );
+                }
+            });
+        Ok(results)
+    }
+
     private_impl! {}
 }
 
+/// Returns the interquartile range of a non-empty lane, shuffling it.
+fn iqr<A, I>(mut lane: Array1<A>, interpolate: &I) -> A
+where
+    A: Ord + Clone + Sub<Output = A>,
+    I: Interpolate<A>,
+{
+    let quartiles = lane
+        .quantiles_mut(&aview1(&[n64(0.25), n64(0.75)]), interpolate)
+        .expect("The quartiles of a non-empty lane are valid.");
+    quartiles[1].clone() - quartiles[0].clone()
+}
+
+/// Returns the scaled median absolute deviation of a non-empty lane, shuffling it.
+fn median_abs_deviation<A, I>(mut lane: Array1<A>, scale: A, interpolate: &I) -> A
+where
+    A: Ord + Clone + Sub<Output = A> + Mul<Output = A>,
+    I: Interpolate<A>,
+{
+    let median = lane
+        .quantile_mut(n64(0.5), interpolate)
+        .expect("The median of a non-empty lane is valid.");
+    let mut abs_deviations = lane.mapv(|x| {
+        if x >= median {
+            x - median.clone()
+        } else {
+            median.clone() - x
+        }
+    });
+    let mad = abs_deviations
+        .quantile_mut(n64(0.5), interpolate)
+        .expect("The median of a non-empty lane is valid.");
+    mad * scale
+}
+
+/// Returns a copy of the non-NaN values of `lane`, if there are any.
+fn not_nan<A>(lane: ArrayView1<'_, A>) -> Option<Array1<A::NotNan>>
+where
+    A: MaybeNan,
+    A::NotNan: Clone,
+{
+    let not_nan: Array1<A::NotNan> = lane
+        .iter()
+        .filter_map(|x| x.try_as_not_nan().cloned())
+        .collect();
+    if not_nan.is_empty() {
+        None
+    } else {
+        Some(not_nan)
+    }
+}
+
+/// Returns the length of the lanes if they are not empty and `proportion` is valid.
+fn check_trim_args(n: usize, proportion: N64) -> Result<usize, QuantileError> {
+    if n == 0 {
+        return Err(QuantileError::EmptyInput);
+    }
+    if !(0. ..0.5).contains(&proportion.raw()) {
+        return Err(QuantileError::InvalidProportion(proportion));
+    }
+    Ok(n)
+}
+
+/// Returns the number of elements cut from each tail of a lane of length `n`.
+fn n_cut(n: usize, proportion: N64) -> usize {
+    (proportion * (n as f64)).floor().to_usize().unwrap()
+}
+
+/// Shuffles `lane` (of length `n`) so that its `n_cut` smallest elements are
+/// at its start and its `n_cut` largest elements are at its end.
+///
+/// Returns `n_cut`, the smallest retained element and the largest retained element.
+fn partition_tails_mut<A>(
+    lane: &mut ArrayViewMut1<'_, A>,
+    n: usize,
+    proportion: N64,
+) -> (usize, A, A)
+where
+    A: Ord + Clone,
+{
+    let n_cut = n_cut(n, proportion);
+    let (lower, higher) = (n_cut, n - 1 - n_cut);
+    let indexes = if lower == higher {
+        vec![lower]
+    } else {
+        vec![lower, higher]
+    };
+    let values = get_many_from_sorted_mut_unchecked(lane, &indexes);
+    let lower_value = values[&lower].clone();
+    let higher_value = values[&higher].clone();
+    (n_cut, lower_value, higher_value)
+}
+
+/// Returns the elements of `lane` after it has been partitioned by
+/// `partition_tails_mut`, with the `n_cut` elements of each tail
+/// replaced by the smallest or the largest retained element.
+fn winsorized<'a, A>(
+    lane: ArrayView1<'a, A>,
+    n_cut: usize,
+    lower: A,
+    higher: A,
+) -> impl Iterator<Item = A> + 'a
+where
+    A: Clone + 'a,
+{
+    let n = lane.len();
+    lane.into_iter().enumerate().map(move |(i, x)| {
+        if i < n_cut {
+            lower.clone()
+        } else if i >= n - n_cut {
+            higher.clone()
+        } else {
+            x.clone()
+        }
+    })
+}
+
+/// Returns the winsorized mean of `lane` (of length `n`), shuffling it in place.
+fn winsorized_mean<A>(lane: &mut ArrayViewMut1<'_, A>, n: usize, proportion: N64) -> A
+where
+    A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+{
+    let (n_cut, lower, higher) = partition_tails_mut(lane, n, proportion);
+    let sum = winsorized(lane.view(), n_cut, lower, higher).fold(A::zero(), |acc, x| acc + x);
+    sum / A::from_usize(n).expect("Converting number of elements to `A` must not fail.")
+}
+
 /// Quantile methods for 1-D arrays.
 pub trait Quantile1dExt<A, S>
 where
@@ -575,7 +1139,7 @@ where
     /// - `q=0.` returns the minimum;
     /// - `q=0.5` returns the median;
     /// - `q=1.` returns the maximum.
-    /// (`q=0` and `q=1` are considered improper quantiles)
+    ///   (`q=0` and `q=1` are considered improper quantiles)
     ///
     /// The array is shuffled **in place** in order to produce the required quantile
     /// without allocating a copy.
@@ -585,7 +1149,7 @@ where
     /// Complexity ([quickselect](https://en.wikipedia.org/wiki/Quickselect)):
     /// - average case: O(`m`);
     /// - worst case: O(`m`^2);
-    /// where `m` is the number of elements in the array.
+    ///   where `m` is the number of elements in the array.
     ///
     /// Returns `Err(EmptyInput)` if the array is empty.
     ///
@@ -622,6 +1186,177 @@ where
         S2: Data<Elem = N64>,
         I: Interpolate<A>;
 
+    /// Returns the [trimmed mean] of the array, i.e. the mean of the array
+    /// after discarding the `⌊proportion * n⌋` smallest and the
+    /// `⌊proportion * n⌋` largest elements, where `n` is the number of elements.
+    ///
+    /// The array is shuffled **in place**, using [`get_many_from_sorted_mut`]
+    /// to find the discarded elements without sorting the array.
+    /// No assumptions should be made on the ordering of the array elements
+    /// after this computation.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// Returns `Err(InvalidProportion(proportion))` if `proportion` is not
+    /// between `0.` (inclusive) and `0.5` (exclusive).
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of retained elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::Quantile1dExt;
+    /// use noisy_float::types::n64;
+    ///
+    /// let mut data = array![n64(1.), n64(-50.), n64(3.), n64(4.), n64(2.), n64(100.)];
+    /// assert_eq!(data.trimmed_mean_mut(n64(0.2)).unwrap(), n64(2.5));
+    /// assert_eq!(data.winsorized_mean_mut(n64(0.2)).unwrap(), n64(2.5));
+    /// ```
+    ///
+    /// [trimmed mean]: https://en.wikipedia.org/wiki/Truncated_mean
+    /// [`get_many_from_sorted_mut`]: trait.Sort1dExt.html#tymethod.get_many_from_sorted_mut
+    fn trimmed_mean_mut(&mut self, proportion: N64) -> Result<A, QuantileError>
+    where
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut;
+
+    /// Returns the [winsorized mean] of the array, i.e. the mean of the array
+    /// after replacing the `⌊proportion * n⌋` smallest elements with the
+    /// smallest retained element and the `⌊proportion * n⌋` largest elements
+    /// with the largest retained element, where `n` is the number of elements.
+    ///
+    /// The array is shuffled **in place** (see [`trimmed_mean_mut`]), but its
+    /// values are not modified.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// Returns `Err(InvalidProportion(proportion))` if `proportion` is not
+    /// between `0.` (inclusive) and `0.5` (exclusive).
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements.
+    ///
+    /// [winsorized mean]: https://en.wikipedia.org/wiki/Winsorized_mean
+    /// [`trimmed_mean_mut`]: #tymethod.trimmed_mean_mut
+    fn winsorized_mean_mut(&mut self, proportion: N64) -> Result<A, QuantileError>
+    where
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut;
+
+    /// Returns the winsorized variance of the array, i.e. the variance of the
+    /// winsorized array (see [`winsorized_mean_mut`]).
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom". For
+    /// example, to calculate the population variance, use `ddof = 0`, or to
+    /// calculate the sample variance, use `ddof = 1`.
+    ///
+    /// The array is shuffled **in place** (see [`trimmed_mean_mut`]), but its
+    /// values are not modified.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// Returns `Err(InvalidProportion(proportion))` if `proportion` is not
+    /// between `0.` (inclusive) and `0.5` (exclusive).
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the number of
+    /// elements, or if `A::from_usize()` fails to convert the number of elements.
+    ///
+    /// [`winsorized_mean_mut`]: #tymethod.winsorized_mean_mut
+    /// [`trimmed_mean_mut`]: #tymethod.trimmed_mean_mut
+    fn winsorized_var_mut(&mut self, proportion: N64, ddof: A) -> Result<A, QuantileError>
+    where
+        A: Ord + Float + FromPrimitive,
+        S: DataMut;
+
+    /// Returns the [interquartile range] `q₃ - q₁` of the array, where the
+    /// quartiles are computed with the `interpolate` strategy (see [`quantile_mut`]).
+    ///
+    /// Unlike [`quantile_mut`], the array is not modified.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// [interquartile range]: https://en.wikipedia.org/wiki/Interquartile_range
+    /// [`quantile_mut`]: #tymethod.quantile_mut
+    fn iqr<I>(&self, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A>,
+        I: Interpolate<A>;
+
+    /// Returns the interquartile range of the array, skipping NaN values.
+    ///
+    /// If the array contains only NaN values, the result is NaN.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// See [`iqr`](#tymethod.iqr) for details.
+    fn iqr_skipnan<I>(&self, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the [median absolute deviation] of the array multiplied by
+    /// `scale`, where the medians are computed with the `interpolate` strategy.
+    ///
+    /// Use a `scale` of `1.4826` for a consistent estimator of the standard
+    /// deviation of normally distributed data, or of one for the raw MAD
+    /// (see [`median_abs_deviation_axis`]).
+    ///
+    /// Unlike [`quantile_mut`], the array is not modified.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+    /// [`median_abs_deviation_axis`]: trait.QuantileExt.html#tymethod.median_abs_deviation_axis
+    /// [`quantile_mut`]: #tymethod.quantile_mut
+    fn median_abs_deviation<I>(&self, scale: A, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + Mul<Output = A>,
+        I: Interpolate<A>;
+
+    /// Returns the median absolute deviation of the array multiplied by
+    /// `scale`, skipping NaN values.
+    ///
+    /// If the array contains only NaN values, the result is NaN.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if `scale` is NaN.
+    ///
+    /// See [`median_abs_deviation`](#tymethod.median_abs_deviation) for details.
+    fn median_abs_deviation_skipnan<I>(&self, scale: A, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Mul<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the `q`th quantile of the unmasked elements of the array,
+    /// i.e. of the elements whose entry in `mask` is `false`, or `None` if
+    /// all the elements are masked.
+    ///
+    /// Unlike [`quantile_mut`], the array is not modified.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MaskedQuantileError::ShapeMismatch` if `mask` doesn't have the same shape as `self`
+    /// * `MaskedQuantileError::InvalidQuantile(q)` if `q` is not between `0.` and `1.` (inclusive)
+    /// * `MaskedQuantileError::EmptyInput` if the array is empty
+    ///
+    /// See [`masked_quantile_axis`] for details.
+    ///
+    /// [`quantile_mut`]: #tymethod.quantile_mut
+    /// [`masked_quantile_axis`]: trait.QuantileExt.html#tymethod.masked_quantile_axis
+    fn masked_quantile<S2, I>(
+        &self,
+        mask: &ArrayBase<S2, Ix1>,
+        q: N64,
+        interpolate: &I,
+    ) -> Result<Option<A>, MaskedQuantileError>
+    where
+        A: Ord + Clone,
+        S2: Data<Elem = bool>,
+        I: Interpolate<A>;
+
     private_decl! {}
 }
 
@@ -654,6 +1389,90 @@ where
         self.quantiles_axis_mut(Axis(0), qs, interpolate)
     }
 
+    fn trimmed_mean_mut(&mut self, proportion: N64) -> Result<A, QuantileError>
+    where
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut,
+    {
+        Ok(self
+            .trimmed_mean_axis_mut(Axis(0), proportion)?
+            .into_scalar())
+    }
+
+    fn winsorized_mean_mut(&mut self, proportion: N64) -> Result<A, QuantileError>
+    where
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        S: DataMut,
+    {
+        Ok(self
+            .winsorized_mean_axis_mut(Axis(0), proportion)?
+            .into_scalar())
+    }
+
+    fn winsorized_var_mut(&mut self, proportion: N64, ddof: A) -> Result<A, QuantileError>
+    where
+        A: Ord + Float + FromPrimitive,
+        S: DataMut,
+    {
+        Ok(self
+            .winsorized_var_axis_mut(Axis(0), proportion, ddof)?
+            .into_scalar())
+    }
+
+    fn iqr<I>(&self, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A>,
+        I: Interpolate<A>,
+    {
+        Ok(self.iqr_axis(Axis(0), interpolate)?.into_scalar())
+    }
+
+    fn iqr_skipnan<I>(&self, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>,
+    {
+        Ok(self.iqr_axis_skipnan(Axis(0), interpolate)?.into_scalar())
+    }
+
+    fn median_abs_deviation<I>(&self, scale: A, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + Mul<Output = A>,
+        I: Interpolate<A>,
+    {
+        Ok(self
+            .median_abs_deviation_axis(Axis(0), scale, interpolate)?
+            .into_scalar())
+    }
+
+    fn median_abs_deviation_skipnan<I>(&self, scale: A, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Mul<Output = A::NotNan>,
+        I: Interpolate<A::NotNan>,
+    {
+        Ok(self
+            .median_abs_deviation_axis_skipnan(Axis(0), scale, interpolate)?
+            .into_scalar())
+    }
+
+    fn masked_quantile<S2, I>(
+        &self,
+        mask: &ArrayBase<S2, Ix1>,
+        q: N64,
+        interpolate: &I,
+    ) -> Result<Option<A>, MaskedQuantileError>
+    where
+        A: Ord + Clone,
+        S2: Data<Elem = bool>,
+        I: Interpolate<A>,
+    {
+        Ok(self
+            .masked_quantile_axis(Axis(0), mask, q, interpolate)?
+            .into_scalar())
+    }
+
     private_impl! {}
 }
 
@@ -0,0 +1,367 @@
+//! Incremental statistics over a sliding window.
+//!
+//! Every accumulator is updated with the non-NaN values entering (`push`) and
+//! leaving (`pop`) the window, in the order of their indexes in the lane.
+use crate::quantile::interpolate::{higher_index, lower_index, Interpolate};
+use crate::summation::two_sum;
+use ndarray::ArrayView1;
+use noisy_float::types::N64;
+use num_traits::{Float, FromPrimitive};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+pub(super) trait Accumulator<A> {
+    /// Adds the value at `index` to the window.
+    fn push(&mut self, index: usize, x: A);
+
+    /// Removes the value at `index` from the window.
+    fn pop(&mut self, index: usize, x: A);
+
+    /// Returns the statistic of the `count` values in the window.
+    fn value(&self, count: usize) -> A;
+}
+
+/// Compensated running sum of the finite values, so that the rounding errors
+/// of values that have left the window don't accumulate along the lane.
+///
+/// The infinite values are counted apart: adding them to the compensated sum
+/// would make it NaN, even after they have left the window.
+pub(super) struct Sum<A> {
+    sum: A,
+    compensation: A,
+    infinities: Infinities,
+}
+
+impl<A: Float> Sum<A> {
+    pub(super) fn new() -> Self {
+        Sum {
+            sum: A::zero(),
+            compensation: A::zero(),
+            infinities: Infinities::default(),
+        }
+    }
+
+    fn add(&mut self, x: A) {
+        let (sum, lost) = two_sum(self.sum, x);
+        self.sum = sum;
+        self.compensation = self.compensation + lost;
+    }
+
+    fn sum(&self) -> A {
+        self.infinities
+            .sum()
+            .unwrap_or_else(|| self.sum + self.compensation)
+    }
+}
+
+impl<A: Float> Accumulator<A> for Sum<A> {
+    fn push(&mut self, _index: usize, x: A) {
+        if !self.infinities.push(x) {
+            self.add(x);
+        }
+    }
+
+    fn pop(&mut self, _index: usize, x: A) {
+        if !self.infinities.pop(x) {
+            self.add(-x);
+        }
+    }
+
+    fn value(&self, _count: usize) -> A {
+        self.sum()
+    }
+}
+
+/// Running mean, computed from the compensated running sum.
+pub(super) struct Mean<A>(Sum<A>);
+
+impl<A: Float> Mean<A> {
+    pub(super) fn new() -> Self {
+        Mean(Sum::new())
+    }
+}
+
+impl<A: Float + FromPrimitive> Accumulator<A> for Mean<A> {
+    fn push(&mut self, index: usize, x: A) {
+        self.0.push(index, x);
+    }
+
+    fn pop(&mut self, index: usize, x: A) {
+        self.0.pop(index, x);
+    }
+
+    fn value(&self, count: usize) -> A {
+        let n = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+        self.0.sum() / n
+    }
+}
+
+/// Running variance of the finite values, updated with Welford's algorithm
+/// (and its inverse to remove values).
+///
+/// The variance of a window containing an infinite value is NaN, so the
+/// infinite values are only counted.
+pub(super) struct Variance<A> {
+    count: usize,
+    mean: A,
+    sum_sq_dev: A,
+    ddof: A,
+    infinities: Infinities,
+}
+
+impl<A: Float + FromPrimitive> Variance<A> {
+    pub(super) fn new(ddof: A) -> Self {
+        Variance {
+            count: 0,
+            mean: A::zero(),
+            sum_sq_dev: A::zero(),
+            ddof,
+            infinities: Infinities::default(),
+        }
+    }
+
+    fn n(&self) -> A {
+        A::from_usize(self.count).expect("Converting number of elements to `A` must not fail.")
+    }
+}
+
+impl<A: Float + FromPrimitive> Accumulator<A> for Variance<A> {
+    fn push(&mut self, _index: usize, x: A) {
+        if self.infinities.push(x) {
+            return;
+        }
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean = self.mean + delta / self.n();
+        self.sum_sq_dev = self.sum_sq_dev + delta * (x - self.mean);
+    }
+
+    fn pop(&mut self, _index: usize, x: A) {
+        if self.infinities.pop(x) {
+            return;
+        }
+        self.count -= 1;
+        if self.count == 0 {
+            self.mean = A::zero();
+            self.sum_sq_dev = A::zero();
+            return;
+        }
+        let delta = x - self.mean;
+        self.mean = self.mean - delta / self.n();
+        self.sum_sq_dev = (self.sum_sq_dev - delta * (x - self.mean)).max(A::zero());
+    }
+
+    fn value(&self, _count: usize) -> A {
+        let dof = self.n() - self.ddof;
+        if self.infinities.any() || dof <= A::zero() {
+            A::nan()
+        } else {
+            self.sum_sq_dev / dof
+        }
+    }
+}
+
+/// The numbers of positive and negative infinite values in the window.
+#[derive(Default)]
+struct Infinities {
+    positive: usize,
+    negative: usize,
+}
+
+impl Infinities {
+    /// Counts `x` if it is infinite, and returns whether it is.
+    fn push<A: Float>(&mut self, x: A) -> bool {
+        match Self::sign(x) {
+            Some(true) => self.positive += 1,
+            Some(false) => self.negative += 1,
+            None => return false,
+        }
+        true
+    }
+
+    /// Uncounts `x` if it is infinite, and returns whether it is.
+    fn pop<A: Float>(&mut self, x: A) -> bool {
+        match Self::sign(x) {
+            Some(true) => self.positive -= 1,
+            Some(false) => self.negative -= 1,
+            None => return false,
+        }
+        true
+    }
+
+    fn sign<A: Float>(x: A) -> Option<bool> {
+        if x.is_infinite() {
+            Some(x > A::zero())
+        } else {
+            None
+        }
+    }
+
+    fn any(&self) -> bool {
+        self.positive + self.negative > 0
+    }
+
+    /// Returns the sum of the window if it is determined by its infinite
+    /// values, i.e. `±∞`, or NaN if both signs are present.
+    fn sum<A: Float>(&self) -> Option<A> {
+        match (self.positive > 0, self.negative > 0) {
+            (true, true) => Some(A::nan()),
+            (true, false) => Some(A::infinity()),
+            (false, true) => Some(A::neg_infinity()),
+            (false, false) => None,
+        }
+    }
+}
+
+/// Running minimum or maximum, kept with a monotone deque of the values that
+/// can still become the extremum of a later window.
+pub(super) struct Extremum<A> {
+    /// `(index, value)` pairs whose values are strictly monotone from the
+    /// front (the current extremum) to the back.
+    deque: VecDeque<(usize, A)>,
+    /// The ordering of a value that replaces the back of the deque.
+    replaces: Ordering,
+}
+
+impl<A> Extremum<A> {
+    pub(super) fn min() -> Self {
+        Extremum {
+            deque: VecDeque::new(),
+            replaces: Ordering::Less,
+        }
+    }
+
+    pub(super) fn max() -> Self {
+        Extremum {
+            deque: VecDeque::new(),
+            replaces: Ordering::Greater,
+        }
+    }
+}
+
+impl<A: Float> Accumulator<A> for Extremum<A> {
+    fn push(&mut self, index: usize, x: A) {
+        while let Some(&(_, back)) = self.deque.back() {
+            let ordering = x.partial_cmp(&back);
+            if ordering == Some(Ordering::Equal) || ordering == Some(self.replaces) {
+                self.deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.deque.push_back((index, x));
+    }
+
+    fn pop(&mut self, index: usize, _x: A) {
+        if let Some(&(front_index, _)) = self.deque.front() {
+            if front_index == index {
+                self.deque.pop_front();
+            }
+        }
+    }
+
+    fn value(&self, _count: usize) -> A {
+        self.deque.front().map_or_else(A::nan, |&(_, x)| x)
+    }
+}
+
+/// Running quantile, kept with a Fenwick tree counting the values of the
+/// window by rank in the (sorted) lane: adding or removing a value and
+/// selecting the `k`-th smallest value of the window are O(log n).
+pub(super) struct OrderStatistics<A, I> {
+    /// The non-NaN values of the lane, in increasing order.
+    sorted: Vec<A>,
+    /// The rank in `sorted` of the value at each index of the lane.
+    ranks: Vec<usize>,
+    /// 1-based Fenwick tree of the number of values in the window by rank.
+    tree: Vec<usize>,
+    q: N64,
+    interpolate: PhantomData<I>,
+}
+
+impl<A, I> OrderStatistics<A, I>
+where
+    A: Float,
+    I: Interpolate<A>,
+{
+    pub(super) fn new(lane: ArrayView1<'_, A>, q: N64) -> Self {
+        let mut indexed: Vec<(usize, A)> = lane
+            .iter()
+            .cloned()
+            .enumerate()
+            .filter(|(_, x)| !x.is_nan())
+            .collect();
+        indexed.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+        let mut ranks = vec![usize::MAX; lane.len()];
+        for (rank, &(index, _)) in indexed.iter().enumerate() {
+            ranks[index] = rank;
+        }
+        OrderStatistics {
+            tree: vec![0; indexed.len() + 1],
+            sorted: indexed.into_iter().map(|(_, x)| x).collect(),
+            ranks,
+            q,
+            interpolate: PhantomData,
+        }
+    }
+
+    fn update(&mut self, index: usize, increment: bool) {
+        let mut node = self.ranks[index] + 1;
+        while node < self.tree.len() {
+            if increment {
+                self.tree[node] += 1;
+            } else {
+                self.tree[node] -= 1;
+            }
+            node += node & node.wrapping_neg();
+        }
+    }
+
+    /// Returns the `k`-th smallest value of the window (starting from 0).
+    fn select(&self, k: usize) -> A {
+        let mut position = 0;
+        let mut remaining = k + 1;
+        let mut step = (self.tree.len() - 1).next_power_of_two();
+        while step > 0 {
+            let next = position + step;
+            if next < self.tree.len() && self.tree[next] < remaining {
+                position = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+        self.sorted[position]
+    }
+}
+
+impl<A, I> Accumulator<A> for OrderStatistics<A, I>
+where
+    A: Float,
+    I: Interpolate<A>,
+{
+    fn push(&mut self, index: usize, _x: A) {
+        self.update(index, true);
+    }
+
+    fn pop(&mut self, index: usize, _x: A) {
+        self.update(index, false);
+    }
+
+    fn value(&self, count: usize) -> A {
+        if count == 0 {
+            return A::nan();
+        }
+        let lower = if I::needs_lower(self.q, count) {
+            Some(self.select(lower_index(self.q, count)))
+        } else {
+            None
+        };
+        let higher = if I::needs_higher(self.q, count) {
+            Some(self.select(higher_index(self.q, count)))
+        } else {
+            None
+        };
+        I::interpolate(lower, higher, self.q, count)
+    }
+}
@@ -0,0 +1,388 @@
+//! Moving-window statistics.
+use self::accumulators::{Accumulator, Extremum, Mean, OrderStatistics, Sum, Variance};
+use crate::errors::QuantileError;
+use crate::quantile::interpolate::{Interpolate, Linear};
+use ndarray::{Array, ArrayBase, ArrayView1, ArrayViewMut1, Axis, Data, Dimension, Zip};
+use noisy_float::types::{n64, N64};
+use num_traits::{Float, FromPrimitive};
+
+mod accumulators;
+
+/// The moving window used by the methods of [`RollingExt`].
+///
+/// A window is described by:
+///
+/// * its `size`, the number of consecutive elements it spans;
+/// * its alignment: by default the window is *trailing*, i.e. the output at
+///   index `i` summarizes the elements `i + 1 - size ..= i`; a
+///   [`centered`] window summarizes the elements
+///   `i - size / 2 ..= i + (size - 1) / 2` instead. Windows are truncated at
+///   the boundaries of the lanes;
+/// * the minimum number of (non-NaN) observations required to produce a
+///   value, see [`min_periods`], which defaults to `size`: the outputs for
+///   windows with fewer observations are NaN;
+/// * whether NaN values are skipped, see [`skip_nan`]. By default, the
+///   output for a window containing a NaN value is NaN.
+///
+/// # Example
+///
+/// ```
+/// use ndarray_stats::RollingWindow;
+///
+/// // A centered window of 5 elements, which produces a value as soon as it
+/// // contains 3 non-NaN elements.
+/// let window = RollingWindow::new(5).centered().min_periods(3).skip_nan();
+/// ```
+///
+/// [`RollingExt`]: trait.RollingExt.html
+/// [`centered`]: #method.centered
+/// [`min_periods`]: #method.min_periods
+/// [`skip_nan`]: #method.skip_nan
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RollingWindow {
+    size: usize,
+    centered: bool,
+    min_periods: usize,
+    skip_nan: bool,
+}
+
+impl RollingWindow {
+    /// Returns a trailing window spanning `size` elements, which requires
+    /// `size` non-NaN observations and doesn't skip NaN values.
+    ///
+    /// **Panics** if `size` is zero.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "The size of the window must be positive.");
+        RollingWindow {
+            size,
+            centered: false,
+            min_periods: size,
+            skip_nan: false,
+        }
+    }
+
+    /// Centers the window on each element instead of ending it there.
+    pub fn centered(mut self) -> Self {
+        self.centered = true;
+        self
+    }
+
+    /// Sets the minimum number of non-NaN observations in a window required
+    /// to produce a value.
+    ///
+    /// **Panics** if `min_periods` is greater than the size of the window.
+    pub fn min_periods(mut self, min_periods: usize) -> Self {
+        assert!(
+            min_periods <= self.size,
+            "`min_periods` must not be greater than the size of the window."
+        );
+        self.min_periods = min_periods;
+        self
+    }
+
+    /// Skips the NaN values in each window instead of propagating them.
+    pub fn skip_nan(mut self) -> Self {
+        self.skip_nan = true;
+        self
+    }
+
+    /// Returns the number of elements spanned by the window.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of elements before and after the current one
+    /// spanned by the window.
+    fn offsets(&self) -> (usize, usize) {
+        if self.centered {
+            (self.size / 2, (self.size - 1) / 2)
+        } else {
+            (self.size - 1, 0)
+        }
+    }
+}
+
+/// Extension trait for `ArrayBase` providing moving-window statistics along
+/// an axis.
+///
+/// All methods return an array of the same shape as the input, whose lanes
+/// along `axis` hold the statistic of the [`RollingWindow`] around each
+/// element (NaN where the window doesn't contain enough observations).
+///
+/// The windows are updated incrementally as they slide along each lane, so
+/// that the complexity doesn't depend on the size of the window (or only
+/// logarithmically, for the quantiles): the sums are compensated,
+/// the variance uses Welford's algorithm, the minimum and maximum use
+/// monotone deques and the quantiles use a Fenwick tree over the ranks of
+/// the values of the lane.
+///
+/// [`RollingWindow`]: struct.RollingWindow.html
+pub trait RollingExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the moving sum along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{RollingExt, RollingWindow};
+    ///
+    /// let a = array![1f64, 2., 3., 4.];
+    /// let sums = a.rolling_sum(Axis(0), RollingWindow::new(2));
+    /// assert!(sums[0].is_nan());
+    /// assert_eq!(sums.slice(ndarray::s![1..]), array![3., 5., 7.]);
+    ///
+    /// let sums = a.rolling_sum(Axis(0), RollingWindow::new(3).centered().min_periods(1));
+    /// assert_eq!(sums, array![3., 6., 9., 7.]);
+    /// ```
+    fn rolling_sum(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float;
+
+    /// Returns the moving arithmetic mean along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the size of the window.
+    fn rolling_mean(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the moving variance along `axis`, with `ddof` "delta degrees of
+    /// freedom" (see [`var_axis`]).
+    ///
+    /// The output is NaN for windows with no more than `ddof` observations.
+    ///
+    /// **Panics** if `ddof` is negative, if `axis` is out of bounds or if
+    /// `A::from_usize()` fails to convert the size of the window.
+    ///
+    /// [`var_axis`]: https://docs.rs/ndarray/0.15/ndarray/struct.ArrayBase.html#method.var_axis
+    fn rolling_var(&self, axis: Axis, window: RollingWindow, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the moving standard deviation along `axis`, with `ddof`
+    /// "delta degrees of freedom", see [`rolling_var`].
+    ///
+    /// **Panics** if `ddof` is negative, if `axis` is out of bounds or if
+    /// `A::from_usize()` fails to convert the size of the window.
+    ///
+    /// [`rolling_var`]: #tymethod.rolling_var
+    fn rolling_std(&self, axis: Axis, window: RollingWindow, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the moving minimum along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    fn rolling_min(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float;
+
+    /// Returns the moving maximum along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    fn rolling_max(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float;
+
+    /// Returns the moving median along `axis`, i.e. the moving 0.5-quantile
+    /// with linear interpolation (see [`rolling_quantile`]).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`rolling_quantile`]: #tymethod.rolling_quantile
+    fn rolling_median(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the moving `q`-quantile along `axis`, using the interpolation
+    /// strategy `I` (see [`quantile_axis_mut`] for the definition).
+    ///
+    /// Returns `Err(QuantileError::InvalidQuantile(q))` if `q` is not between
+    /// `0.` and `1.` (inclusive).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{interpolate::Higher, RollingExt, RollingWindow};
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![5., 1., 4., f64::NAN, 2., 3.];
+    /// let window = RollingWindow::new(3).min_periods(2).skip_nan();
+    /// let quantiles = a.rolling_quantile(Axis(0), window, n64(0.5), &Higher).unwrap();
+    /// assert!(quantiles[0].is_nan());
+    /// assert_eq!(quantiles.slice(ndarray::s![1..]), array![5., 4., 4., 4., 3.]);
+    /// ```
+    ///
+    /// [`quantile_axis_mut`]: trait.QuantileExt.html#tymethod.quantile_axis_mut
+    fn rolling_quantile<I>(
+        &self,
+        axis: Axis,
+        window: RollingWindow,
+        q: N64,
+        interpolate: &I,
+    ) -> Result<Array<A, D>, QuantileError>
+    where
+        A: Float,
+        I: Interpolate<A>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> RollingExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn rolling_sum(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float,
+    {
+        rolling(self, axis, window, |_| Sum::new())
+    }
+
+    fn rolling_mean(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        rolling(self, axis, window, |_| Mean::new())
+    }
+
+    fn rolling_var(&self, axis: Axis, window: RollingWindow, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        assert!(ddof >= A::zero(), "`ddof` must not be negative.");
+        rolling(self, axis, window, |_| Variance::new(ddof))
+    }
+
+    fn rolling_std(&self, axis: Axis, window: RollingWindow, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        let mut std = self.rolling_var(axis, window, ddof);
+        std.mapv_inplace(A::sqrt);
+        std
+    }
+
+    fn rolling_min(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float,
+    {
+        rolling(self, axis, window, |_| Extremum::min())
+    }
+
+    fn rolling_max(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float,
+    {
+        rolling(self, axis, window, |_| Extremum::max())
+    }
+
+    fn rolling_median(&self, axis: Axis, window: RollingWindow) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        self.rolling_quantile(axis, window, n64(0.5), &Linear)
+            .expect("0.5 is a valid quantile.")
+    }
+
+    fn rolling_quantile<I>(
+        &self,
+        axis: Axis,
+        window: RollingWindow,
+        q: N64,
+        _interpolate: &I,
+    ) -> Result<Array<A, D>, QuantileError>
+    where
+        A: Float,
+        I: Interpolate<A>,
+    {
+        if !(0. ..=1.).contains(&q.raw()) {
+            return Err(QuantileError::InvalidQuantile(q));
+        }
+        Ok(rolling(self, axis, window, |lane| {
+            OrderStatistics::<A, I>::new(lane, q)
+        }))
+    }
+
+    private_impl! {}
+}
+
+/// Applies the accumulator returned by `new_accumulator` for each lane along `axis`.
+fn rolling<A, S, D, T, F>(
+    arr: &ArrayBase<S, D>,
+    axis: Axis,
+    window: RollingWindow,
+    new_accumulator: F,
+) -> Array<A, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+    T: Accumulator<A>,
+    F: Fn(ArrayView1<'_, A>) -> T,
+{
+    let mut result = Array::from_elem(arr.raw_dim(), A::nan());
+    Zip::from(result.lanes_mut(axis))
+        .and(arr.lanes(axis))
+        .for_each(|output, lane| {
+            rolling_lane(lane, output, window, new_accumulator(lane));
+        });
+    result
+}
+
+/// Slides `window` along `lane`, writing the value of `accumulator` for each
+/// element to `output`.
+fn rolling_lane<A, T>(
+    lane: ArrayView1<'_, A>,
+    mut output: ArrayViewMut1<'_, A>,
+    window: RollingWindow,
+    mut accumulator: T,
+) where
+    A: Float,
+    T: Accumulator<A>,
+{
+    let len = lane.len();
+    let (before, after) = window.offsets();
+    // The window spans `start..end`, with `count` non-NaN values.
+    let mut start = 0;
+    let mut end = 0;
+    let mut count = 0;
+    let mut nan_count = 0;
+    for i in 0..len {
+        let new_end = (i + after + 1).min(len);
+        while end < new_end {
+            let x = lane[end];
+            if x.is_nan() {
+                nan_count += 1;
+            } else {
+                accumulator.push(end, x);
+                count += 1;
+            }
+            end += 1;
+        }
+        let new_start = i.saturating_sub(before);
+        while start < new_start {
+            let x = lane[start];
+            if x.is_nan() {
+                nan_count -= 1;
+            } else {
+                accumulator.pop(start, x);
+                count -= 1;
+            }
+            start += 1;
+        }
+        if count >= window.min_periods && (window.skip_nan || nan_count == 0) {
+            output[i] = accumulator.value(count);
+        }
+    }
+}
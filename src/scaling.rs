@@ -0,0 +1,540 @@
+use crate::errors::{EmptyInput, ShapeMismatch};
+use crate::maybe_nan::MaybeNan;
+use crate::quantile::interpolate::Interpolate;
+use crate::quantile::QuantileExt;
+use ndarray::{aview1, Array, Array1, ArrayBase, ArrayView, Axis, Data, DataMut, Dimension};
+use ndarray::{RemoveAxis, Zip};
+use noisy_float::types::n64;
+use num_traits::{Float, FromPrimitive};
+
+/// The means and standard deviations of the lanes along an axis, fitted by
+/// [`fit_standardization`] to standardize arrays to z-scores:
+///
+/// ```text
+/// z = (x - mean) / std
+/// ```
+///
+/// Lanes with a standard deviation of zero are only centered.
+///
+/// [`fit_standardization`]: trait.ScalingExt.html#tymethod.fit_standardization
+#[derive(Clone, Debug, PartialEq)]
+pub struct Standardization<A, D: Dimension> {
+    axis: Axis,
+    mean: Array<A, D>,
+    std: Array<A, D>,
+}
+
+/// The minima and maxima of the lanes along an axis, fitted by
+/// [`fit_min_max_scaling`] to rescale arrays so that each lane of the
+/// fitted array spans `[0, 1]`:
+///
+/// ```text
+/// y = (x - min) / (max - min)
+/// ```
+///
+/// Lanes with a range of zero are only shifted.
+///
+/// [`fit_min_max_scaling`]: trait.ScalingExt.html#tymethod.fit_min_max_scaling
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinMaxScaling<A, D: Dimension> {
+    axis: Axis,
+    min: Array<A, D>,
+    max: Array<A, D>,
+}
+
+/// The medians and interquartile ranges of the lanes along an axis, fitted
+/// by [`fit_robust_scaling`] to rescale arrays robustly to outliers:
+///
+/// ```text
+/// y = (x - median) / (q₃ - q₁)
+/// ```
+///
+/// Lanes with an interquartile range of zero are only centered.
+///
+/// [`fit_robust_scaling`]: trait.ScalingExt.html#tymethod.fit_robust_scaling
+#[derive(Clone, Debug, PartialEq)]
+pub struct RobustScaling<A, D: Dimension> {
+    axis: Axis,
+    median: Array<A, D>,
+    iqr: Array<A, D>,
+}
+
+macro_rules! impl_scaling {
+    (
+        $scaling:ident,
+        $(#[$first_meta:meta])* $first:ident,
+        $(#[$second_meta:meta])* $second:ident,
+        $transform:expr
+    ) => {
+        impl<A, D> $scaling<A, D>
+        where
+            A: Float,
+            D: RemoveAxis,
+        {
+            /// Returns the axis along which the parameters were fitted.
+            pub fn axis(&self) -> Axis {
+                self.axis
+            }
+
+            $(#[$first_meta])*
+            pub fn $first(&self) -> ArrayView<'_, A, D::Smaller> {
+                self.$first.index_axis(self.axis, 0)
+            }
+
+            $(#[$second_meta])*
+            pub fn $second(&self) -> ArrayView<'_, A, D::Smaller> {
+                self.$second.index_axis(self.axis, 0)
+            }
+
+            /// Returns a new array with the fitted scaling applied to `a`.
+            ///
+            /// `a` may have any length along the fitted axis, but must have
+            /// the same length as the fitted array along every other axis:
+            /// otherwise, `Err(ShapeMismatch)` is returned, where
+            /// `first_shape` is the shape of the parameters (with a length
+            /// of 1 along the fitted axis).
+            pub fn transform<S>(&self, a: &ArrayBase<S, D>) -> Result<Array<A, D>, ShapeMismatch>
+            where
+                S: Data<Elem = A>,
+            {
+                let mut transformed = a.to_owned();
+                self.transform_inplace(&mut transformed)?;
+                Ok(transformed)
+            }
+
+            /// Applies the fitted scaling to `a` in place, see [`transform`]
+            /// for more details.
+            ///
+            /// [`transform`]: #method.transform
+            pub fn transform_inplace<S>(&self, a: &mut ArrayBase<S, D>) -> Result<(), ShapeMismatch>
+            where
+                S: DataMut<Elem = A>,
+            {
+                apply(self.axis, &self.$first, &self.$second, a, $transform)
+            }
+        }
+    };
+}
+
+impl_scaling! {
+    Standardization,
+    /// Returns the fitted mean of each lane.
+    mean,
+    /// Returns the fitted standard deviation of each lane.
+    std,
+    |x, mean, std| (x - mean) / nonzero(std)
+}
+
+impl_scaling! {
+    MinMaxScaling,
+    /// Returns the fitted minimum of each lane.
+    min,
+    /// Returns the fitted maximum of each lane.
+    max,
+    |x, min, max| (x - min) / nonzero(max - min)
+}
+
+impl_scaling! {
+    RobustScaling,
+    /// Returns the fitted median of each lane.
+    median,
+    /// Returns the fitted interquartile range of each lane.
+    iqr,
+    |x, median, iqr| (x - median) / nonzero(iqr)
+}
+
+/// Extension trait for `ArrayBase` providing methods to standardize and
+/// rescale the lanes of an array along an axis.
+///
+/// Each transform comes in three flavours: `fit_*` returns the fitted
+/// parameters, so that the scaling learned on one array (e.g. a training
+/// set) can be applied to others, while the other two methods fit and apply
+/// the scaling to the array itself, returning a new array or in place.
+///
+/// NaN values propagate: a lane with a NaN value has NaN parameters.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::{array, Axis};
+/// use ndarray_stats::ScalingExt;
+///
+/// let train = array![[1., 10.], [3., 10.], [5., 40.]];
+/// let scaling = train.fit_min_max_scaling(Axis(0)).unwrap();
+/// assert_eq!(scaling.transform(&train).unwrap(), array![[0., 0.], [0.5, 0.], [1., 1.]]);
+/// let test = array![[2., 25.]];
+/// assert_eq!(scaling.transform(&test).unwrap(), array![[0.25, 0.5]]);
+/// ```
+pub trait ScalingExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Fits the mean and the standard deviation of each 1-dimensional lane
+    /// along `axis`, see [`Standardization`].
+    ///
+    /// The standard deviations are computed with [`std_axis`], with `ddof`
+    /// degrees of freedom.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `ddof` is negative or
+    /// greater than the length of `axis`, or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`Standardization`]: struct.Standardization.html
+    /// [`std_axis`]: https://docs.rs/ndarray/0.15/ndarray/struct.ArrayBase.html#method.std_axis
+    fn fit_standardization(&self, axis: Axis, ddof: A) -> Result<Standardization<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the z-scores of the elements of each 1-dimensional lane along
+    /// `axis`, see [`fit_standardization`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `ddof` is negative or
+    /// greater than the length of `axis`, or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`fit_standardization`]: #tymethod.fit_standardization
+    fn standardize(&self, axis: Axis, ddof: A) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Replaces the elements of each 1-dimensional lane along `axis` by their
+    /// z-scores, see [`fit_standardization`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `ddof` is negative or
+    /// greater than the length of `axis`, or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`fit_standardization`]: #tymethod.fit_standardization
+    fn standardize_inplace(&mut self, axis: Axis, ddof: A) -> Result<(), EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        S: DataMut,
+        D: RemoveAxis;
+
+    /// Fits the minimum and the maximum of each 1-dimensional lane along
+    /// `axis`, see [`MinMaxScaling`].
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`MinMaxScaling`]: struct.MinMaxScaling.html
+    fn fit_min_max_scaling(&self, axis: Axis) -> Result<MinMaxScaling<A, D>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Rescales each 1-dimensional lane along `axis` to `[0, 1]`, see
+    /// [`fit_min_max_scaling`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`fit_min_max_scaling`]: #tymethod.fit_min_max_scaling
+    fn min_max_scale(&self, axis: Axis) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Rescales each 1-dimensional lane along `axis` to `[0, 1]` in place,
+    /// see [`fit_min_max_scaling`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`fit_min_max_scaling`]: #tymethod.fit_min_max_scaling
+    fn min_max_scale_inplace(&mut self, axis: Axis) -> Result<(), EmptyInput>
+    where
+        A: Float,
+        S: DataMut,
+        D: RemoveAxis;
+
+    /// Fits the median and the interquartile range of each 1-dimensional lane
+    /// along `axis`, see [`RobustScaling`].
+    ///
+    /// The quartiles are computed with [`quantiles_axis_mut`] on a copy of
+    /// each lane, using the `interpolate` strategy.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`RobustScaling`]: struct.RobustScaling.html
+    /// [`quantiles_axis_mut`]: trait.QuantileExt.html#tymethod.quantiles_axis_mut
+    fn fit_robust_scaling<I>(
+        &self,
+        axis: Axis,
+        interpolate: &I,
+    ) -> Result<RobustScaling<A, D>, EmptyInput>
+    where
+        A: MaybeNan + Float,
+        A::NotNan: Clone + Ord,
+        D: RemoveAxis,
+        I: Interpolate<A::NotNan>;
+
+    /// Centers each 1-dimensional lane along `axis` on its median and
+    /// rescales it by its interquartile range, see [`fit_robust_scaling`]
+    /// for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`fit_robust_scaling`]: #tymethod.fit_robust_scaling
+    fn robust_scale<I>(&self, axis: Axis, interpolate: &I) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: MaybeNan + Float,
+        A::NotNan: Clone + Ord,
+        D: RemoveAxis,
+        I: Interpolate<A::NotNan>;
+
+    /// Centers each 1-dimensional lane along `axis` on its median and
+    /// rescales it by its interquartile range in place, see
+    /// [`fit_robust_scaling`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`fit_robust_scaling`]: #tymethod.fit_robust_scaling
+    fn robust_scale_inplace<I>(&mut self, axis: Axis, interpolate: &I) -> Result<(), EmptyInput>
+    where
+        A: MaybeNan + Float,
+        A::NotNan: Clone + Ord,
+        S: DataMut,
+        D: RemoveAxis,
+        I: Interpolate<A::NotNan>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> ScalingExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn fit_standardization(&self, axis: Axis, ddof: A) -> Result<Standardization<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let mean = self.mean_axis(axis).ok_or(EmptyInput)?;
+        let std = self.std_axis(axis, ddof);
+        Ok(Standardization {
+            axis,
+            mean: keep_axis(mean, axis),
+            std: keep_axis(std, axis),
+        })
+    }
+
+    fn standardize(&self, axis: Axis, ddof: A) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let standardization = self.fit_standardization(axis, ddof)?;
+        Ok(standardization
+            .transform(self)
+            .expect("The fitted array has the fitted shape."))
+    }
+
+    fn standardize_inplace(&mut self, axis: Axis, ddof: A) -> Result<(), EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        let standardization = self.fit_standardization(axis, ddof)?;
+        standardization
+            .transform_inplace(self)
+            .expect("The fitted array has the fitted shape.");
+        Ok(())
+    }
+
+    fn fit_min_max_scaling(&self, axis: Axis) -> Result<MinMaxScaling<A, D>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        // Unlike `Float::min` and `Float::max`, NaN values propagate
+        let min = self.map_axis(axis, |lane| {
+            lane.fold(
+                A::infinity(),
+                |acc, &x| if x < acc || x.is_nan() { x } else { acc },
+            )
+        });
+        let max = self.map_axis(axis, |lane| {
+            lane.fold(A::neg_infinity(), |acc, &x| {
+                if x > acc || x.is_nan() {
+                    x
+                } else {
+                    acc
+                }
+            })
+        });
+        Ok(MinMaxScaling {
+            axis,
+            min: keep_axis(min, axis),
+            max: keep_axis(max, axis),
+        })
+    }
+
+    fn min_max_scale(&self, axis: Axis) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        let scaling = self.fit_min_max_scaling(axis)?;
+        Ok(scaling
+            .transform(self)
+            .expect("The fitted array has the fitted shape."))
+    }
+
+    fn min_max_scale_inplace(&mut self, axis: Axis) -> Result<(), EmptyInput>
+    where
+        A: Float,
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        let scaling = self.fit_min_max_scaling(axis)?;
+        scaling
+            .transform_inplace(self)
+            .expect("The fitted array has the fitted shape.");
+        Ok(())
+    }
+
+    fn fit_robust_scaling<I>(
+        &self,
+        axis: Axis,
+        interpolate: &I,
+    ) -> Result<RobustScaling<A, D>, EmptyInput>
+    where
+        A: MaybeNan + Float,
+        A::NotNan: Clone + Ord,
+        D: RemoveAxis,
+        I: Interpolate<A::NotNan>,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let qs = [n64(0.25), n64(0.5), n64(0.75)];
+        let quartiles = self.map_axis(axis, |lane| {
+            let not_nan: Option<Array1<A::NotNan>> =
+                lane.iter().map(|x| x.try_as_not_nan().cloned()).collect();
+            match not_nan {
+                Some(mut not_nan) => {
+                    let quartiles = not_nan
+                        .quantiles_axis_mut(Axis(0), &aview1(&qs), interpolate)
+                        .expect("The quartiles of a non-empty array are valid.")
+                        .mapv(A::from_not_nan);
+                    (quartiles[1], quartiles[2] - quartiles[0])
+                }
+                None => (A::nan(), A::nan()),
+            }
+        });
+        Ok(RobustScaling {
+            axis,
+            median: keep_axis(quartiles.mapv(|(median, _)| median), axis),
+            iqr: keep_axis(quartiles.mapv(|(_, iqr)| iqr), axis),
+        })
+    }
+
+    fn robust_scale<I>(&self, axis: Axis, interpolate: &I) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: MaybeNan + Float,
+        A::NotNan: Clone + Ord,
+        D: RemoveAxis,
+        I: Interpolate<A::NotNan>,
+    {
+        let scaling = self.fit_robust_scaling(axis, interpolate)?;
+        Ok(scaling
+            .transform(self)
+            .expect("The fitted array has the fitted shape."))
+    }
+
+    fn robust_scale_inplace<I>(&mut self, axis: Axis, interpolate: &I) -> Result<(), EmptyInput>
+    where
+        A: MaybeNan + Float,
+        A::NotNan: Clone + Ord,
+        S: DataMut,
+        D: RemoveAxis,
+        I: Interpolate<A::NotNan>,
+    {
+        let scaling = self.fit_robust_scaling(axis, interpolate)?;
+        scaling
+            .transform_inplace(self)
+            .expect("The fitted array has the fitted shape.");
+        Ok(())
+    }
+
+    private_impl! {}
+}
+
+/// Reinserts `axis` (with length 1) into the parameters fitted along it, so
+/// that they broadcast against the lanes.
+fn keep_axis<A, D>(parameters: Array<A, D::Smaller>, axis: Axis) -> Array<A, D>
+where
+    D: RemoveAxis,
+{
+    parameters
+        .insert_axis(axis)
+        .into_dimensionality()
+        .expect("Inserting an axis restores the dimensionality.")
+}
+
+/// Returns `scale`, unless it is zero (so that constant lanes are only shifted).
+fn nonzero<A: Float>(scale: A) -> A {
+    if scale == A::zero() {
+        A::one()
+    } else {
+        scale
+    }
+}
+
+/// Applies `f(x, first, second)` to the elements of `a`, with the parameters
+/// `first` and `second` broadcast along `axis`.
+fn apply<A, S, D, F>(
+    axis: Axis,
+    first: &Array<A, D>,
+    second: &Array<A, D>,
+    a: &mut ArrayBase<S, D>,
+    f: F,
+) -> Result<(), ShapeMismatch>
+where
+    A: Float,
+    S: DataMut<Elem = A>,
+    D: Dimension,
+    F: Fn(A, A, A) -> A,
+{
+    let compatible = a.ndim() == first.ndim()
+        && a.shape()
+            .iter()
+            .zip(first.shape())
+            .enumerate()
+            .all(|(i, (a_len, len))| i == axis.index() || a_len == len);
+    if !compatible {
+        return Err(ShapeMismatch {
+            first_shape: first.shape().to_vec(),
+            second_shape: a.shape().to_vec(),
+        });
+    }
+    Zip::from(a)
+        .and_broadcast(first)
+        .and_broadcast(second)
+        .for_each(|x, &first, &second| *x = f(*x, first, second));
+    Ok(())
+}
@@ -25,7 +25,7 @@ where
     /// Complexity ([quickselect](https://en.wikipedia.org/wiki/Quickselect)):
     /// - average case: O(`n`);
     /// - worst case: O(`n`^2);
-    /// where n is the number of elements in the array.
+    ///   where n is the number of elements in the array.
     ///
     /// **Panics** if `i` is greater than or equal to `n`.
     fn get_from_sorted_mut(&mut self, i: usize) -> A
@@ -40,6 +40,9 @@ where
     /// The `IndexMap` is sorted with respect to indexes in increasing order:
     /// this ordering is preserved when you iterate over it (using `iter`/`into_iter`).
     ///
+    /// After the shuffling, the array is partitioned around each retrieved
+    /// index `i`, as described in [`get_from_sorted_mut`].
+    ///
     /// **Panics** if any element in `indexes` is greater than or equal to `n`,
     /// where `n` is the length of the array..
     ///
@@ -213,7 +216,7 @@ where
     _get_many_from_sorted_mut_unchecked(array.view_mut(), &mut indexes.to_owned(), &mut values);
 
     // We convert the vector to a more search-friendly `IndexMap`.
-    indexes.iter().cloned().zip(values.into_iter()).collect()
+    indexes.iter().cloned().zip(values).collect()
 }
 
 /// This is the recursive portion of `get_many_from_sorted_mut_unchecked`.
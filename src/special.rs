@@ -0,0 +1,88 @@
+//! Special functions, shared by the entropy estimators and Student's
+//! t-distribution.
+use std::f64::consts::PI;
+
+/// Returns `ln(Γ(x))` for `x > 0`, using the Lanczos approximation.
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1. - x);
+    }
+    let x = x - 1.;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |series, (i, &c)| {
+            series + c / (x + (i + 1) as f64)
+        });
+    let t = x + G + 0.5;
+    0.5 * (2. * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// Returns the digamma function `ψ(x)` for `x > 0`, using the recurrence
+/// `ψ(x) = ψ(x + 1) - 1/x` up to its asymptotic expansion.
+pub(crate) fn digamma(mut x: f64) -> f64 {
+    let mut result = 0.;
+    while x < 10. {
+        result -= 1. / x;
+        x += 1.;
+    }
+    let inv2 = 1. / (x * x);
+    result + x.ln()
+        - 0.5 / x
+        - inv2
+            * (1. / 12.
+                - inv2 * (1. / 120. - inv2 * (1. / 252. - inv2 * (1. / 240. - inv2 / 132.))))
+}
+
+/// Returns the trigamma function `ψ₁(x)` for `x > 0`, using the recurrence
+/// `ψ₁(x) = ψ₁(x + 1) + 1/x²` up to its asymptotic expansion.
+pub(crate) fn trigamma(mut x: f64) -> f64 {
+    let mut result = 0.;
+    while x < 10. {
+        result += 1. / (x * x);
+        x += 1.;
+    }
+    let inv2 = 1. / (x * x);
+    result
+        + 1. / x
+        + inv2 / 2.
+        + inv2 / x * (1. / 6. - inv2 * (1. / 30. - inv2 * (1. / 42. - inv2 / 30.)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_special_functions() {
+        let pi = std::f64::consts::PI;
+        let euler_gamma = 0.577_215_664_901_532_9;
+        assert_abs_diff_eq!(ln_gamma(0.5), pi.sqrt().ln(), epsilon = 1e-12);
+        assert_abs_diff_eq!(ln_gamma(1.), 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(ln_gamma(10.), 362_880f64.ln(), epsilon = 1e-12);
+        assert_abs_diff_eq!(ln_gamma(1e-9), -(1e-9f64).ln(), epsilon = 1e-8);
+        assert_abs_diff_eq!(digamma(1.), -euler_gamma, epsilon = 1e-12);
+        assert_abs_diff_eq!(digamma(0.5), -euler_gamma - 2. * 2f64.ln(), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            digamma(100.),
+            (1..100).map(|k| 1. / k as f64).sum::<f64>() - euler_gamma,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(trigamma(1.), pi * pi / 6., epsilon = 1e-12);
+        assert_abs_diff_eq!(trigamma(0.5), pi * pi / 2., epsilon = 1e-12);
+    }
+}
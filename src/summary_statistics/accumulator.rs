@@ -0,0 +1,267 @@
+use super::means::inner_central_moments;
+use crate::errors::EmptyInput;
+use ndarray::{ArrayBase, Data, Dimension};
+use num_integer::binomial;
+use num_traits::{Float, FromPrimitive};
+
+/// A streaming accumulator of the count, the mean and the central moments
+/// (up to a fixed order) of a sequence of values.
+///
+/// Values can be added one at a time with [`push`] or a whole array at a time
+/// with [`push_array`], and two accumulators built from disjoint chunks of the
+/// data can be combined with [`merge`]. This makes it possible to compute
+/// the same statistics as [`central_moments`], [`skewness`] and [`kurtosis`]
+/// over data that does not fit in memory at once, or in parallel.
+///
+/// The accumulator stores the sums of powers of the deviations from the mean,
+///
+/// ```text
+///       n
+/// Mₚ =  ∑ (xᵢ-x̅)ᵖ
+///      i=1
+/// ```
+///
+/// which are updated using the arbitrary-order pairwise formulas
+/// of [Pébay, 2008] (a generalization of Welford's algorithm).
+///
+/// # Example
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use ndarray_stats::{MomentsAccumulator, SummaryStatisticsExt};
+///
+/// let a = array![1., 4., 2., 8., 5., 7.];
+///
+/// let mut first = MomentsAccumulator::new(4);
+/// first.push_array(&a.slice(ndarray::s![..2]));
+/// let mut second = MomentsAccumulator::new(4);
+/// for &x in a.slice(ndarray::s![2..]).iter() {
+///     second.push(x);
+/// }
+/// first.merge(&second);
+///
+/// assert_eq!(first.count(), 6);
+/// assert_abs_diff_eq!(first.mean().unwrap(), a.mean().unwrap(), epsilon = 1e-12);
+/// assert_abs_diff_eq!(first.kurtosis().unwrap(), a.kurtosis().unwrap(), epsilon = 1e-12);
+/// ```
+///
+/// [`push`]: #method.push
+/// [`push_array`]: #method.push_array
+/// [`merge`]: #method.merge
+/// [`central_moments`]: trait.SummaryStatisticsExt.html#tymethod.central_moments
+/// [`skewness`]: trait.SummaryStatisticsExt.html#tymethod.skewness
+/// [`kurtosis`]: trait.SummaryStatisticsExt.html#tymethod.kurtosis
+/// [Pébay, 2008]: https://www.osti.gov/biblio/1028931
+#[derive(Clone, Debug, PartialEq)]
+pub struct MomentsAccumulator<A> {
+    count: usize,
+    mean: A,
+    /// `sums[p]` holds `Mₚ` for `p >= 2`; `sums[0]` and `sums[1]` are unused.
+    sums: Vec<A>,
+}
+
+impl<A> MomentsAccumulator<A>
+where
+    A: Float + FromPrimitive,
+{
+    /// Returns a new, empty accumulator tracking the central moments
+    /// up to (and including) `order`.
+    pub fn new(order: u16) -> Self {
+        MomentsAccumulator {
+            count: 0,
+            mean: A::zero(),
+            sums: vec![A::zero(); usize::from(order) + 1],
+        }
+    }
+
+    /// Returns the highest order of the central moments tracked by the accumulator.
+    pub fn order(&self) -> u16 {
+        (self.sums.len() - 1) as u16
+    }
+
+    /// Returns the number of values added to the accumulator.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no values have been added to the accumulator.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Adds a single value to the accumulator.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of values.
+    pub fn push(&mut self, x: A) {
+        self.combine(1, x, &[]);
+    }
+
+    /// Adds all the elements of `arr` to the accumulator.
+    ///
+    /// The moments of `arr` are computed with the same corrected two-pass
+    /// algorithm used by [`central_moments`], and then merged into the
+    /// accumulator. In particular, pushing a whole array into an empty
+    /// accumulator gives the same result as [`central_moments`] (up to
+    /// floating point rounding).
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of values.
+    ///
+    /// [`central_moments`]: trait.SummaryStatisticsExt.html#tymethod.central_moments
+    pub fn push_array<S, D>(&mut self, arr: &ArrayBase<S, D>)
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        if arr.is_empty() {
+            return;
+        }
+        let count = arr.len();
+        let n = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+        let mean = arr.sum() / n;
+        let sums: Vec<A> = inner_central_moments(arr, self.order())
+            .into_iter()
+            .map(|central_moment| central_moment * n)
+            .collect();
+        self.combine(count, mean, &sums);
+    }
+
+    /// Merges the values accumulated by `other` into `self`.
+    ///
+    /// The result is the same (up to floating point rounding) as if all the
+    /// values added to `other` had been added to `self`.
+    ///
+    /// **Panics** if `self` and `other` do not track the same order or if
+    /// `A::from_usize()` fails to convert the number of values.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.order(),
+            other.order(),
+            "Only accumulators of the same order can be merged."
+        );
+        if other.is_empty() {
+            return;
+        }
+        self.combine(other.count, other.mean, &other.sums);
+    }
+
+    /// Returns the arithmetic mean of the accumulated values.
+    ///
+    /// If no values have been added, `Err(EmptyInput)` is returned.
+    pub fn mean(&self) -> Result<A, EmptyInput> {
+        if self.is_empty() {
+            Err(EmptyInput)
+        } else {
+            Ok(self.mean)
+        }
+    }
+
+    /// Returns the *p*-th central moment of the accumulated values, see
+    /// [`central_moment`] for the definition.
+    ///
+    /// If no values have been added, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `order` is greater than [`order`](#method.order).
+    ///
+    /// [`central_moment`]: trait.SummaryStatisticsExt.html#tymethod.central_moment
+    pub fn central_moment(&self, order: u16) -> Result<A, EmptyInput> {
+        assert!(
+            order <= self.order(),
+            "`order` must not be greater than the order of the accumulator."
+        );
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(match order {
+            0 => A::one(),
+            1 => A::zero(),
+            p => self.sums[usize::from(p)] / self.n(),
+        })
+    }
+
+    /// Returns all the central moments of the accumulated values, up to
+    /// [`order`](#method.order), ordered by increasing order.
+    ///
+    /// If no values have been added, `Err(EmptyInput)` is returned.
+    pub fn central_moments(&self) -> Result<Vec<A>, EmptyInput> {
+        (0..=self.order())
+            .map(|order| self.central_moment(order))
+            .collect()
+    }
+
+    /// Returns the Pearson's moment coefficient of skewness of the accumulated
+    /// values, see [`skewness`] for the definition.
+    ///
+    /// If no values have been added, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the accumulator tracks fewer than 3 central moments.
+    ///
+    /// [`skewness`]: trait.SummaryStatisticsExt.html#tymethod.skewness
+    pub fn skewness(&self) -> Result<A, EmptyInput> {
+        let m3 = self.central_moment(3)?;
+        Ok(m3 / self.central_moment(2)?.sqrt().powi(3))
+    }
+
+    /// Returns the kurtosis of the accumulated values, see [`kurtosis`] for
+    /// the definition.
+    ///
+    /// If no values have been added, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the accumulator tracks fewer than 4 central moments.
+    ///
+    /// [`kurtosis`]: trait.SummaryStatisticsExt.html#tymethod.kurtosis
+    pub fn kurtosis(&self) -> Result<A, EmptyInput> {
+        let m4 = self.central_moment(4)?;
+        Ok(m4 / self.central_moment(2)?.powi(2))
+    }
+
+    fn n(&self) -> A {
+        A::from_usize(self.count).expect("Converting number of elements to `A` must not fail.")
+    }
+
+    /// Combines the accumulator with the statistics of a disjoint chunk of
+    /// values, given as its count, mean and sums of powers of deviations.
+    ///
+    /// Missing entries in `sums_b` are treated as zeros.
+    fn combine(&mut self, count_b: usize, mean_b: A, sums_b: &[A]) {
+        if self.is_empty() {
+            self.count = count_b;
+            self.mean = mean_b;
+            for (p, sum) in self.sums.iter_mut().enumerate() {
+                *sum = sums_b.get(p).cloned().unwrap_or_else(A::zero);
+            }
+            return;
+        }
+        let sum_b = |p: usize| sums_b.get(p).cloned().unwrap_or_else(A::zero);
+
+        let n_a = self.n();
+        let n_b =
+            A::from_usize(count_b).expect("Converting number of elements to `A` must not fail.");
+        let count = self.count + count_b;
+        let n = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+        let delta = mean_b - self.mean;
+
+        // Higher orders are updated first, since they depend on the
+        // not-yet-updated lower orders.
+        for p in (2..self.sums.len()).rev() {
+            let mut sum = self.sums[p] + sum_b(p);
+            for k in 1..=(p - 2) {
+                let binom = A::from_usize(binomial(p, k)).unwrap();
+                let k = k as i32;
+                sum = sum
+                    + binom
+                        * delta.powi(k)
+                        * ((-n_b / n).powi(k) * self.sums[p - k as usize]
+                            + (n_a / n).powi(k) * sum_b(p - k as usize));
+            }
+            let p_i32 = p as i32;
+            sum = sum
+                + (n_a * n_b * delta / n).powi(p_i32)
+                    * (n_b.powi(1 - p_i32) - (-n_a).powi(1 - p_i32));
+            self.sums[p] = sum;
+        }
+
+        self.mean = self.mean + delta * n_b / n;
+        self.count = count;
+    }
+}
@@ -0,0 +1,78 @@
+use super::estimators::check_len;
+use crate::errors::EstimatorError;
+use num_traits::{Float, FromPrimitive};
+
+/// Returns the cumulants `[κ₀, κ₁, …, κₚ]` of a sample, given its mean and
+/// its central moments `[m₀, m₁, …, mₚ]`.
+///
+/// The cumulants of order *n ≥ 2* are shift-invariant, so they are obtained
+/// from the central moments with the usual moment-cumulant recursion (where
+/// the terms involving *m₁ = 0* vanish):
+///
+/// ```text
+///                 n-2
+/// κₙ = mₙ -        ∑  C(n-1, k-1) κₖ mₙ₋ₖ
+///                 k=2
+/// ```
+pub(super) fn cumulants_from_central_moments<A>(mean: A, central_moments: &[A]) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+{
+    let mut cumulants = Vec::with_capacity(central_moments.len());
+    for n in 0..central_moments.len() {
+        let cumulant = match n {
+            0 => A::zero(),
+            1 => mean,
+            n => (2..n - 1).fold(central_moments[n], |acc, k| {
+                acc - binomial::<A>(n - 1, k - 1) * cumulants[k] * central_moments[n - k]
+            }),
+        };
+        cumulants.push(cumulant);
+    }
+    cumulants
+}
+
+/// Checks that the `order`-th k-statistic is defined for `n` elements.
+///
+/// **Panics** if `order` is not 1, 2, 3 or 4.
+pub(super) fn check_k_statistic(n: usize, order: u16) -> Result<(), EstimatorError> {
+    assert!(
+        (1..=4).contains(&order),
+        "k-statistics are only available for orders 1 to 4."
+    );
+    check_len(n, usize::from(order))
+}
+
+/// Returns the `order`-th k-statistic of a sample of `n` elements, given its
+/// mean and its central moments `[m₀, m₁, …, mₚ]` with *p ≥ order*.
+pub(super) fn k_statistic<A>(n: usize, order: u16, mean: A, central_moments: &[A]) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let n = A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+    let one = A::one();
+    let two = A::from_u8(2).unwrap();
+    let three = A::from_u8(3).unwrap();
+    match order {
+        1 => mean,
+        2 => n / (n - one) * central_moments[2],
+        3 => n * n / ((n - one) * (n - two)) * central_moments[3],
+        4 => {
+            let m2 = central_moments[2];
+            n * n * ((n + one) * central_moments[4] - three * (n - one) * m2 * m2)
+                / ((n - one) * (n - two) * (n - three))
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the binomial coefficient `C(n, k)` as an `A`.
+pub(super) fn binomial<A>(n: usize, k: usize) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let k = k.min(n - k);
+    (0..k).fold(A::one(), |acc, i| {
+        acc * A::from_usize(n - i).unwrap() / A::from_usize(i + 1).unwrap()
+    })
+}
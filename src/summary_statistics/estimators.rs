@@ -0,0 +1,153 @@
+use crate::errors::EstimatorError;
+use num_traits::{Float, FromPrimitive};
+
+/// Estimators of the skewness of a sample, selectable in
+/// [`skewness_with`](trait.SummaryStatisticsExt.html#tymethod.skewness_with).
+///
+/// All of them are computed from the sample size *n* and the second and
+/// third central moments *m₂* and *m₃* of the sample. They match the three
+/// types of [`e1071::skewness`] in R.
+///
+/// [`e1071::skewness`]: https://www.rdocumentation.org/packages/e1071/topics/skewness
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SkewnessEstimator {
+    /// The Fisher–Pearson coefficient of skewness, i.e. the moment
+    /// estimator of the skewness, as returned by
+    /// [`skewness`](trait.SummaryStatisticsExt.html#tymethod.skewness)
+    /// (`e1071` type 1, SciPy's `skew` with `bias=True`):
+    ///
+    /// ```text
+    /// g₁ = m₃ / m₂^(3/2)
+    /// ```
+    FisherPearson,
+    /// The adjusted Fisher–Pearson standardized moment coefficient
+    /// (`e1071` type 2, SciPy's `skew` with `bias=False`, Excel's `SKEW`):
+    ///
+    /// ```text
+    /// G₁ = g₁ √(n(n-1)) / (n-2)
+    /// ```
+    ///
+    /// It requires at least 3 elements.
+    G1,
+    /// The skewness standardized by the sample standard deviation
+    /// (`e1071` type 3, MINITAB and BMDP):
+    ///
+    /// ```text
+    /// b₁ = m₃ / s³ = g₁ ((n-1)/n)^(3/2)
+    /// ```
+    B1,
+}
+
+/// Estimators of the excess kurtosis of a sample, selectable in
+/// [`kurtosis_with`](trait.SummaryStatisticsExt.html#tymethod.kurtosis_with).
+///
+/// All of them are computed from the sample size *n* and the second and
+/// fourth central moments *m₂* and *m₄* of the sample, and are zero (in
+/// expectation) for a normal distribution. They match the three types
+/// of [`e1071::kurtosis`] in R.
+///
+/// [`e1071::kurtosis`]: https://www.rdocumentation.org/packages/e1071/topics/kurtosis
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum KurtosisEstimator {
+    /// The moment estimator of the excess kurtosis, i.e.
+    /// [`kurtosis`](trait.SummaryStatisticsExt.html#tymethod.kurtosis) minus 3
+    /// (`e1071` type 1, SciPy's `kurtosis` with `bias=True`):
+    ///
+    /// ```text
+    /// g₂ = m₄ / m₂² - 3
+    /// ```
+    Moment,
+    /// The bias-corrected excess kurtosis, unbiased for normal samples
+    /// (`e1071` type 2, SciPy's `kurtosis` with `bias=False`, Excel's `KURT`):
+    ///
+    /// ```text
+    /// G₂ = ((n+1) g₂ + 6) (n-1) / ((n-2)(n-3))
+    /// ```
+    ///
+    /// It requires at least 4 elements.
+    G2,
+    /// The excess kurtosis standardized by the sample standard deviation
+    /// (`e1071` type 3, MINITAB and BMDP):
+    ///
+    /// ```text
+    /// b₂ = m₄ / s⁴ - 3 = (g₂ + 3) (1 - 1/n)² - 3
+    /// ```
+    B2,
+}
+
+impl SkewnessEstimator {
+    /// Returns the minimum number of elements the estimator is defined for.
+    fn min_len(self) -> usize {
+        match self {
+            SkewnessEstimator::G1 => 3,
+            SkewnessEstimator::FisherPearson | SkewnessEstimator::B1 => 1,
+        }
+    }
+
+    /// Checks that the estimator is defined for `n` elements.
+    pub(crate) fn check_len(self, n: usize) -> Result<(), EstimatorError> {
+        check_len(n, self.min_len())
+    }
+
+    /// Computes the estimate from the number of elements `n` and the central
+    /// moments `[m₀, m₁, m₂, m₃]`.
+    pub(crate) fn estimate<A>(self, n: usize, central_moments: &[A]) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        let g1 = central_moments[3] / central_moments[2].sqrt().powi(3);
+        let n = A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+        let one = A::one();
+        let two = A::from_u8(2).unwrap();
+        match self {
+            SkewnessEstimator::FisherPearson => g1,
+            SkewnessEstimator::G1 => g1 * (n * (n - one)).sqrt() / (n - two),
+            SkewnessEstimator::B1 => g1 * ((n - one) / n).sqrt().powi(3),
+        }
+    }
+}
+
+impl KurtosisEstimator {
+    /// Returns the minimum number of elements the estimator is defined for.
+    fn min_len(self) -> usize {
+        match self {
+            KurtosisEstimator::G2 => 4,
+            KurtosisEstimator::Moment | KurtosisEstimator::B2 => 1,
+        }
+    }
+
+    /// Checks that the estimator is defined for `n` elements.
+    pub(crate) fn check_len(self, n: usize) -> Result<(), EstimatorError> {
+        check_len(n, self.min_len())
+    }
+
+    /// Computes the estimate from the number of elements `n` and the central
+    /// moments `[m₀, m₁, m₂, m₃, m₄]`.
+    pub(crate) fn estimate<A>(self, n: usize, central_moments: &[A]) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        let three = A::from_u8(3).unwrap();
+        let g2 = central_moments[4] / central_moments[2].powi(2) - three;
+        let n = A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+        let one = A::one();
+        let two = A::from_u8(2).unwrap();
+        let six = A::from_u8(6).unwrap();
+        match self {
+            KurtosisEstimator::Moment => g2,
+            KurtosisEstimator::G2 => ((n + one) * g2 + six) * (n - one) / ((n - two) * (n - three)),
+            KurtosisEstimator::B2 => (g2 + three) * (one - one / n).powi(2) - three,
+        }
+    }
+}
+
+/// Checks that the input has at least `min_len` elements.
+pub(super) fn check_len(len: usize, min_len: usize) -> Result<(), EstimatorError> {
+    if len == 0 {
+        Err(EstimatorError::EmptyInput)
+    } else if len < min_len {
+        Err(EstimatorError::NotEnoughElements { min_len, len })
+    } else {
+        Ok(())
+    }
+}
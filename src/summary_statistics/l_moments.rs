@@ -0,0 +1,64 @@
+use super::cumulants::binomial;
+use num_traits::{Float, FromPrimitive};
+
+/// Returns the sample L-moments `[λ₁, …, λₚ]` of `values`, where *p* is
+/// `order` and *p ≤ n*.
+///
+/// The L-moments are computed from the unbiased estimators of the
+/// probability-weighted moments of the sorted values *x₍₀₎ ≤ … ≤ x₍ₙ₋₁₎*
+/// ([Hosking, 1990]):
+///
+/// ```text
+///      1 n-1  C(j, r)
+/// bᵣ = ―  ∑  ――――――――― x₍ⱼ₎          λᵣ₊₁ = ∑ (-1)ʳ⁻ᵏ C(r, k) C(r+k, k) bₖ
+///      n j=r C(n-1, r)                     k
+/// ```
+///
+/// If any of the values is NaN, all the L-moments are NaN.
+///
+/// [Hosking, 1990]: https://doi.org/10.1111/j.2517-6161.1990.tb01775.x
+pub(super) fn inner_l_moments<A>(mut values: Vec<A>, order: usize) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+{
+    if values.iter().any(|x| x.is_nan()) {
+        return vec![A::nan(); order];
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = values.len();
+    let n_elements = A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+    let probability_weighted_moments: Vec<A> = (0..order)
+        .map(|r| {
+            let sum = values
+                .iter()
+                .enumerate()
+                .skip(r)
+                .fold(A::zero(), |acc, (j, &x)| {
+                    // C(j, r) / C(n-1, r), as a product of ratios to avoid overflow
+                    let weight = (0..r).fold(A::one(), |acc, i| {
+                        acc * A::from_usize(j - i).unwrap() / A::from_usize(n - 1 - i).unwrap()
+                    });
+                    acc + weight * x
+                });
+            sum / n_elements
+        })
+        .collect();
+
+    (0..order)
+        .map(|r| {
+            probability_weighted_moments
+                .iter()
+                .enumerate()
+                .take(r + 1)
+                .fold(A::zero(), |acc, (k, &b)| {
+                    let coefficient = binomial::<A>(r, k) * binomial::<A>(r + k, k);
+                    if (r - k) % 2 == 0 {
+                        acc + coefficient * b
+                    } else {
+                        acc - coefficient * b
+                    }
+                })
+        })
+        .collect()
+}
@@ -1,8 +1,18 @@
-use super::SummaryStatisticsExt;
-use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
-use ndarray::{Array, ArrayBase, Axis, Data, Dimension, Ix1, RemoveAxis};
+use super::cumulants::{check_k_statistic, cumulants_from_central_moments, k_statistic};
+use super::estimators::check_len;
+use super::l_moments::inner_l_moments;
+use super::skipnan;
+use super::student_t::student_t_quantile;
+use super::{weights::Weights, KurtosisEstimator, SkewnessEstimator, SummaryStatisticsExt};
+use crate::errors::{
+    ConfidenceIntervalError, EmptyInput, EstimatorError, MultiInputError, PowerMeanError,
+    ShapeMismatch,
+};
+use crate::maybe_nan::{MaybeNan, MaybeNanExt};
+use crate::summation::{Naive, Summation};
+use ndarray::{Array, ArrayBase, ArrayView1, Axis, Data, Dimension, Ix1, RemoveAxis, Zip};
 use num_integer::IterBinomial;
-use num_traits::{Float, FromPrimitive, Zero};
+use num_traits::{Float, FromPrimitive, ToPrimitive, Zero};
 use std::ops::{Add, AddAssign, Div, Mul};
 
 impl<A, S, D> SummaryStatisticsExt<A, S, D> for ArrayBase<S, D>
@@ -24,19 +34,37 @@ where
         }
     }
 
-    fn weighted_mean(&self, weights: &Self) -> Result<A, MultiInputError>
+    fn mean_with<M>(&self, _summation: &M) -> Result<A, EmptyInput>
+    where
+        A: Clone + FromPrimitive + Div<Output = A>,
+        M: Summation<A>,
+    {
+        let n_elements = self.len();
+        if n_elements == 0 {
+            Err(EmptyInput)
+        } else {
+            let n_elements = A::from_usize(n_elements)
+                .expect("Converting number of elements to `A` must not fail.");
+            Ok(M::sum_array(self) / n_elements)
+        }
+    }
+
+    fn weighted_mean<W>(&self, weights: &W) -> Result<A, MultiInputError>
     where
         A: Copy + Div<Output = A> + Mul<Output = A> + Zero,
+        W: Weights<A, D>,
     {
         return_err_if_empty!(self);
         let weighted_sum = self.weighted_sum(weights)?;
-        Ok(weighted_sum / weights.sum())
+        Ok(weighted_sum / weights.values().sum())
     }
 
-    fn weighted_sum(&self, weights: &ArrayBase<S, D>) -> Result<A, MultiInputError>
+    fn weighted_sum<W>(&self, weights: &W) -> Result<A, MultiInputError>
     where
         A: Copy + Mul<Output = A> + Zero,
+        W: Weights<A, D>,
     {
+        let weights = weights.values();
         return_err_unless_same_shape!(self, weights);
         Ok(self
             .iter()
@@ -44,31 +72,45 @@ where
             .fold(A::zero(), |acc, (&d, &w)| acc + d * w))
     }
 
-    fn weighted_mean_axis(
+    fn weighted_sum_with<W, M>(&self, weights: &W, _summation: &M) -> Result<A, MultiInputError>
+    where
+        A: Copy + Mul<Output = A>,
+        W: Weights<A, D>,
+        M: Summation<A>,
+    {
+        let weights = weights.values();
+        return_err_unless_same_shape!(self, weights);
+        Ok(M::sum(self.iter().zip(weights).map(|(&d, &w)| d * w)))
+    }
+
+    fn weighted_mean_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: Copy + Div<Output = A> + Mul<Output = A> + Zero,
         D: RemoveAxis,
+        W: Weights<A, Ix1>,
     {
         return_err_if_empty!(self);
         let mut weighted_sum = self.weighted_sum_axis(axis, weights)?;
-        let weights_sum = weights.sum();
+        let weights_sum = weights.values().sum();
         weighted_sum.mapv_inplace(|v| v / weights_sum);
         Ok(weighted_sum)
     }
 
-    fn weighted_sum_axis(
+    fn weighted_sum_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: Copy + Mul<Output = A> + Zero,
         D: RemoveAxis,
+        W: Weights<A, Ix1>,
     {
+        let weights = weights.values();
         if self.shape()[axis.index()] != weights.len() {
             return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
                 first_shape: self.shape().to_vec(),
@@ -80,68 +122,225 @@ where
         // conditions and an unwrap per lane.
         Ok(self.map_axis(axis, |lane| {
             lane.iter()
-                .zip(weights)
+                .zip(&weights)
                 .fold(A::zero(), |acc, (&d, &w)| acc + d * w)
         }))
     }
 
-    fn harmonic_mean(&self) -> Result<A, EmptyInput>
+    fn harmonic_mean(&self) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+    {
+        self.power_mean(-A::one())
+    }
+
+    fn geometric_mean(&self) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+    {
+        self.power_mean(A::zero())
+    }
+
+    fn power_mean(&self, p: A) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(PowerMeanError::EmptyInput);
+        }
+        inner_power_mean(self.iter().map(|&x| (x, A::one())), p)
+    }
+
+    fn weighted_power_mean<W>(&self, weights: &W, p: A) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        let weights = weights.values();
+        return_err_unless_same_shape!(self, weights);
+        if self.is_empty() {
+            return Err(PowerMeanError::EmptyInput);
+        }
+        if weights.iter().any(|&w| w < A::zero()) {
+            return Err(PowerMeanError::NegativeWeight);
+        }
+        inner_power_mean(self.iter().cloned().zip(weights.iter().cloned()), p)
+    }
+
+    fn weighted_geometric_mean<W>(&self, weights: &W) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        self.weighted_power_mean(weights, A::zero())
+    }
+
+    fn weighted_harmonic_mean<W>(&self, weights: &W) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        self.weighted_power_mean(weights, -A::one())
+    }
+
+    fn power_mean_axis(&self, axis: Axis, p: A) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(PowerMeanError::EmptyInput);
+        }
+        let means = self.map_axis(axis, |lane| {
+            inner_power_mean(lane.iter().map(|&x| (x, A::one())), p)
+        });
+        collect_power_means(means)
+    }
+
+    fn harmonic_mean_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.power_mean_axis(axis, -A::one())
+    }
+
+    fn geometric_mean_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.power_mean_axis(axis, A::zero())
+    }
+
+    fn weighted_power_mean_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+        p: A,
+    ) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
+    {
+        let weights = weights.values();
+        if self.shape()[axis.index()] != weights.len() {
+            return Err(PowerMeanError::ShapeMismatch(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: weights.shape().to_vec(),
+            }));
+        }
+        if self.is_empty() {
+            return Err(PowerMeanError::EmptyInput);
+        }
+        if weights.iter().any(|&w| w < A::zero()) {
+            return Err(PowerMeanError::NegativeWeight);
+        }
+        let means = self.map_axis(axis, |lane| {
+            inner_power_mean(lane.iter().cloned().zip(weights.iter().cloned()), p)
+        });
+        collect_power_means(means)
+    }
+
+    fn weighted_geometric_mean_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, PowerMeanError>
     where
         A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
     {
-        self.map(|x| x.recip())
-            .mean()
-            .map(|x| x.recip())
-            .ok_or(EmptyInput)
+        self.weighted_power_mean_axis(axis, weights, A::zero())
     }
 
-    fn geometric_mean(&self) -> Result<A, EmptyInput>
+    fn weighted_harmonic_mean_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, PowerMeanError>
     where
         A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
     {
-        self.map(|x| x.ln())
-            .mean()
-            .map(|x| x.exp())
-            .ok_or(EmptyInput)
+        self.weighted_power_mean_axis(axis, weights, -A::one())
     }
 
-    fn weighted_var(&self, weights: &Self, ddof: A) -> Result<A, MultiInputError>
+    fn weighted_var<W>(&self, weights: &W, ddof: A) -> Result<A, MultiInputError>
     where
         A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>,
     {
         return_err_if_empty!(self);
-        return_err_unless_same_shape!(self, weights);
+        return_err_unless_same_shape!(self, weights.values());
+        let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
+        let one = A::from_usize(1).expect("Converting 1 to `A` must not fail.");
+        assert!(
+            !(ddof < zero || ddof > one),
+            "`ddof` must not be less than zero or greater than one",
+        );
+        let correction = ddof * weights.correction();
+        inner_weighted_var(self, &weights.values(), correction, zero)
+    }
+
+    fn weighted_var_with<W, M>(
+        &self,
+        weights: &W,
+        ddof: A,
+        _summation: &M,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+        M: Summation<A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, weights.values());
         let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
         let one = A::from_usize(1).expect("Converting 1 to `A` must not fail.");
         assert!(
             !(ddof < zero || ddof > one),
             "`ddof` must not be less than zero or greater than one",
         );
-        inner_weighted_var(self, weights, ddof, zero)
+        let correction = ddof * weights.correction();
+        let weights = weights.values();
+        let weight_sum = M::sum_array(&weights);
+        let mean = M::sum(self.iter().zip(&weights).map(|(&x, &w)| w * x)) / weight_sum;
+        // The sum of the weighted deviations is zero in exact arithmetic: subtracting
+        // its square corrects the rounding errors of the mean (see Chan et al., 1983).
+        let deviations = || self.iter().zip(&weights).map(|(&x, &w)| (w, x - mean));
+        let s = M::sum(deviations().map(|(w, d)| w * d * d));
+        let c = M::sum(deviations().map(|(w, d)| w * d));
+        Ok((s - c * c / weight_sum) / (weight_sum - correction))
     }
 
-    fn weighted_std(&self, weights: &Self, ddof: A) -> Result<A, MultiInputError>
+    fn weighted_std<W>(&self, weights: &W, ddof: A) -> Result<A, MultiInputError>
     where
         A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>,
     {
         Ok(self.weighted_var(weights, ddof)?.sqrt())
     }
 
-    fn weighted_var_axis(
+    fn weighted_var_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
         ddof: A,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: AddAssign + Float + FromPrimitive,
         D: RemoveAxis,
+        W: Weights<A, Ix1>,
     {
         return_err_if_empty!(self);
-        if self.shape()[axis.index()] != weights.len() {
+        if self.shape()[axis.index()] != weights.values().len() {
             return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
                 first_shape: self.shape().to_vec(),
-                second_shape: weights.shape().to_vec(),
+                second_shape: weights.values().shape().to_vec(),
             }));
         }
         let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
@@ -150,29 +349,89 @@ where
             !(ddof < zero || ddof > one),
             "`ddof` must not be less than zero or greater than one",
         );
+        let correction = ddof * weights.correction();
 
-        // `weights` must be a view because `lane` is a view in this context.
-        let weights = weights.view();
+        let weights = weights.values();
         Ok(self.map_axis(axis, |lane| {
-            inner_weighted_var(&lane, &weights, ddof, zero).unwrap()
+            inner_weighted_var(&lane, &weights, correction, zero).unwrap()
         }))
     }
 
-    fn weighted_std_axis(
+    fn weighted_std_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
         ddof: A,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: AddAssign + Float + FromPrimitive,
         D: RemoveAxis,
+        W: Weights<A, Ix1>,
     {
         Ok(self
             .weighted_var_axis(axis, weights, ddof)?
             .mapv_into(|x| x.sqrt()))
     }
 
+    fn sem(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        check_len(self.len(), 2)?;
+        let n =
+            A::from_usize(self.len()).expect("Converting number of elements to `A` must not fail.");
+        Ok(self.std(A::one()) / n.sqrt())
+    }
+
+    fn sem_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        check_len(self.len_of(axis), 2)?;
+        let n = A::from_usize(self.len_of(axis))
+            .expect("Converting number of elements to `A` must not fail.");
+        Ok(self.std_axis(axis, A::one()).mapv(|std| std / n.sqrt()))
+    }
+
+    fn mean_confidence_interval(&self, level: A) -> Result<(A, A), ConfidenceIntervalError>
+    where
+        A: Float + FromPrimitive,
+    {
+        check_level(level)?;
+        let sem = self.sem()?;
+        let n =
+            A::from_usize(self.len()).expect("Converting number of elements to `A` must not fail.");
+        let mean = self.sum() / n;
+        let t = t_critical_value(level, n - A::one());
+        Ok((mean - t * sem, mean + t * sem))
+    }
+
+    fn weighted_sem<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        let std = self.weighted_std(weights, A::one())?;
+        Ok(std / effective_len(weights).sqrt())
+    }
+
+    fn weighted_mean_confidence_interval<W>(
+        &self,
+        weights: &W,
+        level: A,
+    ) -> Result<(A, A), ConfidenceIntervalError>
+    where
+        A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        check_level(level)?;
+        let sem = self.weighted_sem(weights)?;
+        let mean = self.weighted_mean(weights)?;
+        let t = t_critical_value(level, effective_len(weights) - A::one());
+        Ok((mean - t * sem, mean + t * sem))
+    }
+
     fn kurtosis(&self) -> Result<A, EmptyInput>
     where
         A: Float + FromPrimitive,
@@ -196,19 +455,7 @@ where
         if self.is_empty() {
             return Err(EmptyInput);
         }
-        match order {
-            0 => Ok(A::one()),
-            1 => Ok(A::zero()),
-            n => {
-                let mean = self.mean().unwrap();
-                let shifted_array = self.mapv(|x| x - mean);
-                let shifted_moments = moments(shifted_array, n);
-                let correction_term = -shifted_moments[1];
-
-                let coefficients = central_moment_coefficients(&shifted_moments);
-                Ok(horner_method(coefficients, correction_term))
-            }
-        }
+        Ok(inner_central_moment(self, order))
     }
 
     fn central_moments(&self, order: u16) -> Result<Vec<A>, EmptyInput>
@@ -218,89 +465,1067 @@ where
         if self.is_empty() {
             return Err(EmptyInput);
         }
-        match order {
-            0 => Ok(vec![A::one()]),
-            1 => Ok(vec![A::one(), A::zero()]),
-            n => {
-                // We only perform these operations once, and then reuse their
-                // result to compute all the required moments
-                let mean = self.mean().unwrap();
-                let shifted_array = self.mapv(|x| x - mean);
-                let shifted_moments = moments(shifted_array, n);
-                let correction_term = -shifted_moments[1];
-
-                let mut central_moments = vec![A::one(), A::zero()];
-                for k in 2..=n {
-                    let coefficients =
-                        central_moment_coefficients(&shifted_moments[..=(k as usize)]);
-                    let central_moment = horner_method(coefficients, correction_term);
-                    central_moments.push(central_moment)
-                }
-                Ok(central_moments)
-            }
+        Ok(inner_central_moments(self, order))
+    }
+
+    fn central_moments_with<M>(&self, order: u16, _summation: &M) -> Result<Vec<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        M: Summation<A>,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
         }
+        Ok(inner_central_moments_with::<_, _, _, M>(self, order))
     }
 
-    private_impl! {}
-}
+    fn kurtosis_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            let central_moments = inner_central_moments(&lane, 4);
+            central_moments[4] / central_moments[2].powi(2)
+        }))
+    }
 
-/// Private function for `weighted_var` without conditions and asserts.
-fn inner_weighted_var<A, S, D>(
-    arr: &ArrayBase<S, D>,
-    weights: &ArrayBase<S, D>,
-    ddof: A,
-    zero: A,
-) -> Result<A, MultiInputError>
-where
-    S: Data<Elem = A>,
-    A: AddAssign + Float + FromPrimitive,
-    D: Dimension,
-{
-    let mut weight_sum = zero;
-    let mut mean = zero;
-    let mut s = zero;
-    for (&x, &w) in arr.iter().zip(weights.iter()) {
-        weight_sum += w;
-        let x_minus_mean = x - mean;
-        mean += (w / weight_sum) * x_minus_mean;
-        s += w * x_minus_mean * (x - mean);
+    fn skewness_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            let central_moments = inner_central_moments(&lane, 3);
+            central_moments[3] / central_moments[2].sqrt().powi(3)
+        }))
     }
-    Ok(s / (weight_sum - ddof))
-}
 
-/// Returns a vector containing all moments of the array elements up to
-/// *order*, where the *p*-th moment is defined as:
-///
-/// ```text
-/// 1  n
-/// ―  ∑ xᵢᵖ
-/// n i=1
-/// ```
-///
-/// The returned moments are ordered by power magnitude: 0th moment, 1st moment, etc.
-///
-/// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
-fn moments<A, S, D>(a: ArrayBase<S, D>, order: u16) -> Vec<A>
-where
-    A: Float + FromPrimitive,
-    S: Data<Elem = A>,
-    D: Dimension,
-{
-    let n_elements =
-        A::from_usize(a.len()).expect("Converting number of elements to `A` must not fail");
-    let order = i32::from(order);
+    fn skewness_with(&self, estimator: SkewnessEstimator) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        estimator.check_len(self.len())?;
+        Ok(estimator.estimate(self.len(), &inner_central_moments(self, 3)))
+    }
 
-    // When k=0, we are raising each element to the 0th power
-    // No need to waste CPU cycles going through the array
-    let mut moments = vec![A::one()];
+    fn kurtosis_with(&self, estimator: KurtosisEstimator) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        estimator.check_len(self.len())?;
+        Ok(estimator.estimate(self.len(), &inner_central_moments(self, 4)))
+    }
 
-    if order >= 1 {
-        // When k=1, we don't need to raise elements to the 1th power (identity)
-        moments.push(a.sum() / n_elements)
+    fn skewness_axis_with(
+        &self,
+        axis: Axis,
+        estimator: SkewnessEstimator,
+    ) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        estimator.check_len(n)?;
+        Ok(self.map_axis(axis, |lane| {
+            estimator.estimate(n, &inner_central_moments(&lane, 3))
+        }))
     }
 
-    for k in 2..=order {
-        moments.push(a.map(|x| x.powi(k)).sum() / n_elements)
+    fn kurtosis_axis_with(
+        &self,
+        axis: Axis,
+        estimator: KurtosisEstimator,
+    ) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        estimator.check_len(n)?;
+        Ok(self.map_axis(axis, |lane| {
+            estimator.estimate(n, &inner_central_moments(&lane, 4))
+        }))
+    }
+
+    fn central_moment_axis(
+        &self,
+        axis: Axis,
+        order: u16,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| inner_central_moment(&lane, order)))
+    }
+
+    fn central_moments_axis(&self, axis: Axis, order: u16) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let mut results_shape = self.raw_dim();
+        results_shape[axis.index()] = usize::from(order) + 1;
+        let mut results = Array::zeros(results_shape);
+        Zip::from(results.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut results, lane| {
+                let central_moments = inner_central_moments(&lane, order);
+                results.assign(&ArrayView1::from(&central_moments));
+            });
+        Ok(results)
+    }
+
+    fn cumulants(&self, order: u16) -> Result<Vec<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(inner_cumulants(self, order))
+    }
+
+    fn cumulants_axis(&self, axis: Axis, order: u16) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let mut results_shape = self.raw_dim();
+        results_shape[axis.index()] = usize::from(order) + 1;
+        let mut results = Array::zeros(results_shape);
+        Zip::from(results.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut results, lane| {
+                let cumulants = inner_cumulants(&lane, order);
+                results.assign(&ArrayView1::from(&cumulants));
+            });
+        Ok(results)
+    }
+
+    fn k_statistic(&self, order: u16) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        check_k_statistic(self.len(), order)?;
+        Ok(inner_k_statistic(self, order))
+    }
+
+    fn k_statistic_axis(
+        &self,
+        axis: Axis,
+        order: u16,
+    ) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        check_k_statistic(self.len_of(axis), order)?;
+        Ok(self.map_axis(axis, |lane| inner_k_statistic(&lane, order)))
+    }
+
+    fn l_moments(&self, order: usize) -> Result<Vec<A>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        check_len(self.len(), order)?;
+        Ok(inner_l_moments(self.iter().cloned().collect(), order))
+    }
+
+    fn l_moments_axis(&self, axis: Axis, order: usize) -> Result<Array<A, D>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        check_len(self.len_of(axis), order)?;
+        let mut results_shape = self.raw_dim();
+        results_shape[axis.index()] = order;
+        let mut results = Array::zeros(results_shape);
+        Zip::from(results.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut results, lane| {
+                let l_moments = inner_l_moments(lane.to_vec(), order);
+                results.assign(&ArrayView1::from(&l_moments));
+            });
+        Ok(results)
+    }
+
+    fn l_scale(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        Ok(self.l_moments(2)?[1])
+    }
+
+    fn l_skewness(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        let l_moments = self.l_moments(3)?;
+        Ok(l_moments[2] / l_moments[1])
+    }
+
+    fn l_kurtosis(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+    {
+        let l_moments = self.l_moments(4)?;
+        Ok(l_moments[3] / l_moments[1])
+    }
+
+    fn l_scale_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        check_len(self.len_of(axis), 2)?;
+        Ok(self.map_axis(axis, |lane| inner_l_moments(lane.to_vec(), 2)[1]))
+    }
+
+    fn l_skewness_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        check_len(self.len_of(axis), 3)?;
+        Ok(self.map_axis(axis, |lane| {
+            let l_moments = inner_l_moments(lane.to_vec(), 3);
+            l_moments[2] / l_moments[1]
+        }))
+    }
+
+    fn l_kurtosis_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        check_len(self.len_of(axis), 4)?;
+        Ok(self.map_axis(axis, |lane| {
+            let l_moments = inner_l_moments(lane.to_vec(), 4);
+            l_moments[3] / l_moments[1]
+        }))
+    }
+
+    fn weighted_kurtosis<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        let central_moments = self.weighted_central_moments(weights, 4)?;
+        Ok(central_moments[4] / central_moments[2].powi(2))
+    }
+
+    fn weighted_skewness<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        let central_moments = self.weighted_central_moments(weights, 3)?;
+        Ok(central_moments[3] / central_moments[2].sqrt().powi(3))
+    }
+
+    fn weighted_central_moment<W>(&self, weights: &W, order: u16) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        let central_moments = self.weighted_central_moments(weights, order)?;
+        Ok(central_moments[usize::from(order)])
+    }
+
+    fn weighted_central_moments<W>(
+        &self,
+        weights: &W,
+        order: u16,
+    ) -> Result<Vec<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, weights.values());
+        Ok(inner_weighted_central_moments(
+            self,
+            &weights.values(),
+            order,
+        ))
+    }
+
+    fn weighted_kurtosis_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
+    {
+        let central_moments = self.weighted_central_moments_axis(axis, weights, 4)?;
+        Ok(Zip::from(central_moments.index_axis(axis, 4))
+            .and(central_moments.index_axis(axis, 2))
+            .map_collect(|&m4, &m2| m4 / m2.powi(2)))
+    }
+
+    fn weighted_skewness_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
+    {
+        let central_moments = self.weighted_central_moments_axis(axis, weights, 3)?;
+        Ok(Zip::from(central_moments.index_axis(axis, 3))
+            .and(central_moments.index_axis(axis, 2))
+            .map_collect(|&m3, &m2| m3 / m2.sqrt().powi(3)))
+    }
+
+    fn weighted_central_moment_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+        order: u16,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
+    {
+        Ok(self
+            .weighted_central_moments_axis(axis, weights, order)?
+            .index_axis_move(axis, usize::from(order)))
+    }
+
+    fn weighted_central_moments_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+        order: u16,
+    ) -> Result<Array<A, D>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
+    {
+        return_err_if_empty!(self);
+        let weights = weights.values();
+        if self.shape()[axis.index()] != weights.len() {
+            return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: weights.shape().to_vec(),
+            }));
+        }
+
+        let mut results_shape = self.raw_dim();
+        results_shape[axis.index()] = usize::from(order) + 1;
+        let mut results = Array::zeros(results_shape);
+        Zip::from(results.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut results, lane| {
+                let central_moments = inner_weighted_central_moments(&lane, &weights, order);
+                results.assign(&ArrayView1::from(&central_moments));
+            });
+        Ok(results)
+    }
+
+    fn mean_skipnan(&self) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone + FromPrimitive + Zero + Add<Output = A::NotNan> + Div<Output = A::NotNan>,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(self
+            .fold_skipnan(skipnan::Sum::new(), |acc, x| acc.push(x))
+            .mean())
+    }
+
+    fn mean_axis_skipnan(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone + FromPrimitive + Zero + Add<Output = A::NotNan> + Div<Output = A::NotNan>,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self
+            .fold_axis_skipnan(axis, skipnan::Sum::new(), |acc, x| acc.push(x))
+            .map(|sum| sum.mean()))
+    }
+
+    fn var_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let ddof = not_nan_ddof(&ddof);
+        Ok(self
+            .fold_skipnan(skipnan::Variance::new(), |acc, x| {
+                acc.push(&skipnan::to_f64(x))
+            })
+            .variance(ddof))
+    }
+
+    fn var_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let ddof = not_nan_ddof(&ddof);
+        Ok(self
+            .fold_axis_skipnan(axis, skipnan::Variance::new(), |acc, x| {
+                acc.push(&skipnan::to_f64(x))
+            })
+            .map(|variance| variance.variance(ddof)))
+    }
+
+    fn std_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let ddof = not_nan_ddof(&ddof);
+        Ok(self
+            .fold_skipnan(skipnan::Variance::new(), |acc, x| {
+                acc.push(&skipnan::to_f64(x))
+            })
+            .std(ddof))
+    }
+
+    fn std_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let ddof = not_nan_ddof(&ddof);
+        Ok(self
+            .fold_axis_skipnan(axis, skipnan::Variance::new(), |acc, x| {
+                acc.push(&skipnan::to_f64(x))
+            })
+            .map(|variance| variance.std(ddof)))
+    }
+
+    fn weighted_mean_skipnan<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + Zero
+            + Add<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        W: Weights<A, D>,
+    {
+        return_err_if_empty!(self);
+        let weights = weights.values();
+        return_err_unless_same_shape!(self, weights);
+        Ok(skipnan::weighted_mean(self.iter().zip(&weights)))
+    }
+
+    fn weighted_mean_axis_skipnan<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + Zero
+            + Add<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>,
+    {
+        return_err_if_empty!(self);
+        let weights = weights.values();
+        if self.shape()[axis.index()] != weights.len() {
+            return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: weights.shape().to_vec(),
+            }));
+        }
+        Ok(self.map_axis(axis, |lane| {
+            skipnan::weighted_mean(lane.into_iter().zip(&weights))
+        }))
+    }
+
+    fn central_moments_skipnan(&self, order: u16) -> Result<Vec<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let values = self.fold_skipnan(Vec::new(), |mut values, x| {
+            values.push(skipnan::to_f64(x));
+            values
+        });
+        Ok(skipnan::central_moments(values, order))
+    }
+
+    fn central_moments_axis_skipnan(
+        &self,
+        axis: Axis,
+        order: u16,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: MaybeNan + Clone,
+        A::NotNan: ToPrimitive + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let mut results_shape = self.raw_dim();
+        results_shape[axis.index()] = usize::from(order) + 1;
+        let mut results = Array::from_elem(results_shape, A::from_not_nan_opt(None));
+        Zip::from(results.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut results, lane| {
+                let values = lane.fold_skipnan(Vec::new(), |mut values, x| {
+                    values.push(skipnan::to_f64(x));
+                    values
+                });
+                let central_moments = skipnan::central_moments(values, order);
+                results.assign(&ArrayView1::from(&central_moments));
+            });
+        Ok(results)
+    }
+
+    fn masked_mean<S2>(&self, mask: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, mask);
+        Ok(
+            masked_fold(self, mask, skipnan::Sum::new(), skipnan::Sum::push)
+                .checked_mean()
+                .unwrap_or_else(A::nan),
+        )
+    }
+
+    fn masked_mean_axis<S2>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        return_err_unless_same_shape!(self, mask);
+        Ok(
+            masked_fold_axis(self, axis, mask, skipnan::Sum::new(), skipnan::Sum::push)
+                .map(|sum| sum.checked_mean().unwrap_or_else(A::nan)),
+        )
+    }
+
+    fn masked_var<S2>(&self, mask: &ArrayBase<S2, D>, ddof: A) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, mask);
+        Ok(masked_fold(
+            self,
+            mask,
+            skipnan::Variance::new(),
+            skipnan::Variance::push,
+        )
+        .var(&ddof)
+        .unwrap_or_else(A::nan))
+    }
+
+    fn masked_var_axis<S2>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        return_err_unless_same_shape!(self, mask);
+        Ok(masked_fold_axis(
+            self,
+            axis,
+            mask,
+            skipnan::Variance::new(),
+            skipnan::Variance::push,
+        )
+        .map(|variance| variance.var(&ddof).unwrap_or_else(A::nan)))
+    }
+
+    fn masked_std<S2>(&self, mask: &ArrayBase<S2, D>, ddof: A) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+    {
+        Ok(self.masked_var(mask, ddof)?.sqrt())
+    }
+
+    fn masked_std_axis<S2>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+        D: RemoveAxis,
+    {
+        Ok(self
+            .masked_var_axis(axis, mask, ddof)?
+            .mapv_into(|var| var.sqrt()))
+    }
+
+    private_impl! {}
+}
+
+/// Folds the elements of `arr` whose entry in `mask` is `false` with `f`.
+fn masked_fold<A, S, S2, D, B, F>(
+    arr: &ArrayBase<S, D>,
+    mask: &ArrayBase<S2, D>,
+    init: B,
+    f: F,
+) -> B
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = bool>,
+    D: Dimension,
+    F: Fn(&B, &A) -> B,
+{
+    Zip::from(arr).and(mask).fold(
+        init,
+        |acc, x, &masked| if masked { acc } else { f(&acc, x) },
+    )
+}
+
+/// Folds the elements of each lane of `arr` along `axis` whose entry in
+/// `mask` is `false` with `f`.
+fn masked_fold_axis<A, S, S2, D, B, F>(
+    arr: &ArrayBase<S, D>,
+    axis: Axis,
+    mask: &ArrayBase<S2, D>,
+    init: B,
+    f: F,
+) -> Array<B, D::Smaller>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = bool>,
+    D: RemoveAxis,
+    B: Clone,
+    F: Fn(&B, &A) -> B,
+{
+    let mut results = Array::from_elem(arr.raw_dim().remove_axis(axis), init);
+    Zip::from(&mut results)
+        .and(arr.lanes(axis))
+        .and(mask.lanes(axis))
+        .for_each(|acc, lane, mask| {
+            *acc = masked_fold(&lane, &mask, acc.clone(), &f);
+        });
+    results
+}
+
+/// Returns the weighted power mean of the `(value, weight)` pairs, which must not be empty.
+///
+/// The weights must not be negative; for `p = ±∞`, the values with a zero
+/// weight are ignored.
+fn inner_power_mean<A, I>(pairs: I, p: A) -> Result<A, PowerMeanError>
+where
+    A: Float,
+    I: Iterator<Item = (A, A)>,
+{
+    let check_domain = |x: A| {
+        if x < A::zero() || (x == A::zero() && p <= A::zero()) {
+            Err(PowerMeanError::NonPositiveValue)
+        } else {
+            Ok(x)
+        }
+    };
+    if p.is_infinite() {
+        let mut extremum = None;
+        for (x, w) in pairs {
+            let x = check_domain(x)?;
+            if w > A::zero() {
+                extremum = Some(match extremum {
+                    None => x,
+                    Some(e) if p > A::zero() => x.max(e),
+                    Some(e) => x.min(e),
+                });
+            }
+        }
+        return Ok(extremum.unwrap_or_else(A::nan));
+    }
+    if p.is_zero() {
+        let mut weighted_sum = A::zero();
+        let mut weights_sum = A::zero();
+        for (x, w) in pairs {
+            weighted_sum = weighted_sum + w * check_domain(x)?.ln();
+            weights_sum = weights_sum + w;
+        }
+        return Ok((weighted_sum / weights_sum).exp());
+    }
+    // The values are scaled by the running extremum (the maximum if `p` is
+    // positive, the minimum otherwise) so that `xᵖ` can't overflow.
+    let mut scale = A::nan();
+    let mut weighted_sum = A::zero();
+    let mut weights_sum = A::zero();
+    for (x, w) in pairs {
+        let x = check_domain(x)?;
+        if scale.is_nan() {
+            scale = x;
+        } else if (p > A::zero() && x > scale) || (p < A::zero() && x < scale) {
+            weighted_sum = weighted_sum * (scale / x).powf(p);
+            scale = x;
+        }
+        // Avoids `0 / 0` and `∞ / ∞` when the scale is zero or infinite
+        let term = if x == scale {
+            A::one()
+        } else {
+            (x / scale).powf(p)
+        };
+        weighted_sum = weighted_sum + w * term;
+        weights_sum = weights_sum + w;
+    }
+    Ok(scale * (weighted_sum / weights_sum).powf(p.recip()))
+}
+
+/// Returns the power means computed along an axis, or the first error.
+fn collect_power_means<A, D>(
+    means: Array<Result<A, PowerMeanError>, D>,
+) -> Result<Array<A, D>, PowerMeanError>
+where
+    A: Copy,
+    D: Dimension,
+{
+    if let Some(Err(err)) = means.iter().find(|mean| mean.is_err()) {
+        return Err(err.clone());
+    }
+    Ok(means.mapv(|mean| mean.unwrap()))
+}
+
+/// Returns the effective number of observations `∑ wᵢ / c(w)` of `weights`.
+fn effective_len<A, D, W>(weights: &W) -> A
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+    W: Weights<A, D>,
+{
+    weights.values().sum() / weights.correction()
+}
+
+/// Checks that the confidence `level` is strictly between zero and one.
+fn check_level<A: Float>(level: A) -> Result<(), ConfidenceIntervalError> {
+    if level > A::zero() && level < A::one() {
+        Ok(())
+    } else {
+        Err(ConfidenceIntervalError::InvalidLevel)
+    }
+}
+
+/// Returns the critical value of Student's t-distribution with `df` degrees of
+/// freedom for a two-sided confidence interval at the confidence `level`,
+/// which must have been checked with `check_level`.
+///
+/// Returns infinity if `df` is not positive.
+fn t_critical_value<A>(level: A, df: A) -> A
+where
+    A: Float + FromPrimitive,
+{
+    if df <= A::zero() {
+        return A::infinity();
+    }
+    let p = (1. + level.to_f64().unwrap()) / 2.;
+    A::from_f64(student_t_quantile(p, df.to_f64().unwrap()))
+        .expect("Converting the critical value to `A` must not fail.")
+}
+
+/// Private function for `weighted_var` without conditions and asserts.
+///
+/// `correction` is the product of `ddof` and the correction term of the weights.
+fn inner_weighted_var<A, S, S2, D>(
+    arr: &ArrayBase<S, D>,
+    weights: &ArrayBase<S2, D>,
+    correction: A,
+    zero: A,
+) -> Result<A, MultiInputError>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: AddAssign + Float + FromPrimitive,
+    D: Dimension,
+{
+    let mut weight_sum = zero;
+    let mut mean = zero;
+    let mut s = zero;
+    for (&x, &w) in arr.iter().zip(weights.iter()) {
+        weight_sum += w;
+        let x_minus_mean = x - mean;
+        mean += (w / weight_sum) * x_minus_mean;
+        s += w * x_minus_mean * (x - mean);
+    }
+    Ok(s / (weight_sum - correction))
+}
+
+/// Private function for `central_moment` without the emptiness check.
+fn inner_central_moment<A, S, D>(arr: &ArrayBase<S, D>, order: u16) -> A
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    match order {
+        0 => A::one(),
+        1 => A::zero(),
+        n => {
+            let mean = arr.mean().unwrap();
+            let shifted_array = arr.mapv(|x| x - mean);
+            let shifted_moments = moments::<_, _, _, Naive>(shifted_array, n);
+            let correction_term = -shifted_moments[1];
+
+            let coefficients = central_moment_coefficients(&shifted_moments);
+            horner_method(coefficients, correction_term)
+        }
+    }
+}
+
+/// Private function for `central_moments` without the emptiness check.
+pub(super) fn inner_central_moments<A, S, D>(arr: &ArrayBase<S, D>, order: u16) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    inner_central_moments_with::<_, _, _, Naive>(arr, order)
+}
+
+/// Returns the non-NaN `ddof` as an `f64`.
+///
+/// **Panics** if `ddof` is NaN.
+fn not_nan_ddof<A>(ddof: &A) -> f64
+where
+    A: MaybeNan,
+    A::NotNan: ToPrimitive,
+{
+    skipnan::to_f64(ddof.try_as_not_nan().expect("`ddof` must not be NaN."))
+}
+
+/// Private function for `cumulants` without the emptiness check.
+fn inner_cumulants<A, S, D>(arr: &ArrayBase<S, D>, order: u16) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let central_moments = inner_central_moments(arr, order);
+    cumulants_from_central_moments(arr.mean().unwrap(), &central_moments)
+}
+
+/// Private function for `k_statistic` without the checks.
+fn inner_k_statistic<A, S, D>(arr: &ArrayBase<S, D>, order: u16) -> A
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let central_moments = inner_central_moments(arr, order);
+    k_statistic(arr.len(), order, arr.mean().unwrap(), &central_moments)
+}
+
+/// Private function for `central_moments_with` without the emptiness check.
+fn inner_central_moments_with<A, S, D, M>(arr: &ArrayBase<S, D>, order: u16) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    D: Dimension,
+    M: Summation<A>,
+{
+    match order {
+        0 => vec![A::one()],
+        1 => vec![A::one(), A::zero()],
+        n => {
+            // We only perform these operations once, and then reuse their
+            // result to compute all the required moments
+            let n_elements = A::from_usize(arr.len())
+                .expect("Converting number of elements to `A` must not fail.");
+            let mean = M::sum_array(arr) / n_elements;
+            let shifted_array = arr.mapv(|x| x - mean);
+            let shifted_moments = moments::<_, _, _, M>(shifted_array, n);
+            central_moments_from_shifted_moments(&shifted_moments)
+        }
+    }
+}
+
+/// Private function for `weighted_central_moments` without conditions.
+fn inner_weighted_central_moments<A, S, S2, D>(
+    arr: &ArrayBase<S, D>,
+    weights: &ArrayBase<S2, D>,
+    order: u16,
+) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    match order {
+        0 => vec![A::one()],
+        1 => vec![A::one(), A::zero()],
+        n => {
+            let weights_sum = weights.sum();
+            let mean = arr
+                .iter()
+                .zip(weights)
+                .fold(A::zero(), |acc, (&x, &w)| acc + x * w)
+                / weights_sum;
+            let shifted_array = arr.mapv(|x| x - mean);
+            let shifted_moments = weighted_moments(shifted_array, weights, n);
+            central_moments_from_shifted_moments(&shifted_moments)
+        }
+    }
+}
+
+/// Returns all central moments up to the order of `shifted_moments`, given the
+/// moments of the array elements shifted by (an approximation of) their mean.
+fn central_moments_from_shifted_moments<A>(shifted_moments: &[A]) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+{
+    let correction_term = -shifted_moments[1];
+
+    let mut central_moments = vec![A::one(), A::zero()];
+    for k in 2..shifted_moments.len() {
+        let coefficients = central_moment_coefficients(&shifted_moments[..=k]);
+        let central_moment = horner_method(coefficients, correction_term);
+        central_moments.push(central_moment)
+    }
+    central_moments
+}
+
+/// Returns a vector containing all moments of the array elements up to
+/// *order*, where the *p*-th moment is defined as:
+///
+/// ```text
+/// 1  n
+/// ―  ∑ xᵢᵖ
+/// n i=1
+/// ```
+///
+/// The returned moments are ordered by power magnitude: 0th moment, 1st moment, etc.
+///
+/// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+fn moments<A, S, D, M>(a: ArrayBase<S, D>, order: u16) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    D: Dimension,
+    M: Summation<A>,
+{
+    let n_elements =
+        A::from_usize(a.len()).expect("Converting number of elements to `A` must not fail");
+    let order = i32::from(order);
+
+    // When k=0, we are raising each element to the 0th power
+    // No need to waste CPU cycles going through the array
+    let mut moments = vec![A::one()];
+
+    if order >= 1 {
+        // When k=1, we don't need to raise elements to the 1th power (identity)
+        moments.push(M::sum_array(&a) / n_elements)
+    }
+
+    for k in 2..=order {
+        moments.push(M::sum_array(&a.mapv(|x| x.powi(k))) / n_elements)
+    }
+    moments
+}
+
+/// Returns a vector containing all weighted moments of the array elements up to
+/// *order*, where the *p*-th weighted moment is defined as:
+///
+/// ```text
+///  n
+///  ∑ wᵢxᵢᵖ
+/// i=1
+/// ―――――――
+///   n
+///   ∑ wᵢ
+///  i=1
+/// ```
+///
+/// The returned moments are ordered by power magnitude: 0th moment, 1st moment, etc.
+fn weighted_moments<A, S, S2, D>(
+    a: ArrayBase<S, D>,
+    weights: &ArrayBase<S2, D>,
+    order: u16,
+) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    let weights_sum = weights.sum();
+    let order = i32::from(order);
+
+    let mut moments = vec![A::one()];
+    for k in 1..=order {
+        let weighted_sum = a
+            .iter()
+            .zip(weights)
+            .fold(A::zero(), |acc, (&x, &w)| acc + w * x.powi(k));
+        moments.push(weighted_sum / weights_sum)
     }
     moments
 }
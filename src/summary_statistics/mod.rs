@@ -1,7 +1,14 @@
 //! Summary statistics (e.g. mean, variance, etc.).
-use crate::errors::{EmptyInput, MultiInputError};
+pub use self::accumulator::MomentsAccumulator;
+pub use self::estimators::{KurtosisEstimator, SkewnessEstimator};
+use self::weights::Weights;
+use crate::errors::{
+    ConfidenceIntervalError, EmptyInput, EstimatorError, MultiInputError, PowerMeanError,
+};
+use crate::maybe_nan::MaybeNan;
+use crate::summation::Summation;
 use ndarray::{Array, ArrayBase, Axis, Data, Dimension, Ix1, RemoveAxis};
-use num_traits::{Float, FromPrimitive, Zero};
+use num_traits::{Float, FromPrimitive, ToPrimitive, Zero};
 use std::ops::{Add, AddAssign, Div, Mul};
 
 /// Extension trait for `ArrayBase` providing methods
@@ -28,6 +35,33 @@ where
     where
         A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero;
 
+    /// Returns the [`arithmetic mean`] x̅ of all elements in the array, accumulating
+    /// the sum of the elements with the given [`summation`] strategy.
+    ///
+    /// See [`mean`] for more details.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array1;
+    /// use ndarray_stats::{summation::Kahan, SummaryStatisticsExt};
+    ///
+    /// let a = Array1::<f32>::from_elem(1_000_000, 0.1);
+    /// assert!((a.mean_with(&Kahan).unwrap() - 0.1).abs() < 1e-7);
+    /// ```
+    ///
+    /// [`arithmetic mean`]: https://en.wikipedia.org/wiki/Arithmetic_mean
+    /// [`summation`]: summation/index.html
+    /// [`mean`]: #tymethod.mean
+    fn mean_with<M>(&self, summation: &M) -> Result<A, EmptyInput>
+    where
+        A: Clone + FromPrimitive + Div<Output = A>,
+        M: Summation<A>;
+
     /// Returns the [`arithmetic weighted mean`] x̅ of all elements in the array. Use `weighted_sum`
     /// if the `weights` are normalized (they sum up to 1.0).
     ///
@@ -49,9 +83,10 @@ where
     /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
     ///
     /// [`arithmetic weighted mean`] https://en.wikipedia.org/wiki/Weighted_arithmetic_mean
-    fn weighted_mean(&self, weights: &Self) -> Result<A, MultiInputError>
+    fn weighted_mean<W>(&self, weights: &W) -> Result<A, MultiInputError>
     where
-        A: Copy + Div<Output = A> + Mul<Output = A> + Zero;
+        A: Copy + Div<Output = A> + Mul<Output = A> + Zero,
+        W: Weights<A, D>;
 
     /// Returns the weighted sum of all elements in the array, that is, the dot product of the
     /// arrays `self` and `weights`. Equivalent to `weighted_mean` if the `weights` are normalized.
@@ -65,9 +100,25 @@ where
     /// The following **errors** may be returned:
     ///
     /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
-    fn weighted_sum(&self, weights: &Self) -> Result<A, MultiInputError>
+    fn weighted_sum<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: Copy + Mul<Output = A> + Zero,
+        W: Weights<A, D>;
+
+    /// Returns the weighted sum of all elements in the array, accumulated with the
+    /// given [`summation`] strategy. See [`weighted_sum`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// [`summation`]: summation/index.html
+    /// [`weighted_sum`]: #tymethod.weighted_sum
+    fn weighted_sum_with<W, M>(&self, weights: &W, summation: &M) -> Result<A, MultiInputError>
     where
-        A: Copy + Mul<Output = A> + Zero;
+        A: Copy + Mul<Output = A>,
+        W: Weights<A, D>,
+        M: Summation<A>;
 
     /// Returns the [`arithmetic weighted mean`] x̅ along `axis`. Use `weighted_mean_axis ` if the
     /// `weights` are normalized.
@@ -90,14 +141,15 @@ where
     /// * `MultiInputError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
     ///
     /// [`arithmetic weighted mean`] https://en.wikipedia.org/wiki/Weighted_arithmetic_mean
-    fn weighted_mean_axis(
+    fn weighted_mean_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: Copy + Div<Output = A> + Mul<Output = A> + Zero,
-        D: RemoveAxis;
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
 
     /// Returns the weighted sum along `axis`, that is, the dot product of `weights` and each lane
     /// of `self` along `axis`. Equivalent to `weighted_mean_axis` if the `weights` are normalized.
@@ -113,14 +165,15 @@ where
     /// The following **errors** may be returned
     ///
     /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
-    fn weighted_sum_axis(
+    fn weighted_sum_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: Copy + Mul<Output = A> + Zero,
-        D: RemoveAxis;
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
 
     /// Returns the [`harmonic mean`] `HM(X)` of all elements in the array:
     ///
@@ -130,12 +183,16 @@ where
     ///           ⎝i=1    ⎠
     /// ```
     ///
-    /// If the array is empty, `Err(EmptyInput)` is returned.
+    /// This is the [`power_mean`] with `p = -1`.
     ///
-    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if the array is empty
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
     ///
     /// [`harmonic mean`]: https://en.wikipedia.org/wiki/Harmonic_mean
-    fn harmonic_mean(&self) -> Result<A, EmptyInput>
+    /// [`power_mean`]: #tymethod.power_mean
+    fn harmonic_mean(&self) -> Result<A, PowerMeanError>
     where
         A: Float + FromPrimitive;
 
@@ -147,15 +204,264 @@ where
     ///         ⎝i=1  ⎠
     /// ```
     ///
-    /// If the array is empty, `Err(EmptyInput)` is returned.
+    /// This is the [`power_mean`] with `p = 0`.
     ///
-    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if the array is empty
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
     ///
     /// [`geometric mean`]: https://en.wikipedia.org/wiki/Geometric_mean
-    fn geometric_mean(&self) -> Result<A, EmptyInput>
+    /// [`power_mean`]: #tymethod.power_mean
+    fn geometric_mean(&self) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [`power mean`] (or generalized mean) `Mₚ(X)` of all elements in the array:
+    ///
+    /// ```text
+    ///         ⎛1  n    ⎞¹⁄ₚ
+    /// Mₚ(X) = ⎜―  ∑ xᵢᵖ⎟
+    ///         ⎝n i=1   ⎠
+    /// ```
+    ///
+    /// The arithmetic, quadratic and harmonic means are the power means with
+    /// `p = 1`, `p = 2` and `p = -1`. The power mean is extended by continuity
+    /// to the geometric mean for `p = 0`, to the maximum for `p = +∞` and to
+    /// the minimum for `p = -∞`.
+    ///
+    /// The power mean is defined for positive values: zeros are only allowed
+    /// for positive `p`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if the array is empty
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a negative value,
+    ///   or a zero when `p` is not positive
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![1., 2., 4.];
+    /// assert_abs_diff_eq!(a.power_mean(0.).unwrap(), 2., epsilon = 1e-12);
+    /// assert_abs_diff_eq!(a.power_mean(2.).unwrap(), 7f64.sqrt(), epsilon = 1e-12);
+    /// assert_eq!(a.power_mean(f64::INFINITY).unwrap(), 4.);
+    /// ```
+    ///
+    /// [`power mean`]: https://en.wikipedia.org/wiki/Generalized_mean
+    fn power_mean(&self, p: A) -> Result<A, PowerMeanError>
     where
         A: Float + FromPrimitive;
 
+    /// Returns the weighted [`power mean`] `Mₚ(X)` of all elements in the array:
+    ///
+    /// ```text
+    ///         ⎛ n       ⎞¹⁄ₚ
+    ///         ⎜ ∑ wᵢxᵢᵖ ⎟
+    ///         ⎜i=1      ⎟
+    /// Mₚ(X) = ⎜―――――――――⎟
+    ///         ⎜  n      ⎟
+    ///         ⎜  ∑ wᵢ   ⎟
+    ///         ⎝ i=1     ⎠
+    /// ```
+    ///
+    /// See [`power_mean`] for the definition of the limit cases; for `p = ±∞`,
+    /// elements with a zero weight are ignored.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if `self` is empty
+    /// * `PowerMeanError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a negative value,
+    ///   or a zero when `p` is not positive
+    /// * `PowerMeanError::NegativeWeight` if `weights` contains a negative value
+    ///
+    /// [`power mean`]: https://en.wikipedia.org/wiki/Generalized_mean
+    /// [`power_mean`]: #tymethod.power_mean
+    fn weighted_power_mean<W>(&self, weights: &W, p: A) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the weighted [`geometric mean`] of all elements in the array,
+    /// i.e. the [`weighted_power_mean`] with `p = 0`:
+    ///
+    /// ```text
+    ///             ⎛ n           n    ⎞
+    /// GM(X) = exp ⎜ ∑ wᵢ ln xᵢ ∕ ∑ wᵢ ⎟
+    ///             ⎝i=1         i=1   ⎠
+    /// ```
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if `self` is empty
+    /// * `PowerMeanError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
+    /// * `PowerMeanError::NegativeWeight` if `weights` contains a negative value
+    ///
+    /// [`geometric mean`]: https://en.wikipedia.org/wiki/Weighted_geometric_mean
+    /// [`weighted_power_mean`]: #tymethod.weighted_power_mean
+    fn weighted_geometric_mean<W>(&self, weights: &W) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the weighted [`harmonic mean`] of all elements in the array,
+    /// i.e. the [`weighted_power_mean`] with `p = -1`:
+    ///
+    /// ```text
+    ///          n      ⎛ n       ⎞⁻¹
+    /// HM(X) =  ∑ wᵢ · ⎜ ∑ wᵢxᵢ⁻¹⎟
+    ///         i=1     ⎝i=1      ⎠
+    /// ```
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if `self` is empty
+    /// * `PowerMeanError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
+    /// * `PowerMeanError::NegativeWeight` if `weights` contains a negative value
+    ///
+    /// [`harmonic mean`]: https://en.wikipedia.org/wiki/Harmonic_mean#Weighted_harmonic_mean
+    /// [`weighted_power_mean`]: #tymethod.weighted_power_mean
+    fn weighted_harmonic_mean<W>(&self, weights: &W) -> Result<A, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the [`power mean`] of each 1-dimensional lane along `axis`,
+    /// see [`power_mean`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if the length of `axis` is zero
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a negative value,
+    ///   or a zero when `p` is not positive
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`power mean`]: https://en.wikipedia.org/wiki/Generalized_mean
+    /// [`power_mean`]: #tymethod.power_mean
+    fn power_mean_axis(&self, axis: Axis, p: A) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the [`harmonic mean`] of each 1-dimensional lane along `axis`,
+    /// i.e. the [`power_mean_axis`] with `p = -1`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if the length of `axis` is zero
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`harmonic mean`]: https://en.wikipedia.org/wiki/Harmonic_mean
+    /// [`power_mean_axis`]: #tymethod.power_mean_axis
+    fn harmonic_mean_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the [`geometric mean`] of each 1-dimensional lane along `axis`,
+    /// i.e. the [`power_mean_axis`] with `p = 0`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if the length of `axis` is zero
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`geometric mean`]: https://en.wikipedia.org/wiki/Geometric_mean
+    /// [`power_mean_axis`]: #tymethod.power_mean_axis
+    fn geometric_mean_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the weighted [`power mean`] of each 1-dimensional lane along `axis`,
+    /// see [`weighted_power_mean`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if `self` is empty
+    /// * `PowerMeanError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a negative value,
+    ///   or a zero when `p` is not positive
+    /// * `PowerMeanError::NegativeWeight` if `weights` contains a negative value
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`power mean`]: https://en.wikipedia.org/wiki/Generalized_mean
+    /// [`weighted_power_mean`]: #tymethod.weighted_power_mean
+    fn weighted_power_mean_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+        p: A,
+    ) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// Returns the weighted [`geometric mean`] of each 1-dimensional lane along `axis`,
+    /// see [`weighted_geometric_mean`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if `self` is empty
+    /// * `PowerMeanError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
+    /// * `PowerMeanError::NegativeWeight` if `weights` contains a negative value
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`geometric mean`]: https://en.wikipedia.org/wiki/Weighted_geometric_mean
+    /// [`weighted_geometric_mean`]: #tymethod.weighted_geometric_mean
+    fn weighted_geometric_mean_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// Returns the weighted [`harmonic mean`] of each 1-dimensional lane along `axis`,
+    /// see [`weighted_harmonic_mean`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `PowerMeanError::EmptyInput` if `self` is empty
+    /// * `PowerMeanError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    /// * `PowerMeanError::NonPositiveValue` if the array contains a non-positive value
+    /// * `PowerMeanError::NegativeWeight` if `weights` contains a negative value
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`harmonic mean`]: https://en.wikipedia.org/wiki/Harmonic_mean#Weighted_harmonic_mean
+    /// [`weighted_harmonic_mean`]: #tymethod.weighted_harmonic_mean
+    fn weighted_harmonic_mean_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, PowerMeanError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
     /// Return weighted variance of all elements in the array.
     ///
     /// The weighted variance is computed using the [`West, D. H. D.`] incremental algorithm.
@@ -163,14 +469,46 @@ where
     ///
     /// The parameter `ddof` specifies the "delta degrees of freedom". For example, to calculate the
     /// population variance, use `ddof = 0`, or to calculate the sample variance, use `ddof = 1`.
+    /// How `ddof` corrects the sum of the weights depends on the type of `weights`: plain arrays
+    /// are treated as frequency weights, see the [`weights`] module for the other weight types.
     ///
     /// **Panics** if `ddof` is less than zero or greater than one, or if `axis` is out of bounds,
     /// or if `A::from_usize()` fails for zero or one.
     ///
     /// [`West, D. H. D.`]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Weighted_incremental_algorithm
-    fn weighted_var(&self, weights: &Self, ddof: A) -> Result<A, MultiInputError>
+    /// [`weights`]: weights/index.html
+    fn weighted_var<W>(&self, weights: &W, ddof: A) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Return weighted variance of all elements in the array, accumulating the sums
+    /// with the given [`summation`] strategy. See [`weighted_var`] for more details.
+    ///
+    /// Instead of the single-pass algorithm used by [`weighted_var`], the variance
+    /// is computed with a corrected two-pass algorithm, whose sums are accumulated
+    /// with `summation`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than one, or if `A::from_usize()`
+    /// fails for zero or one.
+    ///
+    /// [`summation`]: summation/index.html
+    /// [`weighted_var`]: #tymethod.weighted_var
+    fn weighted_var_with<W, M>(
+        &self,
+        weights: &W,
+        ddof: A,
+        summation: &M,
+    ) -> Result<A, MultiInputError>
     where
-        A: AddAssign + Float + FromPrimitive;
+        A: Float + FromPrimitive,
+        W: Weights<A, D>,
+        M: Summation<A>;
 
     /// Return weighted standard deviation of all elements in the array.
     ///
@@ -179,14 +517,18 @@ where
     ///
     /// The parameter `ddof` specifies the "delta degrees of freedom". For example, to calculate the
     /// population variance, use `ddof = 0`, or to calculate the sample variance, use `ddof = 1`.
+    /// How `ddof` corrects the sum of the weights depends on the type of `weights`: plain arrays
+    /// are treated as frequency weights, see the [`weights`] module for the other weight types.
     ///
     /// **Panics** if `ddof` is less than zero or greater than one, or if `axis` is out of bounds,
     /// or if `A::from_usize()` fails for zero or one.
     ///
     /// [`West, D. H. D.`]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Weighted_incremental_algorithm
-    fn weighted_std(&self, weights: &Self, ddof: A) -> Result<A, MultiInputError>
+    /// [`weights`]: weights/index.html
+    fn weighted_std<W>(&self, weights: &W, ddof: A) -> Result<A, MultiInputError>
     where
-        A: AddAssign + Float + FromPrimitive;
+        A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>;
 
     /// Return weighted variance along `axis`.
     ///
@@ -195,20 +537,24 @@ where
     ///
     /// The parameter `ddof` specifies the "delta degrees of freedom". For example, to calculate the
     /// population variance, use `ddof = 0`, or to calculate the sample variance, use `ddof = 1`.
+    /// How `ddof` corrects the sum of the weights depends on the type of `weights`: plain arrays
+    /// are treated as frequency weights, see the [`weights`] module for the other weight types.
     ///
     /// **Panics** if `ddof` is less than zero or greater than one, or if `axis` is out of bounds,
     /// or if `A::from_usize()` fails for zero or one.
     ///
     /// [`West, D. H. D.`]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Weighted_incremental_algorithm
-    fn weighted_var_axis(
+    /// [`weights`]: weights/index.html
+    fn weighted_var_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
         ddof: A,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: AddAssign + Float + FromPrimitive,
-        D: RemoveAxis;
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
 
     /// Return weighted standard deviation along `axis`.
     ///
@@ -217,21 +563,163 @@ where
     ///
     /// The parameter `ddof` specifies the "delta degrees of freedom". For example, to calculate the
     /// population variance, use `ddof = 0`, or to calculate the sample variance, use `ddof = 1`.
+    /// How `ddof` corrects the sum of the weights depends on the type of `weights`: plain arrays
+    /// are treated as frequency weights, see the [`weights`] module for the other weight types.
     ///
     /// **Panics** if `ddof` is less than zero or greater than one, or if `axis` is out of bounds,
     /// or if `A::from_usize()` fails for zero or one.
     ///
     /// [`West, D. H. D.`]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Weighted_incremental_algorithm
-    fn weighted_std_axis(
+    /// [`weights`]: weights/index.html
+    fn weighted_std_axis<W>(
         &self,
         axis: Axis,
-        weights: &ArrayBase<S, Ix1>,
+        weights: &W,
         ddof: A,
     ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         A: AddAssign + Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// Returns the [standard error of the mean] of all elements in the array:
+    ///
+    /// ```text
+    /// SEM = s / √n
+    /// ```
+    ///
+    /// where *s* is the sample standard deviation (with `ddof = 1`) and *n*
+    /// is the number of elements in the array.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has a single element
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// [standard error of the mean]: https://en.wikipedia.org/wiki/Standard_error
+    fn sem(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [standard error of the mean] of each 1-dimensional lane along
+    /// `axis`, see [`sem`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is one
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [standard error of the mean]: https://en.wikipedia.org/wiki/Standard_error
+    /// [`sem`]: #tymethod.sem
+    fn sem_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
         D: RemoveAxis;
 
+    /// Returns the bounds `(lower, upper)` of the two-sided [confidence interval]
+    /// of the mean of all elements in the array, at the confidence `level`
+    /// (e.g. `0.95` for a 95% confidence interval):
+    ///
+    /// ```text
+    /// x̅ ± t₍₁₊ₗₑᵥₑₗ₎⸝₂,ₙ₋₁ SEM
+    /// ```
+    ///
+    /// where *SEM* is the [standard error of the mean](#tymethod.sem) and
+    /// *t₍₁₊ₗₑᵥₑₗ₎⸝₂,ₙ₋₁* is the `(1 + level) / 2` quantile of Student's
+    /// t-distribution with *n - 1* degrees of freedom, computed by the crate.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `ConfidenceIntervalError::InvalidLevel` if `level` is not strictly
+    ///   between zero and one
+    /// * `ConfidenceIntervalError::EmptyInput` if the array is empty
+    /// * `ConfidenceIntervalError::NotEnoughElements` if the array has a single element
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![4., 6., 5., 7., 3.];
+    /// let (lower, upper) = a.mean_confidence_interval(0.95).unwrap();
+    /// // t = 2.776445 for 4 degrees of freedom, SEM = √(2.5 / 5)
+    /// assert_abs_diff_eq!(lower, 5. - 2.776445 * 0.5f64.sqrt(), epsilon = 1e-6);
+    /// assert_abs_diff_eq!(upper, 5. + 2.776445 * 0.5f64.sqrt(), epsilon = 1e-6);
+    /// ```
+    ///
+    /// [confidence interval]: https://en.wikipedia.org/wiki/Confidence_interval
+    fn mean_confidence_interval(&self, level: A) -> Result<(A, A), ConfidenceIntervalError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the weighted [standard error of the mean] of all elements in the array:
+    ///
+    /// ```text
+    /// SEM = s / √nₑ
+    /// ```
+    ///
+    /// where *s* is the [`weighted_std`] with `ddof = 1` and *nₑ = ∑ wᵢ / c(w)*
+    /// is the effective number of observations, which depends on the type of
+    /// `weights` (see the [`weights`] module): it is the sum of the weights
+    /// for frequency weights, *(∑ wᵢ)² / ∑ wᵢ²* for analytic weights and the
+    /// number of weights for probability weights.
+    ///
+    /// If the effective number of observations is not greater than one, the
+    /// result is not finite.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails for zero or one.
+    ///
+    /// [standard error of the mean]: https://en.wikipedia.org/wiki/Standard_error
+    /// [`weighted_std`]: #tymethod.weighted_std
+    /// [`weights`]: weights/index.html
+    fn weighted_sem<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the bounds `(lower, upper)` of the two-sided [confidence interval]
+    /// of the weighted mean of all elements in the array, at the confidence `level`,
+    /// see [`mean_confidence_interval`] and [`weighted_sem`] for more details.
+    ///
+    /// The t-distribution has *nₑ - 1* degrees of freedom, where *nₑ* is the
+    /// effective number of observations. If it is not greater than one, the
+    /// bounds are not finite.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `ConfidenceIntervalError::InvalidLevel` if `level` is not strictly
+    ///   between zero and one
+    /// * `ConfidenceIntervalError::EmptyInput` if `self` is empty
+    /// * `ConfidenceIntervalError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails for zero or one.
+    ///
+    /// [confidence interval]: https://en.wikipedia.org/wiki/Confidence_interval
+    /// [`mean_confidence_interval`]: #tymethod.mean_confidence_interval
+    /// [`weighted_sem`]: #tymethod.weighted_sem
+    fn weighted_mean_confidence_interval<W>(
+        &self,
+        weights: &W,
+        level: A,
+    ) -> Result<(A, A), ConfidenceIntervalError>
+    where
+        A: AddAssign + Float + FromPrimitive,
+        W: Weights<A, D>;
+
     /// Returns the [kurtosis] `Kurt[X]` of all elements in the array:
     ///
     /// ```text
@@ -311,7 +799,1041 @@ where
     where
         A: Float + FromPrimitive;
 
+    /// Returns the first *p* [central moments] of all elements in the array, accumulating
+    /// the sums of the corrected two-pass algorithm with the given [`summation`] strategy.
+    /// See [`central_moments`] for more details.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements
+    /// in the array or if `order` overflows `i32`.
+    ///
+    /// [central moments]: https://en.wikipedia.org/wiki/Central_moment
+    /// [`summation`]: summation/index.html
+    /// [`central_moments`]: #tymethod.central_moments
+    fn central_moments_with<M>(&self, order: u16, summation: &M) -> Result<Vec<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        M: Summation<A>;
+
+    /// Returns the [kurtosis] `Kurt[X]` of each 1-dimensional lane along `axis`,
+    /// see [`kurtosis`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [kurtosis]: https://en.wikipedia.org/wiki/Kurtosis
+    /// [`kurtosis`]: #tymethod.kurtosis
+    fn kurtosis_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the [Pearson's moment coefficient of skewness] γ₁ of each
+    /// 1-dimensional lane along `axis`, see [`skewness`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [Pearson's moment coefficient of skewness]: https://en.wikipedia.org/wiki/Skewness
+    /// [`skewness`]: #tymethod.skewness
+    fn skewness_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the *p*-th [central moment] of each 1-dimensional lane along `axis`,
+    /// see [`central_moment`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `A::from_usize()` fails to
+    /// convert the length of `axis` or if `order` overflows `i32`.
+    ///
+    /// [central moment]: https://en.wikipedia.org/wiki/Central_moment
+    /// [`central_moment`]: #tymethod.central_moment
+    fn central_moment_axis(
+        &self,
+        axis: Axis,
+        order: u16,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// A bulk version of [`central_moment_axis`], returning the first *p*
+    /// central moments of each 1-dimensional lane along `axis`.
+    ///
+    /// Returns an `Array`, where the subview along `axis` at index *k*
+    /// contains the *k*-th central moment of each lane (i.e. the length of
+    /// `axis` in the result is `order + 1`).
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `A::from_usize()` fails to
+    /// convert the length of `axis` or if `order` overflows `i32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![[1., 2., 3., 6.], [0., 0., 4., 4.]];
+    /// let central_moments = a.central_moments_axis(Axis(1), 2).unwrap();
+    /// assert_eq!(central_moments, array![[1., 0., 3.5], [1., 0., 4.]]);
+    /// ```
+    ///
+    /// [`central_moment_axis`]: #tymethod.central_moment_axis
+    fn central_moments_axis(&self, axis: Axis, order: u16) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the first *p* sample [cumulants] of all elements in the array.
+    ///
+    /// The returned vector has length `order + 1` and its element at index
+    /// *k* is the *k*-th cumulant κₖ, so that κ₀ = 0, κ₁ is the mean, κ₂ the
+    /// (biased) variance and κ₃ the third central moment. Higher-order
+    /// cumulants are obtained from the [central moments] with the
+    /// moment-cumulant recursion.
+    ///
+    /// These are the cumulants of the empirical distribution of the elements,
+    /// which are biased estimators of the cumulants of the underlying
+    /// distribution: see [`k_statistic`] for unbiased estimators.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements
+    /// in the array or if `order` overflows `i32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Array1};
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![1., 2., 3., 6.];
+    /// let cumulants = Array1::from(a.cumulants(4).unwrap());
+    /// assert_abs_diff_eq!(cumulants, array![0., 3., 3.5, 4.5, -12.25], epsilon = 1e-12);
+    /// ```
+    ///
+    /// [cumulants]: https://en.wikipedia.org/wiki/Cumulant
+    /// [central moments]: #tymethod.central_moments
+    /// [`k_statistic`]: #tymethod.k_statistic
+    fn cumulants(&self, order: u16) -> Result<Vec<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// A bulk version of [`cumulants`], returning the first *p* cumulants of
+    /// each 1-dimensional lane along `axis`.
+    ///
+    /// Returns an `Array`, where the subview along `axis` at index *k*
+    /// contains the *k*-th cumulant of each lane (i.e. the length of `axis`
+    /// in the result is `order + 1`).
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `A::from_usize()` fails to
+    /// convert the length of `axis` or if `order` overflows `i32`.
+    ///
+    /// [`cumulants`]: #tymethod.cumulants
+    fn cumulants_axis(&self, axis: Axis, order: u16) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the *p*-th [k-statistic] of all elements in the array, i.e.
+    /// the unique symmetric unbiased estimator of the *p*-th cumulant κₚ.
+    ///
+    /// Given the number of elements *n*, the mean *x̄* and the central
+    /// moments *mₖ* of the elements (see [`central_moments`]):
+    ///
+    /// ```text
+    /// k₁ = x̄
+    /// k₂ = n m₂ / (n-1)
+    /// k₃ = n² m₃ / ((n-1)(n-2))
+    /// k₄ = n² ((n+1) m₄ - 3(n-1) m₂²) / ((n-1)(n-2)(n-3))
+    /// ```
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has fewer than
+    ///   `order` elements
+    ///
+    /// **Panics** if `order` is not 1, 2, 3 or 4, or if `A::from_usize()`
+    /// fails to convert the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![1., 2., 3., 6.];
+    /// assert_abs_diff_eq!(a.k_statistic(2).unwrap(), 14. / 3., epsilon = 1e-12);
+    /// assert_abs_diff_eq!(a.k_statistic(3).unwrap(), 12., epsilon = 1e-12);
+    /// ```
+    ///
+    /// [k-statistic]: https://mathworld.wolfram.com/k-Statistic.html
+    /// [`central_moments`]: #tymethod.central_moments
+    fn k_statistic(&self, order: u16) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the *p*-th [k-statistic] of each 1-dimensional lane along
+    /// `axis`, see [`k_statistic`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is
+    ///   smaller than `order`
+    ///
+    /// **Panics** if `order` is not 1, 2, 3 or 4, if `axis` is out of bounds
+    /// or if `A::from_usize()` fails to convert the length of `axis`.
+    ///
+    /// [k-statistic]: https://mathworld.wolfram.com/k-Statistic.html
+    /// [`k_statistic`]: #tymethod.k_statistic
+    fn k_statistic_axis(
+        &self,
+        axis: Axis,
+        order: u16,
+    ) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the first *p* sample [L-moments] of all elements in the array.
+    ///
+    /// The returned vector has length `order` and its element at index
+    /// *r - 1* is the *r*-th L-moment λᵣ: λ₁ is the mean, λ₂ the L-scale
+    /// (half the mean absolute difference between two elements), while the
+    /// ratios λ₃/λ₂ and λ₄/λ₂ are the L-skewness and the L-kurtosis.
+    ///
+    /// The L-moments are computed from a sorted copy of the elements, as
+    /// linear combinations of the unbiased estimators of the
+    /// probability-weighted moments ([Hosking, 1990]). Unlike the
+    /// conventional moments, they are robust to outliers and exist whenever
+    /// the mean does, which makes them popular in hydrology and extreme-value
+    /// analysis. Complexity is *O(n log n + np)*.
+    ///
+    /// If any of the elements is NaN, all the L-moments are NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has fewer than
+    ///   `order` elements
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements
+    /// in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![1., 2., 3., 6.];
+    /// let l_moments = a.l_moments(3).unwrap();
+    /// assert_abs_diff_eq!(l_moments[0], 3.);
+    /// assert_abs_diff_eq!(l_moments[1], 4. / 3., epsilon = 1e-12);
+    /// assert_abs_diff_eq!(l_moments[2], 0.5, epsilon = 1e-12);
+    /// ```
+    ///
+    /// [L-moments]: https://en.wikipedia.org/wiki/L-moment
+    /// [Hosking, 1990]: https://doi.org/10.1111/j.2517-6161.1990.tb01775.x
+    fn l_moments(&self, order: usize) -> Result<Vec<A>, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// A bulk version of [`l_moments`], returning the first *p* L-moments of
+    /// each 1-dimensional lane along `axis`.
+    ///
+    /// Returns an `Array`, where the subview along `axis` at index *r - 1*
+    /// contains the *r*-th L-moment of each lane (i.e. the length of `axis`
+    /// in the result is `order`).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is
+    ///   smaller than `order`
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`l_moments`]: #tymethod.l_moments
+    fn l_moments_axis(&self, axis: Axis, order: usize) -> Result<Array<A, D>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the L-scale λ₂ of all elements in the array, see [`l_moments`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has fewer than 2 elements
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements
+    /// in the array.
+    ///
+    /// [`l_moments`]: #tymethod.l_moments
+    fn l_scale(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the L-skewness τ₃ = λ₃/λ₂ of all elements in the array, see
+    /// [`l_moments`].
+    ///
+    /// The L-skewness lies in (-1, 1); it is NaN if all the elements are equal.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has fewer than 3 elements
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements
+    /// in the array.
+    ///
+    /// [`l_moments`]: #tymethod.l_moments
+    fn l_skewness(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the L-kurtosis τ₄ = λ₄/λ₂ of all elements in the array, see
+    /// [`l_moments`].
+    ///
+    /// The L-kurtosis lies in [-1/4, 1); it is NaN if all the elements are equal.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has fewer than 4 elements
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements
+    /// in the array.
+    ///
+    /// [`l_moments`]: #tymethod.l_moments
+    fn l_kurtosis(&self) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the L-scale λ₂ of each 1-dimensional lane along `axis`, see
+    /// [`l_scale`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is smaller than 2
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`l_scale`]: #tymethod.l_scale
+    fn l_scale_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the L-skewness τ₃ of each 1-dimensional lane along `axis`, see
+    /// [`l_skewness`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is smaller than 3
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`l_skewness`]: #tymethod.l_skewness
+    fn l_skewness_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the L-kurtosis τ₄ of each 1-dimensional lane along `axis`, see
+    /// [`l_kurtosis`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is smaller than 4
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`l_kurtosis`]: #tymethod.l_kurtosis
+    fn l_kurtosis_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the skewness of all elements in the array, computed with the
+    /// selected [`SkewnessEstimator`].
+    ///
+    /// [`skewness`] is equivalent to `skewness_with(SkewnessEstimator::FisherPearson)`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has fewer elements
+    ///   than the estimator requires
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{SkewnessEstimator, SummaryStatisticsExt};
+    ///
+    /// let a = array![1., 2., 3., 4., 10.];
+    /// let g1 = a.skewness_with(SkewnessEstimator::FisherPearson).unwrap();
+    /// assert_abs_diff_eq!(g1, a.skewness().unwrap());
+    /// let adjusted_g1 = a.skewness_with(SkewnessEstimator::G1).unwrap();
+    /// assert_abs_diff_eq!(adjusted_g1, g1 * 20f64.sqrt() / 3.);
+    /// ```
+    ///
+    /// [`SkewnessEstimator`]: enum.SkewnessEstimator.html
+    /// [`skewness`]: #tymethod.skewness
+    fn skewness_with(&self, estimator: SkewnessEstimator) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the excess kurtosis of all elements in the array, computed
+    /// with the selected [`KurtosisEstimator`].
+    ///
+    /// Note that, unlike [`kurtosis`], all the estimators subtract 3 so that
+    /// the result is zero for a normal distribution: [`kurtosis`] is
+    /// equivalent to `kurtosis_with(KurtosisEstimator::Moment)` plus 3.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the array is empty
+    /// * `EstimatorError::NotEnoughElements` if the array has fewer elements
+    ///   than the estimator requires
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// [`KurtosisEstimator`]: enum.KurtosisEstimator.html
+    /// [`kurtosis`]: #tymethod.kurtosis
+    fn kurtosis_with(&self, estimator: KurtosisEstimator) -> Result<A, EstimatorError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the skewness of each 1-dimensional lane along `axis`, computed
+    /// with the selected [`SkewnessEstimator`], see [`skewness_with`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is smaller
+    ///   than the number of elements the estimator requires
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`SkewnessEstimator`]: enum.SkewnessEstimator.html
+    /// [`skewness_with`]: #tymethod.skewness_with
+    fn skewness_axis_with(
+        &self,
+        axis: Axis,
+        estimator: SkewnessEstimator,
+    ) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the excess kurtosis of each 1-dimensional lane along `axis`,
+    /// computed with the selected [`KurtosisEstimator`], see [`kurtosis_with`]
+    /// for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `EstimatorError::EmptyInput` if the length of `axis` is zero
+    /// * `EstimatorError::NotEnoughElements` if the length of `axis` is smaller
+    ///   than the number of elements the estimator requires
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the length of `axis`.
+    ///
+    /// [`KurtosisEstimator`]: enum.KurtosisEstimator.html
+    /// [`kurtosis_with`]: #tymethod.kurtosis_with
+    fn kurtosis_axis_with(
+        &self,
+        axis: Axis,
+        estimator: KurtosisEstimator,
+    ) -> Result<Array<A, D::Smaller>, EstimatorError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the weighted [kurtosis] `Kurt[X]` of all elements in the array:
+    ///
+    /// ```text
+    /// Kurt[X] = μ₄ / σ⁴
+    /// ```
+    ///
+    /// where μ₄ is the fourth weighted central moment and σ² is the second weighted
+    /// central moment of the elements in the array, see [`weighted_central_moment`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert a binomial coefficient.
+    ///
+    /// [kurtosis]: https://en.wikipedia.org/wiki/Kurtosis
+    /// [`weighted_central_moment`]: #tymethod.weighted_central_moment
+    fn weighted_kurtosis<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the weighted [Pearson's moment coefficient of skewness] γ₁ of all
+    /// elements in the array:
+    ///
+    /// ```text
+    /// γ₁ = μ₃ / σ³
+    /// ```
+    ///
+    /// where μ₃ is the third weighted central moment and σ² is the second weighted
+    /// central moment of the elements in the array, see [`weighted_central_moment`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert a binomial coefficient.
+    ///
+    /// [Pearson's moment coefficient of skewness]: https://en.wikipedia.org/wiki/Skewness
+    /// [`weighted_central_moment`]: #tymethod.weighted_central_moment
+    fn weighted_skewness<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the *p*-th weighted [central moment] of all elements in the array, μₚ:
+    ///
+    /// ```text
+    ///       n
+    ///       ∑ wᵢ(xᵢ-x̅)ᵖ
+    ///      i=1
+    /// μₚ = ――――――――――――
+    ///         n
+    ///         ∑ wᵢ
+    ///        i=1
+    /// ```
+    ///
+    /// where x̅ is the weighted mean of the elements in the array. The moments
+    /// are not bias-corrected, so the type of `weights` (see the [`weights`]
+    /// module) doesn't affect the result.
+    ///
+    /// The moment is computed with the same corrected two-pass algorithm as
+    /// [`central_moment`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert a binomial coefficient
+    /// or if `order` overflows `i32`.
+    ///
+    /// [central moment]: https://en.wikipedia.org/wiki/Central_moment
+    /// [`weights`]: weights/index.html
+    /// [`central_moment`]: #tymethod.central_moment
+    fn weighted_central_moment<W>(&self, weights: &W, order: u16) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the first *p* weighted central moments of all elements in the array,
+    /// see [`weighted_central_moment`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert a binomial coefficient
+    /// or if `order` overflows `i32`.
+    ///
+    /// [`weighted_central_moment`]: #tymethod.weighted_central_moment
+    fn weighted_central_moments<W>(
+        &self,
+        weights: &W,
+        order: u16,
+    ) -> Result<Vec<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        W: Weights<A, D>;
+
+    /// Returns the weighted kurtosis of each 1-dimensional lane along `axis`,
+    /// see [`weighted_kurtosis`] for more details.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert a binomial coefficient.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    ///
+    /// [`weighted_kurtosis`]: #tymethod.weighted_kurtosis
+    fn weighted_kurtosis_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// Returns the weighted skewness of each 1-dimensional lane along `axis`,
+    /// see [`weighted_skewness`] for more details.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert a binomial coefficient.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    ///
+    /// [`weighted_skewness`]: #tymethod.weighted_skewness
+    fn weighted_skewness_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// Returns the *p*-th weighted central moment of each 1-dimensional lane
+    /// along `axis`, see [`weighted_central_moment`] for more details.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `A::from_usize()` fails to
+    /// convert a binomial coefficient or if `order` overflows `i32`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    ///
+    /// [`weighted_central_moment`]: #tymethod.weighted_central_moment
+    fn weighted_central_moment_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+        order: u16,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// A bulk version of [`weighted_central_moment_axis`], returning the first *p*
+    /// weighted central moments of each 1-dimensional lane along `axis`.
+    ///
+    /// Returns an `Array`, where the subview along `axis` at index *k*
+    /// contains the *k*-th weighted central moment of each lane.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `A::from_usize()` fails to
+    /// convert a binomial coefficient or if `order` overflows `i32`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    ///
+    /// [`weighted_central_moment_axis`]: #tymethod.weighted_central_moment_axis
+    fn weighted_central_moments_axis<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+        order: u16,
+    ) -> Result<Array<A, D>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// Returns the arithmetic mean of the non-NaN elements of the array.
+    ///
+    /// If all the elements are NaN (or `None`, for `Option` elements), the
+    /// result is NaN (or `None`).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::NotNan::from_usize()` fails to convert the number of
+    /// non-NaN elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![1., f64::NAN, 3.];
+    /// assert_eq!(a.mean_skipnan(), Ok(2.));
+    /// let b = array![Some(n64(1.)), None, Some(n64(3.))];
+    /// assert_eq!(b.mean_skipnan(), Ok(Some(n64(2.))));
+    /// ```
+    fn mean_skipnan(&self) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone + FromPrimitive + Zero + Add<Output = A::NotNan> + Div<Output = A::NotNan>;
+
+    /// Returns the arithmetic mean of the non-NaN elements of each
+    /// 1-dimensional lane along `axis`, see [`mean_skipnan`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::NotNan::from_usize()`
+    /// fails to convert the number of non-NaN elements.
+    ///
+    /// [`mean_skipnan`]: #tymethod.mean_skipnan
+    fn mean_axis_skipnan(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone + FromPrimitive + Zero + Add<Output = A::NotNan> + Div<Output = A::NotNan>,
+        D: RemoveAxis;
+
+    /// Returns the variance of the non-NaN elements of the array.
+    ///
+    /// The variance is computed with `n - ddof` degrees of freedom, where
+    /// `n` is the number of non-NaN elements: use `ddof = 0` for the
+    /// population variance and `ddof = 1` for the sample variance. If
+    /// `n - ddof` is not positive (e.g. if all the elements are NaN), the
+    /// result is NaN (or `None`, for `Option` elements).
+    ///
+    /// The variance is computed in `f64` and only the result is converted to
+    /// `A::NotNan`, so that e.g. integer elements don't truncate the
+    /// intermediate values (the result itself is still truncated).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is NaN or if the elements or the variance can't
+    /// be converted to and from `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![1., f64::NAN, 3., 5.];
+    /// assert_eq!(a.var_skipnan(1.), Ok(4.));
+    /// assert_eq!(a.std_skipnan(1.), Ok(2.));
+    /// assert!(array![1., f64::NAN].var_skipnan(1.).unwrap().is_nan());
+    /// ```
+    fn var_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive;
+
+    /// Returns the variance of the non-NaN elements of each 1-dimensional
+    /// lane along `axis`, see [`var_skipnan`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `ddof` is NaN or if the
+    /// elements or a variance can't be converted to and from `f64`.
+    ///
+    /// [`var_skipnan`]: #tymethod.var_skipnan
+    fn var_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the standard deviation of the non-NaN elements of the array,
+    /// i.e. the square root of [`var_skipnan`] (taken in `f64`).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is NaN or if the elements or the standard
+    /// deviation can't be converted to and from `f64`.
+    ///
+    /// [`var_skipnan`]: #tymethod.var_skipnan
+    fn std_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive;
+
+    /// Returns the standard deviation of the non-NaN elements of each
+    /// 1-dimensional lane along `axis`, see [`std_skipnan`] for more details.
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `ddof` is NaN or if the
+    /// elements or a standard deviation can't be converted to and from `f64`.
+    ///
+    /// [`std_skipnan`]: #tymethod.std_skipnan
+    fn std_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the weighted mean of the elements of the array, skipping the
+    /// elements that are NaN or have a NaN weight (see [`weighted_mean`]).
+    ///
+    /// If there are no such pairs of elements and weights, the result is NaN
+    /// (or `None`, for `Option` elements).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `weights` don't have the same shape
+    ///
+    /// [`weighted_mean`]: #tymethod.weighted_mean
+    fn weighted_mean_skipnan<W>(&self, weights: &W) -> Result<A, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + Zero
+            + Add<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        W: Weights<A, D>;
+
+    /// Returns the weighted mean of each 1-dimensional lane along `axis`,
+    /// skipping the elements that are NaN or have a NaN weight, see
+    /// [`weighted_mean_skipnan`] for more details.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` length along axis is not equal to `weights` length
+    ///
+    /// [`weighted_mean_skipnan`]: #tymethod.weighted_mean_skipnan
+    fn weighted_mean_axis_skipnan<W>(
+        &self,
+        axis: Axis,
+        weights: &W,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: MaybeNan,
+        A::NotNan: Clone
+            + Zero
+            + Add<Output = A::NotNan>
+            + Mul<Output = A::NotNan>
+            + Div<Output = A::NotNan>,
+        D: RemoveAxis,
+        W: Weights<A, Ix1>;
+
+    /// Returns the first *p* central moments of the non-NaN elements of the
+    /// array, see [`central_moments`].
+    ///
+    /// The moments are computed in `f64` with a two-pass algorithm on the
+    /// non-NaN elements, and then converted to `A::NotNan`. If all the
+    /// elements are NaN, all the moments are NaN (or `None`, for `Option`
+    /// elements).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the elements or the moments can't be converted to and
+    /// from `f64`.
+    ///
+    /// [`central_moments`]: #tymethod.central_moments
+    fn central_moments_skipnan(&self, order: u16) -> Result<Vec<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: ToPrimitive + FromPrimitive;
+
+    /// A bulk version of [`central_moments_skipnan`], returning the first *p*
+    /// central moments of the non-NaN elements of each 1-dimensional lane
+    /// along `axis`.
+    ///
+    /// Returns an `Array`, where the subview along `axis` at index *k*
+    /// contains the *k*-th central moment of each lane (i.e. the length of
+    /// `axis` in the result is `order + 1`).
+    ///
+    /// If the length of `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the elements or the moments
+    /// can't be converted to and from `f64`.
+    ///
+    /// [`central_moments_skipnan`]: #tymethod.central_moments_skipnan
+    fn central_moments_axis_skipnan(
+        &self,
+        axis: Axis,
+        order: u16,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: MaybeNan + Clone,
+        A::NotNan: ToPrimitive + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the arithmetic mean of the unmasked elements of the array.
+    ///
+    /// An element is masked, and ignored, if its entry in `mask` is `true`.
+    /// This is useful when missing values are marked by sentinels or
+    /// out-of-range readings rather than by NaN. If all the elements are
+    /// masked, the result is NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `mask` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// unmasked elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![1., -999., 3., 5.];
+    /// let mask = a.mapv(|x| x == -999.);
+    /// assert_eq!(a.masked_mean(&mask), Ok(3.));
+    /// assert_eq!(a.masked_var(&mask, 1.), Ok(4.));
+    /// ```
+    fn masked_mean<S2>(&self, mask: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>;
+
+    /// Returns the arithmetic mean of the unmasked elements of each
+    /// 1-dimensional lane along `axis`, see [`masked_mean`] for more details.
+    ///
+    /// Each lane may have a different number of unmasked elements; the mean
+    /// of a lane whose elements are all masked is NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the length of `axis` is zero
+    /// * `MultiInputError::ShapeMismatch` if `self` and `mask` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the number of unmasked elements.
+    ///
+    /// [`masked_mean`]: #tymethod.masked_mean
+    fn masked_mean_axis<S2>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+        D: RemoveAxis;
+
+    /// Returns the variance of the unmasked elements of the array, i.e. the
+    /// elements whose entry in `mask` is `false`.
+    ///
+    /// The variance is computed with `n - ddof` degrees of freedom, where
+    /// `n` is the number of unmasked elements. If `n - ddof` is not positive
+    /// (e.g. if all the elements are masked), the result is NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `mask` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// unmasked elements.
+    fn masked_var<S2>(&self, mask: &ArrayBase<S2, D>, ddof: A) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>;
+
+    /// Returns the variance of the unmasked elements of each 1-dimensional
+    /// lane along `axis`, see [`masked_var`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the length of `axis` is zero
+    /// * `MultiInputError::ShapeMismatch` if `self` and `mask` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the number of unmasked elements.
+    ///
+    /// [`masked_var`]: #tymethod.masked_var
+    fn masked_var_axis<S2>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+        D: RemoveAxis;
+
+    /// Returns the standard deviation of the unmasked elements of the array,
+    /// i.e. the square root of [`masked_var`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `mask` don't have the same shape
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// unmasked elements.
+    ///
+    /// [`masked_var`]: #tymethod.masked_var
+    fn masked_std<S2>(&self, mask: &ArrayBase<S2, D>, ddof: A) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>;
+
+    /// Returns the standard deviation of the unmasked elements of each
+    /// 1-dimensional lane along `axis`, see [`masked_std`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if the length of `axis` is zero
+    /// * `MultiInputError::ShapeMismatch` if `self` and `mask` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails to
+    /// convert the number of unmasked elements.
+    ///
+    /// [`masked_std`]: #tymethod.masked_std
+    fn masked_std_axis<S2>(
+        &self,
+        axis: Axis,
+        mask: &ArrayBase<S2, D>,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        S2: Data<Elem = bool>,
+        D: RemoveAxis;
+
     private_decl! {}
 }
 
+mod accumulator;
+mod cumulants;
+mod estimators;
+mod l_moments;
 mod means;
+mod skipnan;
+mod student_t;
+pub mod weights;
@@ -0,0 +1,197 @@
+//! Running statistics of a subset of the values of an array, used for the
+//! NaN-skipping statistics and the masked ones.
+//!
+//! The NaN-skipping means are computed on `A::NotNan`, while the variances
+//! and the central moments are computed in `f64`.
+use crate::maybe_nan::MaybeNan;
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Running count and sum of the non-NaN values.
+#[derive(Clone)]
+pub(super) struct Sum<T> {
+    count: usize,
+    sum: T,
+}
+
+impl<T> Sum<T>
+where
+    T: Clone + FromPrimitive + Zero + Add<Output = T> + Div<Output = T>,
+{
+    pub(super) fn new() -> Self {
+        Sum {
+            count: 0,
+            sum: T::zero(),
+        }
+    }
+
+    pub(super) fn push(&self, x: &T) -> Self {
+        Sum {
+            count: self.count + 1,
+            sum: self.sum.clone() + x.clone(),
+        }
+    }
+
+    /// Returns the mean, or `None` if there are no values.
+    pub(super) fn checked_mean(&self) -> Option<T> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum.clone() / from_usize(self.count))
+        }
+    }
+
+    /// Returns the mean, or NaN if there are no values.
+    pub(super) fn mean<A>(&self) -> A
+    where
+        A: MaybeNan<NotNan = T>,
+    {
+        A::from_not_nan_opt(self.checked_mean())
+    }
+}
+
+/// Running count, mean and sum of the squared deviations from the mean of
+/// the non-NaN values, updated with Welford's algorithm.
+#[derive(Clone)]
+pub(super) struct Variance<T> {
+    count: usize,
+    mean: T,
+    sum_sq_dev: T,
+}
+
+impl<T> Variance<T>
+where
+    T: Clone
+        + PartialOrd
+        + FromPrimitive
+        + Zero
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    pub(super) fn new() -> Self {
+        Variance {
+            count: 0,
+            mean: T::zero(),
+            sum_sq_dev: T::zero(),
+        }
+    }
+
+    pub(super) fn push(&self, x: &T) -> Self {
+        let count = self.count + 1;
+        let delta = x.clone() - self.mean.clone();
+        let mean = self.mean.clone() + delta.clone() / from_usize(count);
+        let sum_sq_dev = self.sum_sq_dev.clone() + delta * (x.clone() - mean.clone());
+        Variance {
+            count,
+            mean,
+            sum_sq_dev,
+        }
+    }
+
+    /// Returns the variance, or `None` if there are no more values than
+    /// `ddof`.
+    pub(super) fn var(&self, ddof: &T) -> Option<T> {
+        let dof = from_usize::<T>(self.count) - ddof.clone();
+        if dof > T::zero() {
+            Some(self.sum_sq_dev.clone() / dof)
+        } else {
+            None
+        }
+    }
+}
+
+impl Variance<f64> {
+    /// Returns the variance converted to `A::NotNan`, or NaN if there are
+    /// no more values than `ddof`.
+    pub(super) fn variance<A>(&self, ddof: f64) -> A
+    where
+        A: MaybeNan,
+        A::NotNan: FromPrimitive,
+    {
+        A::from_not_nan_opt(self.var(&ddof).map(from_f64))
+    }
+
+    /// Returns the standard deviation converted to `A::NotNan`, or NaN if
+    /// there are no more values than `ddof`.
+    pub(super) fn std<A>(&self, ddof: f64) -> A
+    where
+        A: MaybeNan,
+        A::NotNan: FromPrimitive,
+    {
+        A::from_not_nan_opt(self.var(&ddof).map(|var| from_f64(var.sqrt())))
+    }
+}
+
+/// Returns the weighted mean of the pairs of values and weights that are
+/// both not NaN, or NaN if there are no such pairs.
+pub(super) fn weighted_mean<'a, A, I>(pairs: I) -> A
+where
+    A: 'a + MaybeNan,
+    A::NotNan:
+        Clone + Zero + Add<Output = A::NotNan> + Mul<Output = A::NotNan> + Div<Output = A::NotNan>,
+    I: Iterator<Item = (&'a A, &'a A)>,
+{
+    let mut count = 0;
+    let mut weighted_sum = A::NotNan::zero();
+    let mut weights_sum = A::NotNan::zero();
+    for (x, w) in pairs {
+        if let (Some(x), Some(w)) = (x.try_as_not_nan(), w.try_as_not_nan()) {
+            count += 1;
+            weighted_sum = weighted_sum + x.clone() * w.clone();
+            weights_sum = weights_sum + w.clone();
+        }
+    }
+    A::from_not_nan_opt(if count == 0 {
+        None
+    } else {
+        Some(weighted_sum / weights_sum)
+    })
+}
+
+/// Returns the central moments `[m₀, m₁, …, mₚ]` of `values` (where *p* is
+/// `order`) converted to `A::NotNan`, or NaN values if there are none.
+pub(super) fn central_moments<A>(values: Vec<f64>, order: u16) -> Vec<A>
+where
+    A: MaybeNan,
+    A::NotNan: FromPrimitive,
+{
+    let order = usize::from(order);
+    if values.is_empty() {
+        return (0..=order).map(|_| A::from_not_nan_opt(None)).collect();
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let mut sums = vec![0.; order + 1];
+    for x in &values {
+        let deviation = x - mean;
+        let mut power = 1.;
+        for sum in sums.iter_mut().skip(1) {
+            power *= deviation;
+            *sum += power;
+        }
+    }
+    sums.into_iter()
+        .enumerate()
+        .map(|(k, sum)| match k {
+            0 => A::from_not_nan(from_f64(1.)),
+            1 => A::from_not_nan(from_f64(0.)),
+            _ => A::from_not_nan(from_f64(sum / n)),
+        })
+        .collect()
+}
+
+/// Converts a non-NaN value to `f64`, in which the variance and the central
+/// moments are accumulated so that they aren't truncated for integers.
+pub(super) fn to_f64<T: ToPrimitive>(x: &T) -> f64 {
+    x.to_f64().expect("Converting to `f64` must not fail.")
+}
+
+fn from_f64<T: FromPrimitive>(x: f64) -> T {
+    T::from_f64(x).expect("Converting from `f64` must not fail.")
+}
+
+fn from_usize<T: FromPrimitive>(n: usize) -> T {
+    T::from_usize(n).expect("Converting number of elements to `A` must not fail.")
+}
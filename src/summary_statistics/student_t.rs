@@ -0,0 +1,195 @@
+//! Quantiles of Student's t-distribution, used for confidence intervals.
+use crate::special::ln_gamma;
+use std::f64::consts::PI;
+
+/// Returns the `p`-quantile of Student's t-distribution with `df` degrees of freedom.
+///
+/// The quantile is found with Newton's method on the cumulative distribution
+/// function, safeguarded by bisection, starting from the normal quantile.
+///
+/// **Panics** if `p` is not strictly between 0 and 1 or if `df` is not positive.
+pub(crate) fn student_t_quantile(p: f64, df: f64) -> f64 {
+    assert!(
+        p > 0. && p < 1.,
+        "The probability must be strictly between 0 and 1."
+    );
+    assert!(df > 0., "The degrees of freedom must be positive.");
+    if p < 0.5 {
+        return -student_t_quantile(1. - p, df);
+    }
+    if p == 0.5 {
+        return 0.;
+    }
+
+    // Bracket the quantile: the t-distribution has heavier tails than the normal one.
+    let mut lower = 0.;
+    let mut upper = normal_quantile(p).max(1.);
+    while student_t_cdf(upper, df) < p {
+        lower = upper;
+        upper *= 2.;
+    }
+
+    let mut t = normal_quantile(p).max(lower).min(upper);
+    for _ in 0..100 {
+        let error = student_t_cdf(t, df) - p;
+        if error.abs() <= 1e-15 {
+            break;
+        }
+        if error < 0. {
+            lower = t;
+        } else {
+            upper = t;
+        }
+        let newton = t - error / student_t_pdf(t, df);
+        t = if newton > lower && newton < upper {
+            newton
+        } else {
+            (lower + upper) / 2.
+        };
+        if upper - lower <= 1e-15 * upper {
+            break;
+        }
+    }
+    t
+}
+
+/// Returns the cumulative distribution function of Student's t-distribution.
+fn student_t_cdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * regularized_incomplete_beta(df / 2., 0.5, df / (df + t * t));
+    if t > 0. {
+        1. - tail
+    } else {
+        tail
+    }
+}
+
+/// Returns the probability density function of Student's t-distribution.
+fn student_t_pdf(t: f64, df: f64) -> f64 {
+    (ln_gamma((df + 1.) / 2.)
+        - ln_gamma(df / 2.)
+        - 0.5 * (df * PI).ln()
+        - (df + 1.) / 2. * (t * t / df).ln_1p())
+    .exp()
+}
+
+/// Returns an approximation of the `p`-quantile of the standard normal
+/// distribution (Abramowitz and Stegun, formula 26.2.23), for `p >= 0.5`.
+fn normal_quantile(p: f64) -> f64 {
+    let q = (-2. * (1. - p).ln()).sqrt();
+    q - (2.515517 + 0.802853 * q + 0.010328 * q * q)
+        / (1. + 1.432788 * q + 0.189269 * q * q + 0.001308 * q * q * q)
+}
+
+/// Returns the regularized incomplete beta function Iₓ(a, b).
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0. {
+        return 0.;
+    }
+    if x >= 1. {
+        return 1.;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1. - x).ln();
+    // The continued fraction converges quickly for x < (a + 1) / (a + b + 2)
+    if x < (a + 1.) / (a + b + 2.) {
+        ln_front.exp() * beta_continued_fraction(a, b, x) / a
+    } else {
+        1. - ln_front.exp() * beta_continued_fraction(b, a, 1. - x) / b
+    }
+}
+
+/// Evaluates the continued fraction of the incomplete beta function with
+/// the modified Lentz's method.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1.;
+    let mut d = 1. - (a + b) * x / (a + 1.);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1. / d;
+    let mut h = d;
+    for m in 1..1000 {
+        let m = f64::from(m);
+        let m2 = 2. * m;
+        // Even step
+        let numerator = m * (b - m) * x / ((a + m2 - 1.) * (a + m2));
+        d = 1. + numerator * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1. + numerator / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1. / d;
+        h *= d * c;
+        // Odd step
+        let numerator = -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.));
+        d = 1. + numerator * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1. + numerator / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1. / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.).abs() < 1e-16 {
+            break;
+        }
+    }
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_quantile_with_closed_forms() {
+        for &p in &[0.6, 0.9, 0.95, 0.975, 0.995, 0.9995] {
+            // Cauchy distribution
+            let expected = (PI * (p - 0.5)).tan();
+            assert_abs_diff_eq!(
+                student_t_quantile(p, 1.),
+                expected,
+                epsilon = 1e-10 * expected
+            );
+            let expected = (2. * p - 1.) / (2. * p * (1. - p)).sqrt();
+            assert_abs_diff_eq!(
+                student_t_quantile(p, 2.),
+                expected,
+                epsilon = 1e-10 * expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_quantile_with_tables() {
+        for &(df, expected) in &[
+            (3., 3.182446),
+            (5., 2.570582),
+            (10., 2.228139),
+            (30., 2.042272),
+            (120., 1.979930),
+        ] {
+            assert_abs_diff_eq!(student_t_quantile(0.975, df), expected, epsilon = 1e-6);
+        }
+        assert_abs_diff_eq!(student_t_quantile(0.995, 4.), 4.604095, epsilon = 1e-6);
+        assert_abs_diff_eq!(student_t_quantile(0.05, 7.), -1.894579, epsilon = 1e-6);
+        assert_eq!(student_t_quantile(0.5, 3.), 0.);
+    }
+
+    #[test]
+    fn test_quantile_inverts_cdf() {
+        for &df in &[0.5, 1.5, 4., 25., 1e4] {
+            for &p in &[1e-6, 0.01, 0.3, 0.7, 0.99, 1. - 1e-6] {
+                let t = student_t_quantile(p, df);
+                assert_abs_diff_eq!(student_t_cdf(t, df), p, epsilon = 1e-12);
+            }
+        }
+    }
+}
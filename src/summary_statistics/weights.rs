@@ -0,0 +1,201 @@
+//! Weight types giving a statistical meaning to the weights passed to the
+//! weighted methods of [`SummaryStatisticsExt`].
+//!
+//! The weighted mean doesn't depend on the meaning of the weights, but the
+//! bias correction applied to weighted variances does. Following
+//! [`StatsBase.jl`], the weighted variance is computed as
+//!
+//! ```text
+//!              n
+//!              ∑ wᵢ(xᵢ-x̅)²
+//!             i=1
+//! σ² = ―――――――――――――――――――――――
+//!        n
+//!        ∑ wᵢ  -  ddof * c(w)
+//!       i=1
+//! ```
+//!
+//! where `ddof` is `0` for the uncorrected (population) variance and `1` for
+//! the corrected (sample) variance, and `c(w)` is the correction term of the
+//! weight type:
+//!
+//! * plain arrays and [`FrequencyWeights`]: `c(w) = 1`;
+//! * [`AnalyticWeights`]: `c(w) = ∑ wᵢ² / ∑ wᵢ`;
+//! * [`ProbabilityWeights`]: `c(w) = ∑ wᵢ / n`, where `n` is the number of weights.
+//!
+//! [`SummaryStatisticsExt`]: ../trait.SummaryStatisticsExt.html
+//! [`StatsBase.jl`]: https://juliastats.org/StatsBase.jl/stable/weights/
+//! [`FrequencyWeights`]: struct.FrequencyWeights.html
+//! [`AnalyticWeights`]: struct.AnalyticWeights.html
+//! [`ProbabilityWeights`]: struct.ProbabilityWeights.html
+use ndarray::{ArrayBase, ArrayView, Data, Dimension};
+use num_traits::{Float, FromPrimitive};
+
+/// Weights accepted by the weighted methods of [`SummaryStatisticsExt`].
+///
+/// This trait is implemented by plain arrays, which behave like
+/// [`FrequencyWeights`], and by the weight types of this module.
+///
+/// [`SummaryStatisticsExt`]: ../trait.SummaryStatisticsExt.html
+/// [`FrequencyWeights`]: struct.FrequencyWeights.html
+pub trait Weights<A, D>
+where
+    D: Dimension,
+{
+    /// Returns a view of the weight values.
+    fn values(&self) -> ArrayView<'_, A, D>;
+
+    /// Returns the correction term `c(w)` that, multiplied by `ddof`, is
+    /// subtracted from the sum of the weights when normalizing a weighted
+    /// variance (see the [module documentation](index.html)).
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of weights.
+    fn correction(&self) -> A
+    where
+        A: Float + FromPrimitive;
+
+    private_decl! {}
+}
+
+impl<A, S, D> Weights<A, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn values(&self) -> ArrayView<'_, A, D> {
+        self.view()
+    }
+
+    fn correction(&self) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        A::one()
+    }
+
+    private_impl! {}
+}
+
+macro_rules! weights_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        pub struct $name<S, D>
+        where
+            S: Data,
+            D: Dimension,
+        {
+            weights: ArrayBase<S, D>,
+        }
+
+        impl<S, D> $name<S, D>
+        where
+            S: Data,
+            D: Dimension,
+        {
+            /// Wraps `weights`.
+            pub fn new(weights: ArrayBase<S, D>) -> Self {
+                $name { weights }
+            }
+
+            /// Returns the wrapped weights.
+            pub fn into_inner(self) -> ArrayBase<S, D> {
+                self.weights
+            }
+        }
+    };
+}
+
+weights_type! {
+    /// Frequency weights: each weight is the number of times the
+    /// corresponding observation was seen.
+    ///
+    /// The corrected variance is the one of the expanded data set, where
+    /// each observation is repeated as many times as its weight:
+    /// `c(w) = 1`.
+    FrequencyWeights
+}
+
+weights_type! {
+    /// Analytic weights: each weight is proportional to the inverse of the
+    /// variance of the corresponding observation (e.g. each observation
+    /// is the mean of a group of measurements, weighted by the size of the
+    /// group).
+    ///
+    /// The corrected variance uses the effective number of observations:
+    /// `c(w) = ∑ wᵢ² / ∑ wᵢ`.
+    AnalyticWeights
+}
+
+weights_type! {
+    /// Probability (sampling) weights: each weight is the inverse of the
+    /// probability that the corresponding observation was included in the
+    /// sample, as in survey data.
+    ///
+    /// The corrected variance is `n / (n - 1)` times the uncorrected one,
+    /// where `n` is the number of weights: `c(w) = ∑ wᵢ / n`.
+    ProbabilityWeights
+}
+
+impl<A, S, D> Weights<A, D> for FrequencyWeights<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn values(&self) -> ArrayView<'_, A, D> {
+        self.weights.view()
+    }
+
+    fn correction(&self) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        A::one()
+    }
+
+    private_impl! {}
+}
+
+impl<A, S, D> Weights<A, D> for AnalyticWeights<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn values(&self) -> ArrayView<'_, A, D> {
+        self.weights.view()
+    }
+
+    fn correction(&self) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        let (sum, sum_sq) = self
+            .weights
+            .fold((A::zero(), A::zero()), |(sum, sum_sq), &w| {
+                (sum + w, sum_sq + w * w)
+            });
+        sum_sq / sum
+    }
+
+    private_impl! {}
+}
+
+impl<A, S, D> Weights<A, D> for ProbabilityWeights<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn values(&self) -> ArrayView<'_, A, D> {
+        self.weights.view()
+    }
+
+    fn correction(&self) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        let n = A::from_usize(self.weights.len())
+            .expect("Converting number of weights to `A` must not fail.");
+        self.weights.sum() / n
+    }
+
+    private_impl! {}
+}
@@ -0,0 +1,206 @@
+//! Summation strategies.
+//!
+//! The methods of [`SummaryStatisticsExt`] and [`DeviationExt`] accumulate
+//! sums sequentially, whose rounding error grows linearly with the number of
+//! elements. This is fast, but it can cause a visible drift for very large
+//! arrays of low-precision floats (e.g. `f32`). The methods whose name ends
+//! with `_with` take one of the strategies of this module to opt into a more
+//! accurate summation:
+//!
+//! * [`Naive`]: sequential summation, the one used by the methods without
+//!   the `_with` suffix;
+//! * [`Pairwise`]: pairwise (cascade) summation, whose error grows with the
+//!   logarithm of the number of elements, at a small cost;
+//! * [`Kahan`]: second-order compensated (Kahan–Babuška–Klein) summation,
+//!   whose error doesn't depend on the number of elements, at the cost of
+//!   about seven times as many floating point operations.
+//!
+//! [`SummaryStatisticsExt`]: ../trait.SummaryStatisticsExt.html
+//! [`DeviationExt`]: ../trait.DeviationExt.html
+//! [`Naive`]: struct.Naive.html
+//! [`Pairwise`]: struct.Pairwise.html
+//! [`Kahan`]: struct.Kahan.html
+use ndarray::{ArrayBase, Data, Dimension};
+use num_traits::{Float, Zero};
+use std::ops::Add;
+
+/// Used to provide a summation strategy to the methods whose name ends with `_with`
+/// (e.g. [`mean_with`]).
+///
+/// [`mean_with`]: ../trait.SummaryStatisticsExt.html#tymethod.mean_with
+pub trait Summation<A> {
+    /// Returns the sum of `values`.
+    #[doc(hidden)]
+    fn sum<I>(values: I) -> A
+    where
+        I: IntoIterator<Item = A>;
+
+    /// Returns the sum of the elements of `arr`.
+    #[doc(hidden)]
+    fn sum_array<S, D>(arr: &ArrayBase<S, D>) -> A
+    where
+        A: Clone,
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        Self::sum(arr.iter().cloned())
+    }
+
+    private_decl! {}
+}
+
+/// Sum the values sequentially.
+pub struct Naive;
+/// Sum the values pairwise, in blocks of 128 values.
+pub struct Pairwise;
+/// Sum the values with Klein's second-order Kahan–Babuška summation, i.e.
+/// compensating the rounding errors of both the sum and its compensation.
+///
+/// Once the running sum is no longer finite (because of an infinite or NaN
+/// value, or an overflow), it is returned as is, since the compensations
+/// would be NaN.
+pub struct Kahan;
+
+impl<A> Summation<A> for Naive
+where
+    A: Clone + Add<Output = A> + Zero,
+{
+    fn sum<I>(values: I) -> A
+    where
+        I: IntoIterator<Item = A>,
+    {
+        values.into_iter().fold(A::zero(), |acc, x| acc + x)
+    }
+
+    fn sum_array<S, D>(arr: &ArrayBase<S, D>) -> A
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        arr.sum()
+    }
+
+    private_impl! {}
+}
+
+/// The number of values summed sequentially by `Pairwise` before they are
+/// combined pairwise.
+const PAIRWISE_BLOCK_SIZE: usize = 128;
+
+impl<A> Summation<A> for Pairwise
+where
+    A: Add<Output = A> + Zero,
+{
+    fn sum<I>(values: I) -> A
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut values = values.into_iter();
+        // Partial sums of 2ᵏ blocks, for strictly decreasing k: merging them like
+        // the digits of a binary counter sums the blocks along a balanced tree.
+        let mut partial_sums: Vec<(u32, A)> = Vec::new();
+        loop {
+            let mut block_len = 0;
+            let mut sum = A::zero();
+            for x in values.by_ref().take(PAIRWISE_BLOCK_SIZE) {
+                sum = sum + x;
+                block_len += 1;
+            }
+            if block_len == 0 {
+                break;
+            }
+            let mut level = 0;
+            while let Some(&(partial_level, _)) = partial_sums.last() {
+                if partial_level != level {
+                    break;
+                }
+                let (_, partial_sum) = partial_sums.pop().unwrap();
+                sum = partial_sum + sum;
+                level += 1;
+            }
+            partial_sums.push((level, sum));
+            if block_len < PAIRWISE_BLOCK_SIZE {
+                break;
+            }
+        }
+        partial_sums
+            .into_iter()
+            .rev()
+            .fold(A::zero(), |acc, (_, partial_sum)| partial_sum + acc)
+    }
+
+    private_impl! {}
+}
+
+impl<A> Summation<A> for Kahan
+where
+    A: Float,
+{
+    fn sum<I>(values: I) -> A
+    where
+        I: IntoIterator<Item = A>,
+    {
+        // Neumaier's compensation is itself compensated (second-order
+        // Kahan–Babuška summation, Klein 2006): otherwise the rounding errors
+        // of the compensation grow with the number of values.
+        let (sum, compensation, second_compensation) = values.into_iter().fold(
+            (A::zero(), A::zero(), A::zero()),
+            |(sum, compensation, second_compensation), x| {
+                let (sum, lost) = two_sum(sum, x);
+                let (compensation, lost) = two_sum(compensation, lost);
+                (sum, compensation, second_compensation + lost)
+            },
+        );
+        if sum.is_finite() {
+            sum + (compensation + second_compensation)
+        } else {
+            sum
+        }
+    }
+
+    private_impl! {}
+}
+
+/// Returns `a + b` and the rounding error of the addition (Neumaier's branch
+/// of the Fast2Sum algorithm).
+pub(crate) fn two_sum<A>(a: A, b: A) -> (A, A)
+where
+    A: Float,
+{
+    let sum = a + b;
+    let lost = if a.abs() >= b.abs() {
+        (a - sum) + b
+    } else {
+        (b - sum) + a
+    };
+    (sum, lost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairwise_matches_naive_for_integers() {
+        for &n in &[0, 1, 127, 128, 129, 1000, 4096, 5000] {
+            let expected: i64 = (0..n).sum();
+            assert_eq!(<Pairwise as Summation<i64>>::sum(0..n), expected);
+        }
+    }
+
+    #[test]
+    fn test_kahan_recovers_cancelled_terms() {
+        let values = vec![1., 1e100, 1., -1e100];
+        assert_eq!(<Naive as Summation<f64>>::sum(values.clone()), 0.);
+        assert_eq!(<Kahan as Summation<f64>>::sum(values), 2.);
+    }
+
+    #[test]
+    fn test_kahan_with_infinite_values() {
+        let sum = <Kahan as Summation<f64>>::sum;
+        assert_eq!(sum(vec![1., f64::INFINITY, 2.]), f64::INFINITY);
+        assert_eq!(sum(vec![f64::NEG_INFINITY, 1e100, 1.]), f64::NEG_INFINITY);
+        assert!(sum(vec![f64::INFINITY, 1., f64::NEG_INFINITY]).is_nan());
+        assert_eq!(sum(vec![f64::MAX, f64::MAX]), f64::INFINITY);
+    }
+}
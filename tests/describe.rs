@@ -0,0 +1,119 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, Axis};
+use ndarray_rand::rand_distr::Normal;
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    errors::EmptyInput, interpolate::Linear, DescribeExt, QuantileExt, SummaryStatisticsExt,
+};
+use noisy_float::types::{n64, N64};
+
+#[test]
+fn test_describe_with_empty_array() {
+    let a: Array1<f64> = array![];
+    assert_eq!(a.describe(), Err(EmptyInput));
+    let a: Array2<f64> = Array2::zeros((3, 0));
+    assert_eq!(a.describe_axis(Axis(1)), Err(EmptyInput));
+}
+
+#[test]
+fn test_describe_eq_individual_statistics() {
+    let a: Array1<f64> = Array1::random(301, Normal::new(1., 2.).unwrap());
+    let description = a.describe().unwrap();
+
+    assert_eq!(description.count, 301);
+    assert_eq!(description.nan_count, 0);
+    assert_abs_diff_eq!(description.mean, a.mean().unwrap(), epsilon = 1e-12);
+    assert_abs_diff_eq!(description.std, a.std(1.), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        description.skewness,
+        SummaryStatisticsExt::skewness(&a).unwrap(),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        description.kurtosis,
+        SummaryStatisticsExt::kurtosis(&a).unwrap(),
+        epsilon = 1e-12
+    );
+
+    let mut a: Array1<N64> = a.mapv(n64);
+    let quantiles = a
+        .quantiles_axis_mut(
+            Axis(0),
+            &array![n64(0.), n64(0.25), n64(0.5), n64(0.75), n64(1.)],
+            &Linear,
+        )
+        .unwrap();
+    assert_eq!(description.min, quantiles[0].raw());
+    assert_eq!(description.lower_quartile, quantiles[1].raw());
+    assert_eq!(description.median, quantiles[2].raw());
+    assert_eq!(description.upper_quartile, quantiles[3].raw());
+    assert_eq!(description.max, quantiles[4].raw());
+}
+
+#[test]
+fn test_describe_skips_nan_values() {
+    let a = array![f64::NAN, 5., 1., f64::NAN, 3.];
+    let description = a.describe().unwrap();
+    assert_eq!(description.count, 3);
+    assert_eq!(description.nan_count, 2);
+    assert_eq!(description.mean, 3.);
+    assert_abs_diff_eq!(description.std, 2., epsilon = 1e-12);
+    assert_eq!(description.min, 1.);
+    assert_eq!(description.lower_quartile, 2.);
+    assert_eq!(description.median, 3.);
+    assert_eq!(description.upper_quartile, 4.);
+    assert_eq!(description.max, 5.);
+    assert_abs_diff_eq!(description.skewness, 0., epsilon = 1e-12);
+    assert_abs_diff_eq!(description.kurtosis, 1.5, epsilon = 1e-12);
+
+    let description = array![f64::NAN, f64::NAN].describe().unwrap();
+    assert_eq!(description.count, 0);
+    assert_eq!(description.nan_count, 2);
+    assert!(description.mean.is_nan());
+    assert!(description.median.is_nan());
+    assert!(description.kurtosis.is_nan());
+
+    let description = array![2f32].describe().unwrap();
+    assert_eq!(description.count, 1);
+    assert_eq!(description.mean, 2.);
+    assert_eq!(description.max, 2.);
+    assert!(description.std.is_nan());
+}
+
+#[test]
+fn test_describe_axis_eq_lane_describe() {
+    let mut a: Array2<f64> = Array2::random((7, 4), Normal::new(0., 1.).unwrap());
+    a[(2, 1)] = f64::NAN;
+    a[(5, 3)] = f64::NAN;
+    for axis in 0..2 {
+        let descriptions = a.describe_axis(Axis(axis)).unwrap();
+        assert_eq!(descriptions.len(), a.len_of(Axis(1 - axis)));
+        for (description, lane) in descriptions.iter().zip(a.lanes(Axis(axis))) {
+            let expected = lane.describe().unwrap();
+            assert_eq!(description.count, expected.count);
+            assert_eq!(description.nan_count, expected.nan_count);
+            assert_abs_diff_eq!(description.mean, expected.mean, epsilon = 1e-12);
+            assert_abs_diff_eq!(description.std, expected.std, epsilon = 1e-12);
+            assert_eq!(description.median, expected.median);
+        }
+    }
+}
+
+#[test]
+fn test_describe_display() {
+    let description = array![1., 2., 3., 4., f64::NAN].describe().unwrap();
+    assert_eq!(
+        format!("{:.2}", description),
+        "count         4\n\
+         NaN count     1\n\
+         mean       2.50\n\
+         std        1.29\n\
+         min        1.00\n\
+         25%        1.75\n\
+         50%        2.50\n\
+         75%        3.25\n\
+         max        4.00\n\
+         skewness   0.00\n\
+         kurtosis   1.64"
+    );
+}
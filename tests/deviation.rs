@@ -1,4 +1,5 @@
 use ndarray_stats::errors::{MultiInputError, ShapeMismatch};
+use ndarray_stats::summation::{Kahan, Naive, Pairwise};
 use ndarray_stats::DeviationExt;
 
 use approx::assert_abs_diff_eq;
@@ -48,6 +49,39 @@ fn test_sq_l2_dist() -> Result<(), MultiInputError> {
     Ok(())
 }
 
+#[test]
+fn test_sq_l2_dist_with() -> Result<(), MultiInputError> {
+    let a = array![0., 1., 4., 2.];
+    let b = array![1., 1., 2., 4.];
+
+    assert_eq!(a.sq_l2_dist_with(&b, &Naive)?, 9.);
+    assert_eq!(a.sq_l2_dist_with(&b, &Pairwise)?, 9.);
+    assert_eq!(a.sq_l2_dist_with(&b, &Kahan)?, 9.);
+    let c = array![0., f64::INFINITY, 4., 2.];
+    assert_eq!(c.sq_l2_dist_with(&b, &Kahan)?, f64::INFINITY);
+
+    let a = Array1::<f32>::from_elem(1_000_000, 0.1);
+    let b = Array1::<f32>::zeros(1_000_000);
+    let expected = 1_000_000. * f64::from(0.1f32 * 0.1f32);
+    assert_abs_diff_eq!(
+        f64::from(a.sq_l2_dist_with(&b, &Pairwise)?),
+        expected,
+        epsilon = 1e-6 * expected
+    );
+    assert_abs_diff_eq!(
+        f64::from(a.sq_l2_dist_with(&b, &Kahan)?),
+        expected,
+        epsilon = 1e-7 * expected
+    );
+    assert_eq!(
+        a.slice(ndarray::s![..0])
+            .sq_l2_dist_with(&b.slice(ndarray::s![..0]), &Kahan),
+        Err(MultiInputError::EmptyInput)
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_l2_dist() -> Result<(), MultiInputError> {
     let a = array![0., 1., 4., 2.];
@@ -268,3 +302,35 @@ fn test_deviation_computation_for_mixed_ownership() {
     let _ = a.root_mean_sq_err(&b.view());
     let _ = a.peak_signal_to_noise_ratio(&b.view(), 10.);
 }
+
+#[test]
+fn test_masked_deviations() -> Result<(), MultiInputError> {
+    let a = array![[1., 2., -999.], [4., -999., 3.]];
+    let b = array![[2., 4., 3.], [1., 0., 1.]];
+    let mask = a.mapv(|x| x == -999.);
+
+    assert_eq!(a.masked_l1_dist(&b, &mask)?, 8.);
+    assert_eq!(a.masked_sq_l2_dist(&b, &mask)?, 18.);
+    assert_abs_diff_eq!(a.masked_mean_abs_err(&b, &mask)?, 2.);
+    assert_abs_diff_eq!(a.masked_mean_sq_err(&b, &mask)?, 4.5);
+    assert_abs_diff_eq!(a.masked_root_mean_sq_err(&b, &mask)?, 4.5f64.sqrt());
+
+    // Without masked indices, the deviations are the unmasked ones
+    let no_mask = mask.mapv(|_| false);
+    assert_eq!(b.masked_l1_dist(&a, &no_mask)?, b.l1_dist(&a)?);
+    assert_eq!(b.masked_mean_sq_err(&a, &no_mask)?, b.mean_sq_err(&a)?);
+
+    // With all the indices masked, the means are undefined
+    let all_masked = mask.mapv(|_| true);
+    assert_eq!(a.masked_l1_dist(&b, &all_masked)?, 0.);
+    assert!(a.masked_mean_abs_err(&b, &all_masked)?.is_nan());
+
+    assert_eq!(
+        a.masked_l1_dist(&b, &array![[true, false]]),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2, 3],
+            second_shape: vec![1, 2],
+        }))
+    );
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, ArrayView1, Axis};
+use ndarray_rand::rand_distr::Normal;
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    errors::{EmptyInput, MultiInputError, ShapeMismatch},
+    CorrelationExt, Ewm, EwmExt,
+};
+use noisy_float::types::{n64, N64};
+use quickcheck_macros::quickcheck;
+
+/// The exponentially weighted mean, biased variance and sum of the squared
+/// normalized weights of the observations up to `t`, with the weights of the
+/// adjusted mode.
+fn naive_adjusted(x: ArrayView1<'_, f64>, t: usize, alpha: f64) -> (f64, f64, f64) {
+    let observations: Vec<(f64, f64)> = (0..=t)
+        .filter(|&i| !x[i].is_nan())
+        .map(|i| ((1. - alpha).powi((t - i) as i32), x[i]))
+        .collect();
+    let sum_weights: f64 = observations.iter().map(|(w, _)| w).sum();
+    let mean = observations.iter().map(|(w, x)| w * x).sum::<f64>() / sum_weights;
+    let var = observations
+        .iter()
+        .map(|(w, x)| w * (x - mean).powi(2))
+        .sum::<f64>()
+        / sum_weights;
+    let sum_sq_weights = observations
+        .iter()
+        .map(|(w, _)| (w / sum_weights).powi(2))
+        .sum::<f64>();
+    (mean, var, sum_sq_weights)
+}
+
+fn assert_eq_with_nan(actual: &Array1<f64>, expected: &Array1<f64>) {
+    assert_eq!(actual.len(), expected.len());
+    for (&x, &y) in actual.iter().zip(expected) {
+        if y.is_nan() {
+            assert!(x.is_nan(), "{}\n{}", actual, expected);
+        } else {
+            assert_abs_diff_eq!(x, y, epsilon = 1e-9 * (1. + y.abs()));
+        }
+    }
+}
+
+#[test]
+fn test_ewm_parameters() {
+    assert_abs_diff_eq!(Ewm::with_span(9.).alpha(), 0.2);
+    assert_abs_diff_eq!(Ewm::with_center_of_mass(4.).alpha(), 0.2);
+    assert_abs_diff_eq!(Ewm::with_halflife(2.).alpha(), 1. - 0.5f64.sqrt());
+    assert_eq!(Ewm::with_alpha(1.).alpha(), 1.);
+}
+
+#[test]
+#[should_panic]
+fn test_ewm_with_invalid_alpha() {
+    Ewm::with_alpha(0.);
+}
+
+#[test]
+fn test_ewm_with_empty_array() {
+    let a: Array1<f64> = array![];
+    let ewm = Ewm::with_alpha(0.3);
+    assert_eq!(a.ewm_mean(Axis(0), &ewm), a);
+    assert_eq!(a.ewm_var(Axis(0), &ewm, false), a);
+    let m: Array2<f64> = Array2::zeros((2, 0));
+    assert_eq!(m.ewm_cov_matrix(&ewm, false), Err(EmptyInput));
+    assert_eq!(m.ewm_pearson_correlation(&ewm), Err(EmptyInput));
+}
+
+#[test]
+fn test_ewm_not_adjusted() {
+    let a = array![1., 3., 2., 6.];
+    let ewm = Ewm::with_alpha(0.25).adjust(false);
+    let expected = array![1., 1.5, 1.625, 2.71875];
+    assert_abs_diff_eq!(a.ewm_mean(Axis(0), &ewm), expected, epsilon = 1e-12);
+
+    // The weights are α(1 - α)ᵗ⁻ⁱ, except (1 - α)ᵗ for the first observation
+    let weights = array![0.421875, 0.140625, 0.1875, 0.25];
+    let mean = expected[3];
+    let var = weights
+        .iter()
+        .zip(&a)
+        .map(|(w, x)| w * (x - mean) * (x - mean))
+        .sum::<f64>();
+    let sum_sq_weights = weights.mapv(|w| w * w).sum();
+    let vars = a.ewm_var(Axis(0), &ewm, true);
+    assert_abs_diff_eq!(vars[3], var, epsilon = 1e-12);
+    let vars = a.ewm_var(Axis(0), &ewm, false);
+    assert_abs_diff_eq!(vars[3], var / (1. - sum_sq_weights), epsilon = 1e-12);
+}
+
+#[test]
+fn test_ewm_ignore_nan_eq_compacted_series() {
+    let a = array![f64::NAN, 1., 3., f64::NAN, f64::NAN, 2., 6., f64::NAN];
+    let compacted = array![1., 3., 2., 6.];
+    for &adjust in &[false, true] {
+        let ewm = Ewm::with_alpha(0.4).adjust(adjust).ignore_nan(true);
+        let means = a.ewm_mean(Axis(0), &ewm);
+        let compacted_means = compacted.ewm_mean(Axis(0), &ewm);
+        let vars = a.ewm_var(Axis(0), &ewm, false);
+        let compacted_vars = compacted.ewm_var(Axis(0), &ewm, false);
+        for (i, &j) in [1, 2, 2, 2, 3, 4, 4].iter().enumerate() {
+            if j > 0 {
+                assert_eq!(means[i + 1], compacted_means[j - 1]);
+                assert_eq!(vars[i + 1].to_bits(), compacted_vars[j - 1].to_bits());
+            }
+        }
+        assert!(means[0].is_nan());
+    }
+}
+
+#[test]
+fn test_ewm_min_periods() {
+    let a = array![1., f64::NAN, 3., 2.];
+    let ewm = Ewm::with_alpha(0.5).min_periods(2);
+    let means = a.ewm_mean(Axis(0), &ewm);
+    assert!(means[0].is_nan() && means[1].is_nan());
+    assert!(!means[2].is_nan() && !means[3].is_nan());
+}
+
+#[test]
+fn test_ewm_with_options_eq_with_nan() {
+    let a = array![1., f64::NAN, 3., 2., f64::NAN, 6.];
+    let b: Array1<Option<N64>> = a.mapv(|x| if x.is_nan() { None } else { Some(n64(x)) });
+    let to_f64 = |b: Array1<Option<N64>>| b.mapv(|x| x.map_or(f64::NAN, N64::raw));
+    for &ignore_nan in &[false, true] {
+        let ewm = Ewm::with_alpha(0.4).ignore_nan(ignore_nan).min_periods(2);
+        assert_eq_with_nan(
+            &to_f64(b.ewm_mean(Axis(0), &ewm)),
+            &a.ewm_mean(Axis(0), &ewm),
+        );
+        assert_eq_with_nan(
+            &to_f64(b.ewm_std(Axis(0), &ewm, false)),
+            &a.ewm_std(Axis(0), &ewm, false),
+        );
+        assert_eq_with_nan(
+            &to_f64(
+                b.ewm_corr(&b.mapv(|x| x.map(|x| -x)), Axis(0), &ewm)
+                    .unwrap(),
+            ),
+            &a.ewm_corr(&-&a, Axis(0), &ewm).unwrap(),
+        );
+    }
+    assert_eq!(b.ewm_var(Axis(0), &Ewm::with_alpha(0.4), false)[0], None);
+}
+
+#[test]
+fn test_ewm_cov_and_corr() {
+    let x = array![1., 2., f64::NAN, 4., 3.];
+    let y = array![2., 1., 5., f64::NAN, 0.];
+    let ewm = Ewm::with_alpha(0.5);
+    assert_eq!(
+        x.ewm_cov(&y.slice(ndarray::s![..4]), Axis(0), &ewm, true),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![5],
+            second_shape: vec![4],
+        }))
+    );
+
+    // Only the pairs (1, 2), (2, 1) and (3, 0) are observations
+    let covs = x.ewm_cov(&y, Axis(0), &ewm, true).unwrap();
+    let (w0, w1, w4) = (0.0625, 0.125, 1.);
+    let sum_weights = w0 + w1 + w4;
+    let mean_x = (w0 * 1. + w1 * 2. + w4 * 3.) / sum_weights;
+    let mean_y = (w0 * 2. + w1 * 1. + w4 * 0.) / sum_weights;
+    let cov = (w0 * (1. - mean_x) * (2. - mean_y)
+        + w1 * (2. - mean_x) * (1. - mean_y)
+        + w4 * (3. - mean_x) * (0. - mean_y))
+        / sum_weights;
+    assert_abs_diff_eq!(covs[4], cov, epsilon = 1e-12);
+    assert_eq!(covs[0], 0.);
+
+    let corrs = x.ewm_corr(&y, Axis(0), &ewm).unwrap();
+    let var_x =
+        (w0 * (1. - mean_x).powi(2) + w1 * (2. - mean_x).powi(2) + w4 * (3. - mean_x).powi(2))
+            / sum_weights;
+    let var_y =
+        (w0 * (2. - mean_y).powi(2) + w1 * (1. - mean_y).powi(2) + w4 * (0. - mean_y).powi(2))
+            / sum_weights;
+    assert_abs_diff_eq!(corrs[4], cov / (var_x * var_y).sqrt(), epsilon = 1e-12);
+    assert!(corrs[0].is_nan());
+    assert_abs_diff_eq!(corrs[1], -1., epsilon = 1e-12);
+    assert_eq!(corrs[2], corrs[1]);
+}
+
+#[test]
+fn test_ewm_cov_matrix_eq_last_ewm_cov() {
+    let mut a: Array2<f64> = Array2::random((3, 20), Normal::new(0., 1.).unwrap());
+    a[(1, 4)] = f64::NAN;
+    a[(2, 19)] = f64::NAN;
+    let ewm = Ewm::with_span(5.).min_periods(3);
+    let covariance = a.ewm_cov_matrix(&ewm, false).unwrap();
+    let correlation = a.ewm_pearson_correlation(&ewm).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            let cov = a.row(i).ewm_cov(&a.row(j), Axis(0), &ewm, false).unwrap();
+            let corr = a.row(i).ewm_corr(&a.row(j), Axis(0), &ewm).unwrap();
+            assert_abs_diff_eq!(covariance[(i, j)], cov[19], epsilon = 1e-12);
+            assert_abs_diff_eq!(correlation[(i, j)], corr[19], epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(correlation[(i, i)], 1., epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_ewm_axis() {
+    let a: Array2<f64> = Array2::random((4, 6), Normal::new(0., 1.).unwrap());
+    let ewm = Ewm::with_alpha(0.3);
+    let stds = a.ewm_std(Axis(1), &ewm, false);
+    for (i, row) in a.outer_iter().enumerate() {
+        assert_eq_with_nan(&stds.row(i).to_owned(), &row.ewm_std(Axis(0), &ewm, false));
+    }
+}
+
+#[quickcheck]
+fn ewm_adjusted_eq_naive(values: Vec<Option<i8>>, alpha: u8) -> bool {
+    let a: Array1<f64> = values
+        .into_iter()
+        .map(|x| x.map_or(f64::NAN, f64::from))
+        .collect();
+    let alpha = (f64::from(alpha) + 1.) / 256.;
+    let ewm = Ewm::with_alpha(alpha);
+    let means = a.ewm_mean(Axis(0), &ewm);
+    let biased_vars = a.ewm_var(Axis(0), &ewm, true);
+    let vars = a.ewm_var(Axis(0), &ewm, false);
+    let expected = |f: &dyn Fn(f64, f64, f64) -> f64| {
+        Array1::from_shape_fn(a.len(), |t| {
+            if a.slice(ndarray::s![..=t]).iter().all(|x| x.is_nan()) {
+                f64::NAN
+            } else {
+                let (mean, var, sum_sq_weights) = naive_adjusted(a.view(), t, alpha);
+                f(mean, var, sum_sq_weights)
+            }
+        })
+    };
+    assert_eq_with_nan(&means, &expected(&|mean, _, _| mean));
+    assert_eq_with_nan(&biased_vars, &expected(&|_, var, _| var));
+    for (&actual, expected) in vars.iter().zip(&expected(&|_, var, sum_sq_weights| {
+        var / (1. - sum_sq_weights)
+    })) {
+        // The bias correction is ill-conditioned for a single observation
+        if expected.is_finite() && expected.abs() < 1e12 {
+            assert_abs_diff_eq!(actual, expected, epsilon = 1e-6 * (1. + expected.abs()));
+        }
+    }
+    true
+}
@@ -0,0 +1,224 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array, Array1, Array2, Axis};
+use ndarray_stats::{
+    errors::{EmptyInput, MultiInputError, ShapeMismatch},
+    EntropyExt, FrequencyExt,
+};
+use noisy_float::types::n64;
+use quickcheck_macros::quickcheck;
+use std::f64;
+
+#[test]
+fn test_mode_with_empty_array() {
+    let a: Array1<i32> = array![];
+    assert_eq!(a.mode(), Err(EmptyInput));
+    assert_eq!(a.multimode(), Err(EmptyInput));
+    let a: Array1<f64> = array![];
+    assert_eq!(a.mode_skipnan(), Err(EmptyInput));
+    assert_eq!(a.multimode_skipnan(), Err(EmptyInput));
+}
+
+#[test]
+fn test_mode_ties_in_first_seen_order() {
+    let a = array![5, 3, 3, 7, 5, 1];
+    assert_eq!(a.mode(), Ok(&5));
+    assert_eq!(a.multimode(), Ok(vec![&5, &3]));
+
+    let a = array![["b", "a"], ["a", "b"]];
+    assert_eq!(a.mode(), Ok(&"b"));
+    assert_eq!(a.t().mode(), Ok(&"b"));
+    assert_eq!(a.t().multimode(), Ok(vec![&"b", &"a"]));
+
+    let a = array![4, 2, 9];
+    assert_eq!(a.multimode(), Ok(vec![&4, &2, &9]));
+}
+
+#[test]
+fn test_mode_skipnan() {
+    let a = array![f64::NAN, 2., f64::NAN, 1., f64::NAN, 1.];
+    assert_eq!(a.mode_skipnan(), Ok(&1.));
+    assert_eq!(a.multimode_skipnan(), Ok(vec![&1.]));
+
+    let a = array![Some(3), None, None, Some(2), Some(3), Some(2)];
+    assert_eq!(a.mode_skipnan(), Ok(&Some(3)));
+    assert_eq!(a.multimode_skipnan(), Ok(vec![&Some(3), &Some(2)]));
+
+    let a = array![f64::NAN, f64::NAN];
+    assert_eq!(a.mode_skipnan(), Err(EmptyInput));
+    assert_eq!(a.multimode_skipnan(), Err(EmptyInput));
+
+    let a = array![n64(0.5), n64(0.5), n64(1.)];
+    assert_eq!(a.mode(), Ok(&n64(0.5)));
+}
+
+#[test]
+fn test_mode_axis() {
+    let a = array![[1, 2, 2], [3, 3, 1], [1, 4, 5]];
+    assert_eq!(a.mode_axis(Axis(0)), Ok(array![1, 2, 2]));
+    assert_eq!(a.mode_axis(Axis(1)), Ok(array![2, 3, 1]));
+
+    let a = Array2::<i32>::zeros((0, 3));
+    assert_eq!(a.mode_axis(Axis(0)), Err(EmptyInput));
+    assert_eq!(a.mode_axis(Axis(1)), Ok(array![]));
+}
+
+#[test]
+fn test_mode_axis_skipnan() {
+    let a = array![[f64::NAN, 2., 2.], [f64::NAN, f64::NAN, f64::NAN]];
+    let modes = a.mode_axis_skipnan(Axis(1)).unwrap();
+    assert_eq!(modes[0], 2.);
+    assert!(modes[1].is_nan());
+
+    let a = Array2::<f64>::zeros((2, 0));
+    assert_eq!(a.mode_axis_skipnan(Axis(1)), Err(EmptyInput));
+}
+
+#[quickcheck]
+fn mode_is_first_of_multimode(data: Vec<u8>) -> bool {
+    let a = Array1::from(data);
+    match a.multimode() {
+        Ok(modes) => {
+            let count = |x: &u8| a.iter().filter(|&y| y == x).count();
+            let max_count = a.iter().map(count).max().unwrap();
+            a.mode() == Ok(modes[0])
+                && modes.iter().all(|&x| count(x) == max_count)
+                && a.iter().filter(|&x| count(x) == max_count).count() == modes.len() * max_count
+        }
+        Err(EmptyInput) => a.is_empty() && a.mode() == Err(EmptyInput),
+    }
+}
+
+#[test]
+fn test_value_counts_and_proportions() {
+    let a = array![["b", "a", "c"], ["a", "a", "b"]];
+    let counts = a.value_counts();
+    assert_eq!(
+        counts.into_iter().collect::<Vec<_>>(),
+        vec![("b", 2), ("a", 3), ("c", 1)]
+    );
+    let proportions = a.proportions().unwrap();
+    assert_eq!(
+        proportions.into_iter().collect::<Vec<_>>(),
+        vec![("b", 2. / 6.), ("a", 3. / 6.), ("c", 1. / 6.)]
+    );
+
+    let empty: Array1<i32> = array![];
+    assert!(empty.value_counts().is_empty());
+    assert_eq!(empty.proportions(), Err(EmptyInput));
+}
+
+#[test]
+fn test_weighted_value_counts_and_proportions() {
+    let a = array![1, 2, 1, 3];
+    let w = array![0.5, 1., 1.5, 2.];
+    let counts = a.weighted_value_counts(&w).unwrap();
+    assert_eq!(
+        counts.into_iter().collect::<Vec<_>>(),
+        vec![(1, 2.), (2, 1.), (3, 2.)]
+    );
+    let proportions = a.weighted_proportions(&w).unwrap();
+    assert_eq!(
+        proportions.into_iter().collect::<Vec<_>>(),
+        vec![(1, 0.4), (2, 0.2), (3, 0.4)]
+    );
+
+    assert_eq!(
+        a.weighted_value_counts(&array![1, 2]),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![4],
+            second_shape: vec![2],
+        }))
+    );
+    let empty: Array1<i32> = array![];
+    assert_eq!(
+        empty.weighted_proportions(&Array1::<f64>::zeros(0)),
+        Err(MultiInputError::EmptyInput)
+    );
+}
+
+#[test]
+fn test_level_counts() {
+    let a = array![[4, 1, 4], [9, 0, 1]];
+    assert_eq!(a.level_counts(0..6), array![1, 2, 0, 0, 2, 0]);
+    assert_eq!(a.level_counts(vec![9, 4]), array![1, 2]);
+    assert_eq!(
+        a.weighted_level_counts(0..3, &array![[1, 2, 3], [4, 5, 6]])
+            .unwrap(),
+        array![5, 8, 0]
+    );
+    assert_eq!(
+        a.level_counts_axis(Axis(1), 0..5),
+        array![[0, 1, 0, 0, 2], [1, 1, 0, 0, 0]]
+    );
+    assert_eq!(
+        a.level_counts_axis(Axis(0), 0..2),
+        array![[0, 1, 0], [0, 1, 1]]
+    );
+    let a = Array2::<u8>::zeros((0, 2));
+    assert_eq!(a.level_counts_axis(Axis(0), 0..3), Array::zeros((3, 2)));
+}
+
+#[test]
+fn test_level_counts_with_repeated_levels() {
+    let a = array![0, 1, 0, 1];
+    assert_eq!(a.level_counts(vec![0, 1, 0]), array![2, 2]);
+    assert_eq!(
+        a.weighted_level_counts(vec![1, 1, 0], &array![1, 2, 3, 4])
+            .unwrap(),
+        array![6, 4]
+    );
+    let a = array![[0, 1], [0, 0]];
+    assert_eq!(
+        a.level_counts_axis(Axis(1), vec![0, 0, 1]),
+        array![[1, 1], [2, 0]]
+    );
+}
+
+#[test]
+fn test_value_counts_and_proportions_axis() {
+    let a = array![["b", "a", "b"], ["a", "a", "a"]];
+    let counts = a.value_counts_axis(Axis(1));
+    assert_eq!(counts.shape(), &[2]);
+    for (lane, counts) in a.outer_iter().zip(&counts) {
+        assert_eq!(counts, &lane.value_counts());
+    }
+    let proportions = a.proportions_axis(Axis(0)).unwrap();
+    assert_eq!(proportions.shape(), &[3]);
+    for (lane, proportions) in a.columns().into_iter().zip(&proportions) {
+        assert_eq!(proportions, &lane.proportions().unwrap());
+    }
+    assert_eq!(
+        proportions[0].clone().into_iter().collect::<Vec<_>>(),
+        vec![("b", 0.5), ("a", 0.5)]
+    );
+
+    let empty = Array2::<u8>::zeros((0, 2));
+    assert!(empty
+        .value_counts_axis(Axis(0))
+        .iter()
+        .all(|c| c.is_empty()));
+    assert_eq!(empty.proportions_axis(Axis(0)), Err(EmptyInput));
+    assert_eq!(empty.proportions_axis(Axis(1)).unwrap().len(), 0);
+}
+
+#[quickcheck]
+fn level_counts_match_value_counts(data: Vec<u8>) -> bool {
+    let a = Array1::from(data);
+    let counts = a.value_counts();
+    let level_counts = a.level_counts(0..=255);
+    level_counts.sum() == a.len()
+        && level_counts
+            .indexed_iter()
+            .all(|(i, &count)| counts.get(&(i as u8)).cloned().unwrap_or(0) == count)
+}
+
+#[test]
+fn test_empirical_entropy() {
+    let samples = array![[1, 2], [2, 2]];
+    let expected = array![0.25, 0.75].entropy().unwrap();
+    assert_abs_diff_eq!(samples.empirical_entropy().unwrap(), expected);
+    let constant = array!["x", "x", "x"];
+    assert_abs_diff_eq!(constant.empirical_entropy().unwrap(), 0.);
+    let empty: Array1<i32> = array![];
+    assert_eq!(empty.empirical_entropy(), Err(EmptyInput));
+}
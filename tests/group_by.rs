@@ -0,0 +1,141 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, Axis};
+use ndarray_rand::rand_distr::{Normal, Uniform};
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    errors::{QuantileError, ShapeMismatch},
+    interpolate::{Linear, Lower},
+    GroupByExt, QuantileExt,
+};
+use noisy_float::types::n64;
+
+#[test]
+fn test_group_by_with_mismatched_labels() {
+    let a: Array2<f64> = Array2::zeros((3, 2));
+    assert_eq!(
+        a.group_by(Axis(1), &array![0, 1, 0]).unwrap_err(),
+        ShapeMismatch {
+            first_shape: vec![2],
+            second_shape: vec![3],
+        }
+    );
+}
+
+#[test]
+fn test_group_by_with_empty_axis() {
+    let a: Array2<f64> = Array2::zeros((0, 2));
+    let labels: Array1<u8> = array![];
+    let groups = a.group_by(Axis(0), &labels).unwrap();
+    assert_eq!(groups.n_groups(), 0);
+    assert_eq!(groups.count(), array![]);
+    assert_eq!(groups.mean().shape(), &[0, 2]);
+    assert_eq!(groups.median().shape(), &[0, 2]);
+    assert_eq!(
+        groups
+            .quantiles(&array![n64(0.1), n64(0.9)], &Linear)
+            .unwrap()
+            .shape(),
+        &[2, 0, 2]
+    );
+}
+
+#[test]
+fn test_group_by_eq_partitioned_by_hand() {
+    let a: Array2<f64> = Array2::random((4, 30), Normal::new(0., 1.).unwrap());
+    let labels = Array1::random(30, Uniform::new(0, 5));
+    let groups = a.group_by(Axis(1), &labels).unwrap();
+    assert_eq!(groups.axis(), Axis(1));
+
+    let count = groups.count();
+    let sum = groups.sum();
+    let mean = groups.mean();
+    let var = groups.var(1.);
+    let std = groups.std(0.);
+    let min = groups.min();
+    let max = groups.max();
+    let median = groups.median();
+    let quartile = groups.quantile(n64(0.25), &Lower).unwrap();
+    let qs = array![n64(0.25), n64(0.5), n64(0.9)];
+    let quantiles = groups.quantiles(&qs, &Linear).unwrap();
+    assert_eq!(quantiles.shape(), &[3, 4, groups.n_groups()]);
+    for (&q, quantiles) in qs.iter().zip(quantiles.outer_iter()) {
+        assert_eq!(quantiles, groups.quantile(q, &Linear).unwrap());
+    }
+    assert_eq!(quantiles.index_axis(Axis(0), 1), median);
+    for (g, key) in groups.keys().iter().enumerate() {
+        let indices: Vec<usize> = (0..labels.len()).filter(|&i| labels[i] == *key).collect();
+        let group = a.select(Axis(1), &indices);
+        assert_eq!(count[g], indices.len());
+        assert_abs_diff_eq!(sum.column(g), group.sum_axis(Axis(1)), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            mean.column(g),
+            group.mean_axis(Axis(1)).unwrap(),
+            epsilon = 1e-12
+        );
+        if indices.len() > 1 {
+            assert_abs_diff_eq!(var.column(g), group.var_axis(Axis(1), 1.), epsilon = 1e-12);
+        } else {
+            assert!(var.column(g).iter().all(|x| x.is_nan()));
+        }
+        assert_abs_diff_eq!(std.column(g), group.std_axis(Axis(1), 0.), epsilon = 1e-12);
+
+        let mut group = group.mapv(n64);
+        assert_eq!(
+            min.column(g),
+            group.map_axis(Axis(1), |lane| lane.min().unwrap().raw())
+        );
+        assert_eq!(
+            max.column(g),
+            group.map_axis(Axis(1), |lane| lane.max().unwrap().raw())
+        );
+        assert_eq!(
+            median.column(g),
+            group
+                .quantile_axis_mut(Axis(1), n64(0.5), &Linear)
+                .unwrap()
+                .mapv(|x| x.raw())
+        );
+        assert_eq!(
+            quartile.column(g),
+            group
+                .quantile_axis_mut(Axis(1), n64(0.25), &Lower)
+                .unwrap()
+                .mapv(|x| x.raw())
+        );
+    }
+}
+
+#[test]
+fn test_group_by_propagates_nan() {
+    let a = array![1., f64::NAN, 3., 4.];
+    let groups = a.group_by(Axis(0), &array!['x', 'y', 'x', 'y']).unwrap();
+    assert_eq!(groups.keys(), &['x', 'y']);
+    let quantiles = groups.quantiles(&array![n64(0.5)], &Linear).unwrap();
+    let quantiles = quantiles.index_axis(Axis(0), 0).to_owned();
+    for stat in &[
+        groups.mean(),
+        groups.min(),
+        groups.max(),
+        groups.median(),
+        quantiles,
+    ] {
+        assert!(!stat[0].is_nan());
+        assert!(stat[1].is_nan());
+    }
+    assert_eq!(groups.min()[0], 1.);
+    assert_eq!(groups.max()[0], 3.);
+}
+
+#[test]
+fn test_group_by_invalid_quantile() {
+    let a = array![1., 2.];
+    let groups = a.group_by(Axis(0), &array![0, 0]).unwrap();
+    assert_eq!(
+        groups.quantile(n64(1.5), &Linear),
+        Err(QuantileError::InvalidQuantile(n64(1.5)))
+    );
+    assert_eq!(
+        groups.quantiles(&array![n64(0.5), n64(-0.1)], &Linear),
+        Err(QuantileError::InvalidQuantile(n64(-0.1)))
+    );
+}
@@ -2,7 +2,7 @@ use itertools::izip;
 use ndarray::array;
 use ndarray::prelude::*;
 use ndarray_stats::{
-    errors::{EmptyInput, MinMaxError, QuantileError},
+    errors::{EmptyInput, MaskedQuantileError, MinMaxError, QuantileError, ShapeMismatch},
     interpolate::{Higher, Interpolate, Linear, Lower, Midpoint, Nearest},
     Quantile1dExt, QuantileExt,
 };
@@ -17,7 +17,7 @@ fn test_argmin() {
     let a = array![[1., 5., 3.], [2., 0., 6.]];
     assert_eq!(a.argmin(), Ok((1, 1)));
 
-    let a = array![[1., 5., 3.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[1., 5., 3.], [2., f64::NAN, 6.]];
     assert_eq!(a.argmin(), Err(MinMaxError::UndefinedOrder));
 
     let a: Array2<i32> = array![[], []];
@@ -35,16 +35,16 @@ fn test_argmin_skipnan() {
     let a = array![[1., 5., 3.], [2., 0., 6.]];
     assert_eq!(a.argmin_skipnan(), Ok((1, 1)));
 
-    let a = array![[1., 5., 3.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[1., 5., 3.], [2., f64::NAN, 6.]];
     assert_eq!(a.argmin_skipnan(), Ok((0, 0)));
 
-    let a = array![[::std::f64::NAN, 5., 3.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[f64::NAN, 5., 3.], [2., f64::NAN, 6.]];
     assert_eq!(a.argmin_skipnan(), Ok((1, 0)));
 
     let a: Array2<f64> = array![[], []];
     assert_eq!(a.argmin_skipnan(), Err(EmptyInput));
 
-    let a = arr2(&[[::std::f64::NAN; 2]; 2]);
+    let a = arr2(&[[f64::NAN; 2]; 2]);
     assert_eq!(a.argmin_skipnan(), Err(EmptyInput));
 }
 
@@ -68,7 +68,7 @@ fn test_min() {
     let a = array![[1., 5., 3.], [2., 0., 6.]];
     assert_eq!(a.min(), Ok(&0.));
 
-    let a = array![[1., 5., 3.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[1., 5., 3.], [2., f64::NAN, 6.]];
     assert_eq!(a.min(), Err(MinMaxError::UndefinedOrder));
 }
 
@@ -77,13 +77,13 @@ fn test_min_skipnan() {
     let a = array![[1., 5., 3.], [2., 0., 6.]];
     assert_eq!(a.min_skipnan(), &0.);
 
-    let a = array![[1., 5., 3.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[1., 5., 3.], [2., f64::NAN, 6.]];
     assert_eq!(a.min_skipnan(), &1.);
 }
 
 #[test]
 fn test_min_skipnan_all_nan() {
-    let a = arr2(&[[::std::f64::NAN; 3]; 2]);
+    let a = arr2(&[[f64::NAN; 3]; 2]);
     assert!(a.min_skipnan().is_nan());
 }
 
@@ -95,7 +95,7 @@ fn test_argmax() {
     let a = array![[1., 5., 3.], [2., 0., 6.]];
     assert_eq!(a.argmax(), Ok((1, 2)));
 
-    let a = array![[1., 5., 3.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[1., 5., 3.], [2., f64::NAN, 6.]];
     assert_eq!(a.argmax(), Err(MinMaxError::UndefinedOrder));
 
     let a: Array2<i32> = array![[], []];
@@ -113,19 +113,16 @@ fn test_argmax_skipnan() {
     let a = array![[1., 5., 3.], [2., 0., 6.]];
     assert_eq!(a.argmax_skipnan(), Ok((1, 2)));
 
-    let a = array![[1., 5., 3.], [2., ::std::f64::NAN, ::std::f64::NAN]];
+    let a = array![[1., 5., 3.], [2., f64::NAN, f64::NAN]];
     assert_eq!(a.argmax_skipnan(), Ok((0, 1)));
 
-    let a = array![
-        [::std::f64::NAN, ::std::f64::NAN, 3.],
-        [2., ::std::f64::NAN, 6.]
-    ];
+    let a = array![[f64::NAN, f64::NAN, 3.], [2., f64::NAN, 6.]];
     assert_eq!(a.argmax_skipnan(), Ok((1, 2)));
 
     let a: Array2<f64> = array![[], []];
     assert_eq!(a.argmax_skipnan(), Err(EmptyInput));
 
-    let a = arr2(&[[::std::f64::NAN; 2]; 2]);
+    let a = arr2(&[[f64::NAN; 2]; 2]);
     assert_eq!(a.argmax_skipnan(), Err(EmptyInput));
 }
 
@@ -149,7 +146,7 @@ fn test_max() {
     let a = array![[1., 5., 7.], [2., 0., 6.]];
     assert_eq!(a.max(), Ok(&7.));
 
-    let a = array![[1., 5., 7.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[1., 5., 7.], [2., f64::NAN, 6.]];
     assert_eq!(a.max(), Err(MinMaxError::UndefinedOrder));
 }
 
@@ -158,13 +155,13 @@ fn test_max_skipnan() {
     let a = array![[1., 5., 7.], [2., 0., 6.]];
     assert_eq!(a.max_skipnan(), &7.);
 
-    let a = array![[1., 5., 7.], [2., ::std::f64::NAN, 6.]];
+    let a = array![[1., 5., 7.], [2., f64::NAN, 6.]];
     assert_eq!(a.max_skipnan(), &7.);
 }
 
 #[test]
 fn test_max_skipnan_all_nan() {
-    let a = arr2(&[[::std::f64::NAN; 3]; 2]);
+    let a = arr2(&[[f64::NAN; 3]; 2]);
     assert!(a.max_skipnan().is_nan());
 }
 
@@ -247,7 +244,7 @@ fn test_quantile_axis_skipnan_mut_midpoint_opt_i32() {
 
 #[test]
 fn test_quantile_axis_skipnan_mut_linear_f64() {
-    let mut a = arr2(&[[1., 2., ::std::f64::NAN, 3.], [::std::f64::NAN; 4]]);
+    let mut a = arr2(&[[1., 2., f64::NAN, 3.], [f64::NAN; 4]]);
     let q = a
         .quantile_axis_skipnan_mut(Axis(1), n64(0.75), &Linear)
         .unwrap();
@@ -329,7 +326,7 @@ fn check_one_interpolation_method_for_quantiles_mut(
 ) -> bool {
     let bulk_quantiles = v.clone().quantiles_mut(&quantile_indexes, interpolate);
 
-    if v.len() == 0 {
+    if v.is_empty() {
         bulk_quantiles.is_err()
     } else {
         let bulk_quantiles = bulk_quantiles.unwrap();
@@ -404,7 +401,7 @@ fn check_one_interpolation_method_for_quantiles_axis_mut(
         .clone()
         .quantiles_axis_mut(axis, &quantile_indexes, interpolate);
 
-    if v.len() == 0 {
+    if v.is_empty() {
         bulk_quantiles.is_err()
     } else {
         let bulk_quantiles = bulk_quantiles.unwrap();
@@ -417,3 +414,288 @@ fn check_one_interpolation_method_for_quantiles_axis_mut(
         )
     }
 }
+
+#[test]
+fn test_trimmed_mean_mut() {
+    let mut a = array![n64(1.), n64(-50.), n64(3.), n64(4.), n64(2.), n64(100.)];
+    assert_eq!(a.trimmed_mean_mut(n64(0.)).unwrap(), n64(10.));
+    assert_eq!(a.trimmed_mean_mut(n64(0.2)).unwrap(), n64(2.5));
+    assert_eq!(a.trimmed_mean_mut(n64(0.49)).unwrap(), n64(2.5));
+}
+
+#[test]
+fn test_winsorized_mean_and_var_mut() {
+    let mut a = array![n64(1.), n64(-50.), n64(3.), n64(4.), n64(2.), n64(100.)];
+    assert_eq!(a.winsorized_mean_mut(n64(0.2)).unwrap(), n64(2.5));
+    // Winsorized data: [1., 1., 2., 3., 4., 4.]
+    assert_eq!(
+        a.winsorized_var_mut(n64(0.2), n64(1.)).unwrap(),
+        n64(9.5 / 5.)
+    );
+    let mut sorted = a.to_vec();
+    sorted.sort();
+    let mut expected = vec![n64(-50.), n64(1.), n64(2.), n64(3.), n64(4.), n64(100.)];
+    expected.sort();
+    assert_eq!(sorted, expected);
+}
+
+#[test]
+fn test_trimmed_and_winsorized_invalid_proportion() {
+    let mut a = array![n64(1.), n64(2.)];
+    for &p in &[n64(-0.1), n64(0.5), n64(1.)] {
+        assert_eq!(
+            a.trimmed_mean_mut(p),
+            Err(QuantileError::InvalidProportion(p))
+        );
+        assert_eq!(
+            a.winsorized_mean_mut(p),
+            Err(QuantileError::InvalidProportion(p))
+        );
+        assert_eq!(
+            a.winsorized_var_mut(p, n64(0.)),
+            Err(QuantileError::InvalidProportion(p))
+        );
+    }
+}
+
+#[test]
+fn test_trimmed_and_winsorized_with_zero_axis_length() {
+    let mut a = Array2::<N64>::zeros((5, 0));
+    assert_eq!(
+        a.trimmed_mean_axis_mut(Axis(1), n64(0.1)),
+        Err(QuantileError::EmptyInput)
+    );
+    assert_eq!(
+        a.winsorized_mean_axis_mut(Axis(1), n64(0.1)),
+        Err(QuantileError::EmptyInput)
+    );
+    assert_eq!(
+        a.winsorized_var_axis_mut(Axis(1), n64(0.1), n64(0.)),
+        Err(QuantileError::EmptyInput)
+    );
+    let empty: Array1<N64> = array![];
+    assert_eq!(
+        empty.clone().trimmed_mean_mut(n64(0.1)),
+        Err(QuantileError::EmptyInput)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_winsorized_var_mut_with_invalid_ddof() {
+    let mut a = array![n64(1.), n64(2.)];
+    let _ = a.winsorized_var_mut(n64(0.), n64(3.));
+}
+
+#[quickcheck]
+fn trimmed_and_winsorized_match_sorted(xs: Vec<i32>, proportion: u8) -> bool {
+    let proportion = n64(f64::from(proportion) / 256. * 0.5);
+    let mut a: Array1<N64> = xs.iter().map(|&x| n64(f64::from(x))).collect();
+    if a.is_empty() {
+        return a.trimmed_mean_mut(proportion) == Err(QuantileError::EmptyInput);
+    }
+
+    let mut sorted = xs.iter().map(|&x| f64::from(x)).collect::<Vec<_>>();
+    sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    let n = sorted.len();
+    let g = (proportion.raw() * n as f64).floor() as usize;
+    let trimmed = &sorted[g..n - g];
+    let expected_trimmed = trimmed.iter().sum::<f64>() / trimmed.len() as f64;
+    let winsorized: Vec<f64> = sorted
+        .iter()
+        .map(|&x| x.max(sorted[g]).min(sorted[n - 1 - g]))
+        .collect();
+    let expected_winsorized = winsorized.iter().sum::<f64>() / n as f64;
+    let expected_var = winsorized
+        .iter()
+        .map(|&x| (x - expected_winsorized).powi(2))
+        .sum::<f64>()
+        / n as f64;
+
+    let close = |x: N64, y: f64| (x.raw() - y).abs() <= 1e-6 * (1. + y.abs());
+    close(a.trimmed_mean_mut(proportion).unwrap(), expected_trimmed)
+        && close(
+            a.winsorized_mean_mut(proportion).unwrap(),
+            expected_winsorized,
+        )
+        && close(
+            a.winsorized_var_mut(proportion, n64(0.)).unwrap(),
+            expected_var,
+        )
+}
+
+#[test]
+fn test_trimmed_and_winsorized_axis_mut() {
+    let mut a = array![
+        [n64(1.), n64(-50.), n64(3.), n64(4.), n64(2.), n64(100.)],
+        [n64(6.), n64(5.), n64(4.), n64(3.), n64(2.), n64(1.)]
+    ];
+    assert_eq!(
+        a.trimmed_mean_axis_mut(Axis(1), n64(0.2)).unwrap(),
+        array![n64(2.5), n64(3.5)]
+    );
+    assert_eq!(
+        a.winsorized_mean_axis_mut(Axis(1), n64(0.2)).unwrap(),
+        array![n64(2.5), n64(3.5)]
+    );
+    assert_eq!(
+        a.t()
+            .to_owned()
+            .trimmed_mean_axis_mut(Axis(0), n64(0.2))
+            .unwrap(),
+        array![n64(2.5), n64(3.5)]
+    );
+}
+
+#[test]
+fn test_iqr_and_median_abs_deviation() {
+    let a = array![3, 1, 4, 1, 5, 9, 2, 6];
+    // Sorted: [1, 1, 2, 3, 4, 5, 6, 9]
+    assert_eq!(a.iqr(&Lower), Ok(5 - 1));
+    assert_eq!(a.iqr(&Higher), Ok(6 - 2));
+    // The median is 3.5, the sorted absolute deviations are
+    // [0.5, 0.5, 1.5, 1.5, 2.5, 2.5, 2.5, 5.5]
+    let a = a.mapv(|x| n64(f64::from(x)));
+    assert_eq!(a.median_abs_deviation(n64(1.), &Linear), Ok(n64(2.)));
+    assert_eq!(
+        a.median_abs_deviation(n64(1.4826), &Linear),
+        Ok(n64(2.9652))
+    );
+    // The array is not modified
+    assert_eq!(a[0], n64(3.));
+
+    let empty: Array1<N64> = array![];
+    assert_eq!(empty.iqr(&Linear), Err(EmptyInput));
+    assert_eq!(
+        empty.median_abs_deviation(n64(1.), &Linear),
+        Err(EmptyInput)
+    );
+    assert_eq!(
+        Array2::<i32>::zeros((2, 0)).iqr_axis(Axis(1), &Nearest),
+        Err(EmptyInput)
+    );
+}
+
+#[test]
+fn test_iqr_and_median_abs_deviation_skipnan() {
+    let a: Array2<f64> = array![[1., f64::NAN, 3., 5., 4., 2.], [f64::NAN; 6]];
+    let iqrs = a.iqr_axis_skipnan(Axis(1), &Linear).unwrap();
+    assert_eq!(iqrs[0], 2.);
+    assert!(iqrs[1].is_nan());
+    let mads = a
+        .median_abs_deviation_axis_skipnan(Axis(1), 1., &Linear)
+        .unwrap();
+    assert_eq!(mads[0], 1.);
+    assert!(mads[1].is_nan());
+
+    let b = array![Some(n64(4.)), None, Some(n64(1.)), Some(n64(2.))];
+    assert_eq!(b.iqr_skipnan(&Lower), Ok(Some(n64(1.))));
+    assert_eq!(
+        b.median_abs_deviation_skipnan(Some(n64(2.)), &Lower),
+        Ok(Some(n64(2.)))
+    );
+    let c: Array1<Option<N64>> = array![None, None];
+    assert_eq!(c.iqr_skipnan(&Lower), Ok(None));
+    assert_eq!(
+        Array1::<f64>::zeros(0).iqr_skipnan(&Linear),
+        Err(EmptyInput)
+    );
+}
+
+#[quickcheck]
+fn iqr_and_median_abs_deviation_axis_eq_lanes(xs: Vec<i64>, n_rows: u8) -> bool {
+    let n_rows = usize::from(n_rows % 4) + 1;
+    let n_cols = xs.len() / n_rows;
+    if n_cols == 0 {
+        return true;
+    }
+    let a = Array::from_shape_vec((n_rows, n_cols), xs[..n_rows * n_cols].to_vec())
+        .unwrap()
+        .mapv(|x| n64(x as f64));
+    let iqrs = a.iqr_axis(Axis(1), &Midpoint).unwrap();
+    let mads = a
+        .median_abs_deviation_axis(Axis(1), n64(1.5), &Nearest)
+        .unwrap();
+    izip!(a.outer_iter(), &iqrs, &mads).all(|(row, &iqr, &mad)| {
+        let mut sorted = row.to_vec();
+        sorted.sort();
+        let mut copy = row.to_owned();
+        let q1 = copy.quantile_mut(n64(0.25), &Midpoint).unwrap();
+        let q3 = copy.quantile_mut(n64(0.75), &Midpoint).unwrap();
+        let median = copy.quantile_mut(n64(0.5), &Nearest).unwrap();
+        let mut deviations = row.mapv(|x| n64((x - median).raw().abs()));
+        let expected_mad = deviations.quantile_mut(n64(0.5), &Nearest).unwrap() * n64(1.5);
+        iqr == q3 - q1
+            && mad == expected_mad
+            && row.iqr(&Midpoint) == Ok(iqr)
+            && row.median_abs_deviation(n64(1.5), &Nearest) == Ok(mad)
+    })
+}
+
+#[test]
+fn test_masked_quantile_axis() {
+    let a = array![[3, 7, 1, 2], [8, 9, 5, 4], [6, 0, 1, 2]];
+    let mask = array![
+        [false, true, false, false],
+        [true, true, false, false],
+        [true, true, true, true]
+    ];
+    assert_eq!(
+        a.masked_quantile_axis(Axis(1), &mask, n64(0.5), &Lower),
+        Ok(array![Some(2), Some(4), None])
+    );
+    assert_eq!(
+        a.masked_quantile_axis(Axis(0), &mask, n64(1.), &Linear),
+        Ok(array![Some(3), None, Some(5), Some(4)])
+    );
+    assert_eq!(
+        a.row(0).masked_quantile(&mask.row(0), n64(0.), &Higher),
+        Ok(Some(1))
+    );
+    assert_eq!(
+        a.row(2).masked_quantile(&mask.row(2), n64(0.5), &Higher),
+        Ok(None)
+    );
+}
+
+#[test]
+fn test_masked_quantile_errors() {
+    let a = array![[1, 2], [3, 4]];
+    let mask = a.mapv(|_| false);
+    assert_eq!(
+        a.masked_quantile_axis(Axis(0), &mask, n64(1.5), &Linear),
+        Err(MaskedQuantileError::InvalidQuantile(n64(1.5)))
+    );
+    assert_eq!(
+        a.masked_quantile_axis(Axis(0), &array![[false, false]], n64(0.5), &Linear),
+        Err(MaskedQuantileError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2, 2],
+            second_shape: vec![1, 2],
+        }))
+    );
+    let empty: Array2<i32> = Array2::zeros((0, 2));
+    assert_eq!(
+        empty.masked_quantile_axis(Axis(0), &empty.mapv(|_| false), n64(0.5), &Linear),
+        Err(MaskedQuantileError::EmptyInput)
+    );
+}
+
+#[quickcheck]
+fn masked_quantile_eq_quantile_of_unmasked_elements(values: Vec<(i64, bool)>, q: u8) -> bool {
+    let q = n64(f64::from(q) / 255.);
+    let a: Array1<i64> = values.iter().map(|&(x, _)| x).collect();
+    let mask: Array1<bool> = values.iter().map(|&(_, masked)| masked).collect();
+    let mut unmasked: Array1<i64> = values
+        .iter()
+        .filter(|&&(_, masked)| !masked)
+        .map(|&(x, _)| x)
+        .collect();
+    let masked_quantile = a.masked_quantile(&mask, q, &Midpoint);
+    if a.is_empty() {
+        masked_quantile == Err(MaskedQuantileError::EmptyInput)
+    } else if unmasked.is_empty() {
+        masked_quantile == Ok(None)
+    } else {
+        masked_quantile == Ok(Some(unmasked.quantile_mut(q, &Midpoint).unwrap()))
+    }
+}
@@ -0,0 +1,245 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, ArrayView1, Axis};
+use ndarray_rand::rand_distr::Normal;
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    errors::QuantileError,
+    interpolate::{Higher, Linear, Lower},
+    Quantile1dExt, RollingExt, RollingWindow,
+};
+use noisy_float::types::{n64, N64};
+use quickcheck_macros::quickcheck;
+
+/// Returns the statistic of each window computed from scratch.
+fn naive_rolling<F>(
+    a: ArrayView1<'_, f64>,
+    size: usize,
+    centered: bool,
+    min_periods: usize,
+    skip_nan: bool,
+    statistic: F,
+) -> Array1<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let (before, after) = if centered {
+        (size / 2, (size - 1) / 2)
+    } else {
+        (size - 1, 0)
+    };
+    Array1::from_shape_fn(a.len(), |i| {
+        let start = i.saturating_sub(before);
+        let end = (i + after + 1).min(a.len());
+        let window = a.slice(ndarray::s![start..end]);
+        let values: Vec<f64> = window.iter().cloned().filter(|x| !x.is_nan()).collect();
+        if values.len() < min_periods || (!skip_nan && values.len() < window.len()) {
+            f64::NAN
+        } else {
+            statistic(&values)
+        }
+    })
+}
+
+fn assert_eq_with_nan(actual: &Array1<f64>, expected: &Array1<f64>) {
+    assert_eq!(actual.len(), expected.len());
+    for (&x, &y) in actual.iter().zip(expected) {
+        if y.is_nan() {
+            assert!(x.is_nan(), "{} != {}\n{}\n{}", x, y, actual, expected);
+        } else if y.is_infinite() {
+            assert_eq!(x, y, "\n{}\n{}", actual, expected);
+        } else {
+            assert_abs_diff_eq!(x, y, epsilon = 1e-9 * (1. + y.abs()));
+        }
+    }
+}
+
+fn sample_var(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return f64::NAN;
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+}
+
+fn quantile(values: &[f64], q: f64) -> f64 {
+    let mut values: Array1<N64> = values.iter().cloned().map(n64).collect();
+    values.quantile_mut(n64(q), &Linear).unwrap().raw()
+}
+
+#[test]
+fn test_rolling_with_empty_array() {
+    let a: Array1<f64> = array![];
+    let window = RollingWindow::new(3);
+    assert_eq!(a.rolling_sum(Axis(0), window), a);
+    assert_eq!(a.rolling_median(Axis(0), window), a);
+}
+
+#[test]
+fn test_rolling_trailing_and_centered() {
+    let a = array![1., 5., 2., 8., 3.];
+    let window = RollingWindow::new(3).min_periods(1);
+    assert_eq!(
+        a.rolling_mean(Axis(0), window),
+        array![1., 3., 8. / 3., 5., 13. / 3.]
+    );
+    assert_eq!(a.rolling_max(Axis(0), window), array![1., 5., 5., 8., 8.]);
+    assert_eq!(
+        a.rolling_min(Axis(0), window.centered()),
+        array![1., 1., 2., 2., 3.]
+    );
+    assert_eq!(
+        a.rolling_median(Axis(0), window.centered()),
+        array![3., 2., 5., 3., 5.5]
+    );
+    // Windows of even size extend further before the current element
+    assert_eq!(
+        a.rolling_sum(Axis(0), RollingWindow::new(4).centered().min_periods(1)),
+        array![6., 8., 16., 18., 13.]
+    );
+}
+
+#[test]
+fn test_rolling_min_periods_and_nan() {
+    let a = array![1., f64::NAN, 3., 4., 5.];
+    let sums = a.rolling_sum(Axis(0), RollingWindow::new(2));
+    assert_eq_with_nan(&sums, &array![f64::NAN, f64::NAN, f64::NAN, 7., 9.]);
+    let sums = a.rolling_sum(Axis(0), RollingWindow::new(2).min_periods(1).skip_nan());
+    assert_eq_with_nan(&sums, &array![1., 1., 3., 7., 9.]);
+    let sums = a.rolling_sum(Axis(0), RollingWindow::new(2).min_periods(0).skip_nan());
+    assert_eq_with_nan(&sums, &array![1., 1., 3., 7., 9.]);
+    let stds = a.rolling_std(Axis(0), RollingWindow::new(3).min_periods(1).skip_nan(), 1.);
+    assert_eq_with_nan(
+        &stds,
+        &array![f64::NAN, f64::NAN, 2f64.sqrt(), 0.5f64.sqrt(), 1.],
+    );
+}
+
+#[test]
+fn test_rolling_with_infinite_values() {
+    let inf = f64::INFINITY;
+    let nan = f64::NAN;
+    let a = array![1., inf, 2., 3., 4.];
+    let window = RollingWindow::new(2);
+    assert_eq_with_nan(
+        &a.rolling_sum(Axis(0), window),
+        &array![nan, inf, inf, 5., 7.],
+    );
+    assert_eq_with_nan(
+        &a.rolling_mean(Axis(0), window),
+        &array![nan, inf, inf, 2.5, 3.5],
+    );
+    assert_eq_with_nan(
+        &a.rolling_var(Axis(0), window, 1.),
+        &array![nan, nan, nan, 0.5, 0.5],
+    );
+
+    let a = array![-inf, 1., inf, 2., 3., 4.];
+    let window = RollingWindow::new(3).min_periods(1);
+    assert_eq_with_nan(
+        &a.rolling_sum(Axis(0), window),
+        &array![-inf, -inf, nan, inf, inf, 9.],
+    );
+    assert_eq_with_nan(
+        &a.rolling_mean(Axis(0), window),
+        &array![-inf, -inf, nan, inf, inf, 3.],
+    );
+    assert_eq_with_nan(
+        &a.rolling_var(Axis(0), window, 0.),
+        &array![nan, nan, nan, nan, nan, 2. / 3.],
+    );
+}
+
+#[test]
+fn test_rolling_quantile_with_invalid_quantile() {
+    let a = array![1., 2.];
+    assert_eq!(
+        a.rolling_quantile(Axis(0), RollingWindow::new(1), n64(1.5), &Lower),
+        Err(QuantileError::InvalidQuantile(n64(1.5)))
+    );
+}
+
+#[test]
+fn test_rolling_axis() {
+    let a: Array2<f64> = Array2::random((6, 9), Normal::new(0., 1.).unwrap());
+    let window = RollingWindow::new(4).min_periods(2);
+    for axis in 0..2 {
+        let maxima = a.rolling_max(Axis(axis), window);
+        let quantiles = a
+            .rolling_quantile(Axis(axis), window, n64(0.8), &Higher)
+            .unwrap();
+        assert_eq!(maxima.shape(), a.shape());
+        for ((lane, max_lane), quantile_lane) in a
+            .lanes(Axis(axis))
+            .into_iter()
+            .zip(maxima.lanes(Axis(axis)))
+            .zip(quantiles.lanes(Axis(axis)))
+        {
+            assert_eq_with_nan(&max_lane.to_owned(), &lane.rolling_max(Axis(0), window));
+            assert_eq_with_nan(
+                &quantile_lane.to_owned(),
+                &lane
+                    .rolling_quantile(Axis(0), window, n64(0.8), &Higher)
+                    .unwrap(),
+            );
+        }
+    }
+}
+
+#[quickcheck]
+fn rolling_eq_naive_rolling(
+    values: Vec<Option<i8>>,
+    size: u8,
+    centered: bool,
+    min_periods: u8,
+    skip_nan: bool,
+) -> bool {
+    let a: Array1<f64> = values
+        .into_iter()
+        .map(|x| x.map_or(f64::NAN, f64::from))
+        .collect();
+    let size = usize::from(size % 10) + 1;
+    let min_periods = usize::from(min_periods) % (size + 1);
+    let mut window = RollingWindow::new(size).min_periods(min_periods);
+    if centered {
+        window = window.centered();
+    }
+    if skip_nan {
+        window = window.skip_nan();
+    }
+    let naive = |statistic: &dyn Fn(&[f64]) -> f64| {
+        naive_rolling(a.view(), size, centered, min_periods, skip_nan, statistic)
+    };
+    let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+    let min = |values: &[f64]| values.iter().cloned().fold(f64::NAN, f64::min);
+    let max = |values: &[f64]| values.iter().cloned().fold(f64::NAN, f64::max);
+    let median = |values: &[f64]| {
+        if values.is_empty() {
+            f64::NAN
+        } else {
+            quantile(values, 0.5)
+        }
+    };
+    let quartile = |values: &[f64]| {
+        if values.is_empty() {
+            f64::NAN
+        } else {
+            quantile(values, 0.25)
+        }
+    };
+
+    assert_eq_with_nan(
+        &a.rolling_sum(Axis(0), window),
+        &naive(&|values: &[f64]| values.iter().sum()),
+    );
+    assert_eq_with_nan(&a.rolling_mean(Axis(0), window), &naive(&mean));
+    assert_eq_with_nan(&a.rolling_var(Axis(0), window, 1.), &naive(&sample_var));
+    assert_eq_with_nan(&a.rolling_min(Axis(0), window), &naive(&min));
+    assert_eq_with_nan(&a.rolling_max(Axis(0), window), &naive(&max));
+    assert_eq_with_nan(&a.rolling_median(Axis(0), window), &naive(&median));
+    assert_eq_with_nan(
+        &a.rolling_quantile(Axis(0), window, n64(0.25), &Linear)
+            .unwrap(),
+        &naive(&quartile),
+    );
+    true
+}
@@ -0,0 +1,130 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1, Array2, Axis};
+use ndarray_rand::rand_distr::Normal;
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    errors::{EmptyInput, ShapeMismatch},
+    interpolate::{Linear, Lower},
+    QuantileExt, ScalingExt,
+};
+use noisy_float::types::{n64, N64};
+
+#[test]
+fn test_scaling_with_empty_axis() {
+    let a: Array2<f64> = Array2::zeros((0, 3));
+    assert_eq!(a.fit_standardization(Axis(0), 0.), Err(EmptyInput));
+    assert_eq!(a.min_max_scale(Axis(0)), Err(EmptyInput));
+    assert_eq!(a.robust_scale(Axis(0), &Linear), Err(EmptyInput));
+    // Lanes along the other axis are empty, but there are none
+    assert_eq!(a.standardize(Axis(1), 0.).unwrap().shape(), &[0, 3]);
+}
+
+#[test]
+fn test_standardize() {
+    let a: Array2<f64> = Array2::random((20, 3), Normal::new(2., 3.).unwrap());
+    for &ddof in &[0., 1.] {
+        let standardization = a.fit_standardization(Axis(0), ddof).unwrap();
+        assert_eq!(standardization.axis(), Axis(0));
+        assert_abs_diff_eq!(standardization.mean(), a.mean_axis(Axis(0)).unwrap());
+        assert_abs_diff_eq!(standardization.std(), a.std_axis(Axis(0), ddof));
+
+        let z = a.standardize(Axis(0), ddof).unwrap();
+        assert_abs_diff_eq!(
+            z.mean_axis(Axis(0)).unwrap(),
+            Array1::zeros(3),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(z.std_axis(Axis(0), ddof), Array1::ones(3), epsilon = 1e-12);
+
+        let mut b = a.clone();
+        b.standardize_inplace(Axis(0), ddof).unwrap();
+        assert_eq!(b, z);
+    }
+}
+
+#[test]
+fn test_min_max_scale() {
+    let a = array![[1., 4., 2.], [-1., 4., f64::NAN]];
+    let scaling = a.fit_min_max_scaling(Axis(1)).unwrap();
+    assert_eq!(scaling.min().slice(ndarray::s![..1]), array![1.]);
+    assert_eq!(scaling.max().slice(ndarray::s![..1]), array![4.]);
+    assert!(scaling.min()[1].is_nan() && scaling.max()[1].is_nan());
+
+    let scaled = a.min_max_scale(Axis(1)).unwrap();
+    assert_eq!(scaled.row(0), array![0., 1., 1. / 3.]);
+    assert!(scaled.row(1).iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn test_constant_lanes_are_only_shifted() {
+    let a = array![[3., 1.], [3., 2.], [3., 3.]];
+    assert_eq!(
+        a.standardize(Axis(0), 0.).unwrap().column(0),
+        array![0., 0., 0.]
+    );
+    assert_eq!(
+        a.min_max_scale(Axis(0)).unwrap().column(0),
+        array![0., 0., 0.]
+    );
+    assert_eq!(
+        a.robust_scale(Axis(0), &Linear).unwrap().column(0),
+        array![0., 0., 0.]
+    );
+}
+
+#[test]
+fn test_robust_scale() {
+    let a = array![[1., 2., 3., 4., 100.], [5., 1., 3., 2., 4.]];
+    let scaling = a.fit_robust_scaling(Axis(1), &Linear).unwrap();
+    assert_eq!(scaling.median(), array![3., 3.]);
+    assert_eq!(scaling.iqr(), array![2., 2.]);
+    assert_eq!(
+        a.robust_scale(Axis(1), &Linear).unwrap(),
+        array![[-1., -0.5, 0., 0.5, 48.5], [1., -1., 0., -0.5, 0.5]]
+    );
+
+    // The parameters match the quantiles of the lanes
+    let b: Array2<f64> = Array2::random((3, 9), Normal::new(0., 1.).unwrap());
+    let scaling = b.fit_robust_scaling(Axis(1), &Lower).unwrap();
+    let quartiles = b
+        .mapv(n64)
+        .quantiles_axis_mut(Axis(1), &array![n64(0.25), n64(0.5), n64(0.75)], &Lower)
+        .unwrap()
+        .mapv(N64::raw);
+    assert_eq!(scaling.median(), quartiles.column(1));
+    assert_eq!(scaling.iqr(), &quartiles.column(2) - &quartiles.column(0));
+
+    let mut c = a.clone();
+    c.robust_scale_inplace(Axis(1), &Linear).unwrap();
+    assert_eq!(c, a.robust_scale(Axis(1), &Linear).unwrap());
+}
+
+#[test]
+fn test_transform_new_array() {
+    let train = array![[0., 10.], [2., 30.]];
+    let test = array![[1., 20.], [4., 50.], [-2., 0.]];
+    let standardization = train.fit_standardization(Axis(0), 1.).unwrap();
+    let std = 2f64.sqrt();
+    assert_abs_diff_eq!(
+        standardization.transform(&test).unwrap(),
+        array![
+            [0., 0.],
+            [3. / std, 30. / (10. * std)],
+            [-3. / std, -20. / (10. * std)],
+        ],
+        epsilon = 1e-12
+    );
+
+    let mut test = test;
+    let scaling = train.fit_min_max_scaling(Axis(0)).unwrap();
+    scaling.transform_inplace(&mut test).unwrap();
+    assert_eq!(test, array![[0.5, 0.5], [2., 2.], [-1., -0.5]]);
+
+    assert_eq!(
+        scaling.transform(&array![[1., 2., 3.]]),
+        Err(ShapeMismatch {
+            first_shape: vec![1, 2],
+            second_shape: vec![1, 3],
+        })
+    );
+}
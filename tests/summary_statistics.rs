@@ -1,12 +1,17 @@
 use approx::{abs_diff_eq, assert_abs_diff_eq};
-use ndarray::{arr0, array, Array, Array1, Array2, Axis};
+use ndarray::{arr0, array, s, Array, Array1, Array2, Axis};
 use ndarray_rand::rand_distr::Uniform;
 use ndarray_rand::RandomExt;
 use ndarray_stats::{
-    errors::{EmptyInput, MultiInputError, ShapeMismatch},
-    SummaryStatisticsExt,
+    errors::{
+        ConfidenceIntervalError, EmptyInput, EstimatorError, MultiInputError, PowerMeanError,
+        ShapeMismatch,
+    },
+    summation::{Kahan, Naive, Pairwise},
+    weights::{AnalyticWeights, FrequencyWeights, ProbabilityWeights},
+    KurtosisEstimator, MomentsAccumulator, SkewnessEstimator, SummaryStatisticsExt,
 };
-use noisy_float::types::N64;
+use noisy_float::types::{n64, N64};
 use quickcheck::{quickcheck, TestResult};
 use std::f64;
 
@@ -29,6 +34,8 @@ fn test_with_nan_values() {
         .is_nan());
     assert!(a.harmonic_mean().unwrap().is_nan());
     assert!(a.geometric_mean().unwrap().is_nan());
+    assert!(a.power_mean(2.0).unwrap().is_nan());
+    assert!(a.weighted_power_mean(&weights, -1.0).unwrap().is_nan());
     assert!(a.weighted_var(&weights, 0.0).unwrap().is_nan());
     assert!(a.weighted_std(&weights, 0.0).unwrap().is_nan());
     assert!(a
@@ -53,8 +60,8 @@ fn test_with_empty_array_of_floats() {
         a.weighted_mean_axis(Axis(0), &weights),
         Err(MultiInputError::EmptyInput)
     );
-    assert_eq!(a.harmonic_mean(), Err(EmptyInput));
-    assert_eq!(a.geometric_mean(), Err(EmptyInput));
+    assert_eq!(a.harmonic_mean(), Err(PowerMeanError::EmptyInput));
+    assert_eq!(a.geometric_mean(), Err(PowerMeanError::EmptyInput));
     assert_eq!(
         a.weighted_var(&weights, 0.0),
         Err(MultiInputError::EmptyInput)
@@ -87,8 +94,8 @@ fn test_with_empty_array_of_noisy_floats() {
         a.weighted_mean_axis(Axis(0), &weights),
         Err(MultiInputError::EmptyInput)
     );
-    assert_eq!(a.harmonic_mean(), Err(EmptyInput));
-    assert_eq!(a.geometric_mean(), Err(EmptyInput));
+    assert_eq!(a.harmonic_mean(), Err(PowerMeanError::EmptyInput));
+    assert_eq!(a.geometric_mean(), Err(PowerMeanError::EmptyInput));
     assert_eq!(
         a.weighted_var(&weights, N64::new(0.0)),
         Err(MultiInputError::EmptyInput)
@@ -230,7 +237,7 @@ fn weighted_sum_dimension_zero() {
 #[test]
 fn mean_eq_if_uniform_weights() {
     fn prop(a: Vec<f64>) -> TestResult {
-        if a.len() < 1 {
+        if a.is_empty() {
             return TestResult::discard();
         }
         let a = Array1::from(a);
@@ -268,7 +275,7 @@ fn mean_axis_eq_if_uniform_weights() {
 #[test]
 fn weighted_var_eq_var_if_uniform_weight() {
     fn prop(a: Vec<f64>) -> TestResult {
-        if a.len() < 1 {
+        if a.is_empty() {
             return TestResult::discard();
         }
         let a = Array1::from(a);
@@ -280,6 +287,174 @@ fn weighted_var_eq_var_if_uniform_weight() {
     quickcheck(prop as fn(Vec<f64>) -> TestResult);
 }
 
+#[test]
+fn test_power_means() {
+    let a = array![1., 2., 4., 8.];
+    assert_abs_diff_eq!(a.power_mean(1.).unwrap(), 3.75, epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        a.power_mean(2.).unwrap(),
+        85f64.sqrt() / 2.,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.power_mean(0.).unwrap(),
+        a.geometric_mean().unwrap(),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.power_mean(-1.).unwrap(),
+        a.harmonic_mean().unwrap(),
+        epsilon = 1e-12
+    );
+    assert_eq!(a.power_mean(f64::INFINITY).unwrap(), 8.);
+    assert_eq!(a.power_mean(f64::NEG_INFINITY).unwrap(), 1.);
+    // The power mean tends to the geometric mean, the maximum and the minimum
+    assert_abs_diff_eq!(a.power_mean(1e-9).unwrap(), 8f64.sqrt(), epsilon = 1e-6);
+    assert_abs_diff_eq!(a.power_mean(1e4).unwrap(), 8., epsilon = 1e-2);
+    assert_abs_diff_eq!(a.power_mean(-1e4).unwrap(), 1., epsilon = 1e-2);
+}
+
+#[test]
+fn test_power_means_domain() {
+    let a = array![0., 1., 2.];
+    assert_abs_diff_eq!(a.power_mean(1.).unwrap(), 1., epsilon = 1e-12);
+    assert_eq!(a.power_mean(f64::INFINITY).unwrap(), 2.);
+    assert_eq!(
+        a.power_mean(f64::NEG_INFINITY),
+        Err(PowerMeanError::NonPositiveValue)
+    );
+    assert_eq!(a.power_mean(0.), Err(PowerMeanError::NonPositiveValue));
+    assert_eq!(a.power_mean(-2.), Err(PowerMeanError::NonPositiveValue));
+    assert_eq!(
+        a.weighted_harmonic_mean(&array![1., 1., 1.]),
+        Err(PowerMeanError::NonPositiveValue)
+    );
+
+    assert_eq!(a.harmonic_mean(), Err(PowerMeanError::NonPositiveValue));
+    assert_eq!(a.geometric_mean(), Err(PowerMeanError::NonPositiveValue));
+
+    let a = array![-1., 1., 2.];
+    assert_eq!(a.power_mean(2.), Err(PowerMeanError::NonPositiveValue));
+    assert_eq!(a.geometric_mean(), Err(PowerMeanError::NonPositiveValue));
+    // The reciprocals would sum to zero
+    assert_eq!(
+        array![-1., 1.].harmonic_mean(),
+        Err(PowerMeanError::NonPositiveValue)
+    );
+    assert_eq!(
+        a.power_mean(f64::INFINITY),
+        Err(PowerMeanError::NonPositiveValue)
+    );
+    let a = array![1., 2., 3.];
+    for &p in &[f64::NEG_INFINITY, -1., 0., 2., f64::INFINITY] {
+        assert_eq!(
+            a.weighted_power_mean(&array![1., -1., 1.], p),
+            Err(PowerMeanError::NegativeWeight)
+        );
+    }
+
+    let a: Array1<f64> = array![];
+    assert_eq!(a.power_mean(1.), Err(PowerMeanError::EmptyInput));
+    assert_eq!(
+        a.weighted_power_mean(&array![], 1.),
+        Err(PowerMeanError::EmptyInput)
+    );
+    assert_eq!(
+        array![1., 2.].weighted_geometric_mean(&array![1.]),
+        Err(PowerMeanError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2],
+            second_shape: vec![1],
+        }))
+    );
+}
+
+#[test]
+fn test_weighted_power_means_eq_expanded_power_means() {
+    let a = array![1., 3., 9.];
+    let weights = array![2., 0., 1.];
+    let expanded = array![1., 1., 9.];
+    for &p in &[-2., -1., 0., 0.5, 1., 3., f64::INFINITY, f64::NEG_INFINITY] {
+        assert_abs_diff_eq!(
+            a.weighted_power_mean(&weights, p).unwrap(),
+            expanded.power_mean(p).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+    assert_abs_diff_eq!(
+        a.weighted_geometric_mean(&weights).unwrap(),
+        expanded.geometric_mean().unwrap(),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.weighted_harmonic_mean(&weights).unwrap(),
+        expanded.harmonic_mean().unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_power_means_axis_eq_lane_power_means() {
+    let a = array![[1., 2., 4.], [3., 5., 7.]];
+    let weights = array![1., 2., 3.];
+    for &p in &[-1., 0., 2., f64::INFINITY] {
+        let means = a.power_mean_axis(Axis(1), p).unwrap();
+        let weighted_means = a.weighted_power_mean_axis(Axis(1), &weights, p).unwrap();
+        for (i, lane) in a.outer_iter().enumerate() {
+            assert_abs_diff_eq!(means[i], lane.power_mean(p).unwrap(), epsilon = 1e-12);
+            assert_abs_diff_eq!(
+                weighted_means[i],
+                lane.weighted_power_mean(&weights, p).unwrap(),
+                epsilon = 1e-12
+            );
+        }
+    }
+    assert_abs_diff_eq!(
+        a.geometric_mean_axis(Axis(0)).unwrap(),
+        array![3f64.sqrt(), 10f64.sqrt(), 28f64.sqrt()],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.harmonic_mean_axis(Axis(0)).unwrap(),
+        array![1.5, 20. / 7., 56. / 11.],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.weighted_geometric_mean_axis(Axis(1), &weights).unwrap(),
+        array![
+            (2f64.ln() * 2. / 6. + 4f64.ln() * 3. / 6.).exp(),
+            (3f64.ln() / 6. + 5f64.ln() * 2. / 6. + 7f64.ln() * 3. / 6.).exp()
+        ],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.weighted_harmonic_mean_axis(Axis(1), &weights).unwrap(),
+        array![6. / (1. + 1. + 0.75), 6. / (1. / 3. + 0.4 + 3. / 7.)],
+        epsilon = 1e-12
+    );
+
+    let a = array![[1., 2.], [0., 3.]];
+    assert_eq!(
+        a.geometric_mean_axis(Axis(1)),
+        Err(PowerMeanError::NonPositiveValue)
+    );
+    assert_eq!(
+        a.weighted_power_mean_axis(Axis(1), &array![1.], 1.),
+        Err(PowerMeanError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2, 2],
+            second_shape: vec![1],
+        }))
+    );
+    assert_eq!(
+        a.weighted_geometric_mean_axis(Axis(0), &array![2., -1.]),
+        Err(PowerMeanError::NegativeWeight)
+    );
+    let a: Array2<f64> = Array2::zeros((2, 0));
+    assert_eq!(
+        a.power_mean_axis(Axis(1), 1.),
+        Err(PowerMeanError::EmptyInput)
+    );
+}
+
 #[test]
 fn weighted_var_algo_eq_simple_algo() {
     fn prop(mut a: Vec<f64>) -> TestResult {
@@ -347,7 +522,7 @@ fn test_central_moments() {
         0.5028445, 0.6693834, 0.14256682, 0.52724704, 0.73482372, 0.1809703,
     ];
     // Computed using scipy.stats.moment
-    let expected_moments = vec![
+    let expected_moments = [
         1.,
         0.,
         0.09339920262960291,
@@ -408,3 +583,946 @@ fn test_kurtosis_and_skewness() {
     assert_abs_diff_eq!(kurtosis, expected_kurtosis, epsilon = 1e-12);
     assert_abs_diff_eq!(skewness, expected_skewness, epsilon = 1e-8);
 }
+
+#[test]
+fn test_skewness_and_kurtosis_estimators() {
+    let a: Array1<f64> = array![1., 2., 3., 4., 10.];
+    // n = 5, m₂ = 10, m₃ = 36, m₄ = 278.8; G₂ is cross-checked against the
+    // formula used by SciPy's `kurtosis` with `bias=False`
+    for &(estimator, expected) in &[
+        (SkewnessEstimator::FisherPearson, 1.1384199576606167),
+        (SkewnessEstimator::G1, 1.6970562748477143),
+        (SkewnessEstimator::B1, 0.8145870119269027),
+    ] {
+        assert_abs_diff_eq!(
+            a.skewness_with(estimator).unwrap(),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+    for &(estimator, expected) in &[
+        (KurtosisEstimator::Moment, -0.212),
+        (KurtosisEstimator::G2, 3.152),
+        (KurtosisEstimator::B2, -1.21568),
+    ] {
+        assert_abs_diff_eq!(
+            a.kurtosis_with(estimator).unwrap(),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+    assert_abs_diff_eq!(
+        a.kurtosis_with(KurtosisEstimator::Moment).unwrap() + 3.,
+        a.kurtosis().unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_skewness_and_kurtosis_estimators_with_too_few_elements() {
+    let empty: Array1<f64> = array![];
+    assert_eq!(
+        empty.skewness_with(SkewnessEstimator::FisherPearson),
+        Err(EstimatorError::EmptyInput)
+    );
+    assert_eq!(
+        empty.kurtosis_with(KurtosisEstimator::B2),
+        Err(EstimatorError::EmptyInput)
+    );
+    let a: Array1<f64> = array![1., 2., 4.];
+    assert!(a.skewness_with(SkewnessEstimator::G1).is_ok());
+    assert_eq!(
+        a.slice(s![..2]).skewness_with(SkewnessEstimator::G1),
+        Err(EstimatorError::NotEnoughElements { min_len: 3, len: 2 })
+    );
+    assert_eq!(
+        a.kurtosis_with(KurtosisEstimator::G2),
+        Err(EstimatorError::NotEnoughElements { min_len: 4, len: 3 })
+    );
+    assert_eq!(
+        Array2::<f64>::zeros((3, 2)).kurtosis_axis_with(Axis(0), KurtosisEstimator::G2),
+        Err(EstimatorError::NotEnoughElements { min_len: 4, len: 3 })
+    );
+}
+
+#[test]
+fn test_skewness_and_kurtosis_estimators_axis() {
+    let a = Array::random((4, 6), Uniform::new(-1., 1.));
+    let skewness = a
+        .skewness_axis_with(Axis(1), SkewnessEstimator::G1)
+        .unwrap();
+    let kurtosis = a
+        .kurtosis_axis_with(Axis(1), KurtosisEstimator::G2)
+        .unwrap();
+    for (i, lane) in a.outer_iter().enumerate() {
+        assert_abs_diff_eq!(
+            skewness[i],
+            lane.skewness_with(SkewnessEstimator::G1).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            kurtosis[i],
+            lane.kurtosis_with(KurtosisEstimator::G2).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_central_moments_axis_with_empty_axis() {
+    let a = Array2::<f64>::zeros((0, 3));
+    assert_eq!(a.central_moment_axis(Axis(0), 2), Err(EmptyInput));
+    assert_eq!(a.central_moments_axis(Axis(0), 2), Err(EmptyInput));
+    assert_eq!(a.skewness_axis(Axis(0)), Err(EmptyInput));
+    assert_eq!(a.kurtosis_axis(Axis(0)), Err(EmptyInput));
+    // Reducing along a non-empty axis is fine
+    assert_eq!(
+        a.central_moments_axis(Axis(1), 2).unwrap(),
+        Array2::zeros((0, 3))
+    );
+}
+
+#[test]
+fn test_central_moments_axis_eq_lane_central_moments() {
+    let a = Array::random((5, 20, 3), Uniform::new(-1., 1.));
+    let order = 6;
+    for axis in 0..a.ndim() {
+        let axis = Axis(axis);
+        let central_moments = a.central_moments_axis(axis, order).unwrap();
+        assert_eq!(central_moments.len_of(axis), order as usize + 1);
+        for k in 0..=order {
+            let central_moment = a.central_moment_axis(axis, k).unwrap();
+            let expected = a.map_axis(axis, |lane| lane.to_owned().central_moment(k).unwrap());
+            assert_abs_diff_eq!(central_moment, expected, epsilon = 1e-12);
+            assert_abs_diff_eq!(
+                central_moments.index_axis(axis, k as usize),
+                expected,
+                epsilon = 1e-12
+            );
+        }
+    }
+}
+
+#[test]
+fn test_kurtosis_and_skewness_axis() {
+    let a = Array::random((4, 30), Uniform::new(0., 1.));
+    let kurtosis = a.kurtosis_axis(Axis(1)).unwrap();
+    let skewness = a.skewness_axis(Axis(1)).unwrap();
+    for (i, row) in a.outer_iter().enumerate() {
+        assert_abs_diff_eq!(kurtosis[i], row.kurtosis().unwrap(), epsilon = 1e-12);
+        assert_abs_diff_eq!(skewness[i], row.skewness().unwrap(), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_moments_accumulator_with_no_values() {
+    let acc = MomentsAccumulator::<f64>::new(4);
+    assert_eq!(acc.count(), 0);
+    assert_eq!(acc.mean(), Err(EmptyInput));
+    assert_eq!(acc.central_moments(), Err(EmptyInput));
+    assert_eq!(acc.skewness(), Err(EmptyInput));
+    assert_eq!(acc.kurtosis(), Err(EmptyInput));
+}
+
+#[test]
+fn test_moments_accumulator_eq_central_moments() {
+    let a = Array::random(100, Uniform::new(-10., 10.));
+    let order = 6;
+    let expected = a.central_moments(order).unwrap();
+
+    let mut pushed = MomentsAccumulator::new(order);
+    for &x in a.iter() {
+        pushed.push(x);
+    }
+    let mut pushed_array = MomentsAccumulator::new(order);
+    pushed_array.push_array(&a);
+
+    for acc in &[pushed, pushed_array] {
+        assert_eq!(acc.count(), a.len());
+        assert_abs_diff_eq!(acc.mean().unwrap(), a.mean().unwrap(), epsilon = 1e-12);
+        for (k, (&m, &e)) in acc
+            .central_moments()
+            .unwrap()
+            .iter()
+            .zip(&expected)
+            .enumerate()
+        {
+            assert_abs_diff_eq!(m, e, epsilon = 1e-9 * 10f64.powi(k as i32));
+        }
+        assert_abs_diff_eq!(
+            acc.skewness().unwrap(),
+            a.skewness().unwrap(),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            acc.kurtosis().unwrap(),
+            a.kurtosis().unwrap(),
+            epsilon = 1e-9
+        );
+    }
+}
+
+#[test]
+fn moments_accumulator_merge_eq_push_array() {
+    fn prop(a: Vec<f64>, split: usize) -> TestResult {
+        if a.len() < 2 || a.iter().any(|x| x.abs() > 1e3) {
+            return TestResult::discard();
+        }
+        let split = split % a.len();
+        let a = Array1::from(a);
+        let (left, right) = a.view().split_at(Axis(0), split);
+
+        let mut merged = MomentsAccumulator::new(4);
+        merged.push_array(&left);
+        let mut other = MomentsAccumulator::new(4);
+        other.push_array(&right);
+        merged.merge(&other);
+
+        let mut expected = MomentsAccumulator::new(4);
+        expected.push_array(&a);
+
+        let scale = a.iter().fold(1., |acc: f64, x| acc.max(x.abs()));
+        let success = merged.count() == expected.count()
+            && merged
+                .central_moments()
+                .unwrap()
+                .iter()
+                .zip(&expected.central_moments().unwrap())
+                .enumerate()
+                .all(|(k, (m, e))| abs_diff_eq!(m, e, epsilon = 1e-9 * scale.powi(k as i32)));
+        TestResult::from_bool(success)
+    }
+    quickcheck(prop as fn(Vec<f64>, usize) -> TestResult);
+}
+
+#[test]
+fn test_weighted_var_with_frequency_weights_eq_expanded_var() {
+    let a = array![1., 4., 2., 8., 5.];
+    let counts = array![3., 1., 2., 4., 1.];
+    let expanded: Array1<f64> = a
+        .iter()
+        .zip(&counts)
+        .flat_map(|(&x, &c)| vec![x; c as usize])
+        .collect();
+    let weights = FrequencyWeights::new(counts.view());
+    for &ddof in &[0., 1.] {
+        assert_abs_diff_eq!(
+            a.weighted_var(&weights, ddof).unwrap(),
+            expanded.var(ddof),
+            epsilon = 1e-12
+        );
+        // Plain arrays are frequency weights
+        assert_abs_diff_eq!(
+            a.weighted_var(&counts, ddof).unwrap(),
+            expanded.var(ddof),
+            epsilon = 1e-12
+        );
+    }
+    assert_abs_diff_eq!(
+        a.weighted_mean(&weights).unwrap(),
+        expanded.mean().unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_weighted_var_with_analytic_and_probability_weights() {
+    let a: Array1<f64> = array![0.5, 1.5, -2., 3.25, 0.];
+    let w = array![0.2, 1.3, 0.7, 2.1, 0.4];
+    let (sum, sum_sq, n) = (w.sum(), w.mapv(|w| w * w).sum(), w.len() as f64);
+    let mean = a.weighted_mean(&w).unwrap();
+    let sq_dev = a
+        .iter()
+        .zip(&w)
+        .map(|(&x, &w)| w * (x - mean).powi(2))
+        .sum::<f64>();
+
+    let analytic = AnalyticWeights::new(w.view());
+    assert_abs_diff_eq!(a.weighted_mean(&analytic).unwrap(), mean, epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        a.weighted_var(&analytic, 0.).unwrap(),
+        sq_dev / sum,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.weighted_var(&analytic, 1.).unwrap(),
+        sq_dev / (sum - sum_sq / sum),
+        epsilon = 1e-12
+    );
+
+    let probability = ProbabilityWeights::new(w.clone());
+    assert_abs_diff_eq!(
+        a.weighted_var(&probability, 0.).unwrap(),
+        sq_dev / sum,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.weighted_std(&probability, 1.).unwrap(),
+        (sq_dev * n / ((n - 1.) * sum)).sqrt(),
+        epsilon = 1e-12
+    );
+
+    // The axis versions apply the same correction to every lane
+    let data = array![[0.5, 1.5, -2., 3.25, 0.], [1., 2., 3., 4., 5.]];
+    let var_axis = data.weighted_var_axis(Axis(1), &analytic, 1.).unwrap();
+    for (row, &var) in data.outer_iter().zip(&var_axis) {
+        assert_abs_diff_eq!(
+            row.weighted_var(&analytic, 1.).unwrap(),
+            var,
+            epsilon = 1e-12
+        );
+    }
+    let std_axis = data.weighted_std_axis(Axis(1), &probability, 1.).unwrap();
+    for (row, &std) in data.outer_iter().zip(&std_axis) {
+        assert_abs_diff_eq!(
+            row.weighted_std(&probability, 1.).unwrap(),
+            std,
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_weighted_central_moments_with_errors() {
+    let a: Array1<f64> = array![];
+    assert_eq!(
+        a.weighted_central_moments(&array![], 2),
+        Err(MultiInputError::EmptyInput)
+    );
+    assert_eq!(
+        a.weighted_skewness_axis(Axis(0), &array![]),
+        Err(MultiInputError::EmptyInput)
+    );
+    let a = array![1., 2., 3.];
+    assert!(a
+        .weighted_kurtosis(&array![1., 2.])
+        .unwrap_err()
+        .is_shape_mismatch());
+    assert!(a
+        .weighted_central_moment_axis(Axis(0), &array![1., 2.], 3)
+        .unwrap_err()
+        .is_shape_mismatch());
+}
+
+#[test]
+fn test_weighted_central_moments_eq_expanded_central_moments() {
+    let a = array![0.3, -1.2, 2.5, 0.8, 1.1, -0.4];
+    let counts = array![2., 5., 1., 3., 1., 4.];
+    let expanded: Array1<f64> = a
+        .iter()
+        .zip(&counts)
+        .flat_map(|(&x, &c)| vec![x; c as usize])
+        .collect();
+    let order = 6;
+    let weighted = a.weighted_central_moments(&counts, order).unwrap();
+    let expected = expanded.central_moments(order).unwrap();
+    for k in 0..=order {
+        assert_abs_diff_eq!(weighted[k as usize], expected[k as usize], epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            a.weighted_central_moment(&counts, k).unwrap(),
+            expected[k as usize],
+            epsilon = 1e-12
+        );
+    }
+    assert_abs_diff_eq!(
+        a.weighted_skewness(&FrequencyWeights::new(counts.view()))
+            .unwrap(),
+        expanded.skewness().unwrap(),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.weighted_kurtosis(&counts).unwrap(),
+        expanded.kurtosis().unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_weighted_central_moments_axis_eq_lane_weighted_central_moments() {
+    let a = Array::random((4, 7, 3), Uniform::new(-1., 1.));
+    let order = 5;
+    for axis in 0..a.ndim() {
+        let axis = Axis(axis);
+        let weights = Array::random(a.len_of(axis), Uniform::new(0.1, 1.));
+        let central_moments = a
+            .weighted_central_moments_axis(axis, &weights, order)
+            .unwrap();
+        let skewness = a.weighted_skewness_axis(axis, &weights).unwrap();
+        let kurtosis = a.weighted_kurtosis_axis(axis, &weights).unwrap();
+        for k in 0..=order {
+            let expected = a.map_axis(axis, |lane| {
+                lane.weighted_central_moment(&weights, k).unwrap()
+            });
+            assert_abs_diff_eq!(
+                central_moments.index_axis(axis, k as usize),
+                expected,
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(
+                a.weighted_central_moment_axis(axis, &weights, k).unwrap(),
+                expected,
+                epsilon = 1e-12
+            );
+        }
+        assert_abs_diff_eq!(
+            skewness,
+            a.map_axis(axis, |lane| lane.weighted_skewness(&weights).unwrap()),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            kurtosis,
+            a.map_axis(axis, |lane| lane.weighted_kurtosis(&weights).unwrap()),
+            epsilon = 1e-9
+        );
+    }
+}
+
+#[test]
+fn test_mean_with_summation() {
+    let a: Array1<f64> = array![1., 2., 3., 4.];
+    assert_eq!(a.mean_with(&Naive).unwrap(), a.mean().unwrap());
+    assert_eq!(a.mean_with(&Pairwise).unwrap(), 2.5);
+    assert_eq!(a.mean_with(&Kahan).unwrap(), 2.5);
+    let empty: Array1<f64> = array![];
+    assert_eq!(empty.mean_with(&Kahan), Err(EmptyInput));
+
+    // Sequential summation of a million `f32`s drifts visibly
+    let a = Array1::<f32>::from_elem(1_000_000, 0.1);
+    let expected = f64::from(0.1f32);
+    for &mean in &[
+        a.mean_with(&Pairwise).unwrap(),
+        a.mean_with(&Kahan).unwrap(),
+    ] {
+        assert_abs_diff_eq!(f64::from(mean), expected, epsilon = 1e-7);
+    }
+}
+
+#[test]
+fn test_summation_with_infinite_values() {
+    let a = array![1., f64::INFINITY, 2.];
+    let b = array![1., f64::NEG_INFINITY, f64::INFINITY];
+    let w = array![1., 2., 1.];
+    assert_eq!(a.mean_with(&Kahan), Ok(f64::INFINITY));
+    assert_eq!(a.mean_with(&Kahan), a.mean_with(&Pairwise));
+    assert_eq!((-&a).mean_with(&Kahan), Ok(f64::NEG_INFINITY));
+    assert!(b.mean_with(&Kahan).unwrap().is_nan());
+    assert_eq!(a.weighted_sum_with(&w, &Kahan), Ok(f64::INFINITY));
+    assert!(b.weighted_sum_with(&w, &Kahan).unwrap().is_nan());
+    assert!(a.weighted_var_with(&w, 0., &Kahan).unwrap().is_nan());
+    let moments = a.central_moments_with(2, &Kahan).unwrap();
+    assert_eq!(moments[..2], [1., 0.]);
+    assert!(moments[2].is_nan() && a.central_moments(2).unwrap()[2].is_nan());
+}
+
+#[test]
+fn test_weighted_sum_and_var_with_summation() {
+    let a = Array::random(10_000, Uniform::new(-1f32, 1.));
+    let w = Array::random(10_000, Uniform::new(0f32, 1.));
+    let a64 = a.mapv(f64::from);
+    let w64 = w.mapv(f64::from);
+
+    let expected_sum = a64.weighted_sum(&w64).unwrap();
+    assert_eq!(a.weighted_sum_with(&w, &Naive), a.weighted_sum(&w));
+    for &sum in &[
+        a.weighted_sum_with(&w, &Pairwise).unwrap(),
+        a.weighted_sum_with(&w, &Kahan).unwrap(),
+    ] {
+        assert_abs_diff_eq!(f64::from(sum), expected_sum, epsilon = 1e-4);
+    }
+
+    let expected_var = a64.weighted_var(&w64, 1.).unwrap();
+    for &var in &[
+        a.weighted_var_with(&w, 1., &Naive).unwrap(),
+        a.weighted_var_with(&w, 1., &Pairwise).unwrap(),
+        a.weighted_var_with(&AnalyticWeights::new(w.view()), 0., &Kahan)
+            .unwrap(),
+    ] {
+        assert_abs_diff_eq!(f64::from(var), expected_var, epsilon = 1e-3);
+    }
+    assert_abs_diff_eq!(
+        a64.weighted_var_with(&w64, 1., &Kahan).unwrap(),
+        expected_var,
+        epsilon = 1e-12
+    );
+
+    assert_eq!(
+        a.weighted_var_with(&w.slice(s![..10]), 1., &Kahan),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![10_000],
+            second_shape: vec![10],
+        }))
+    );
+}
+
+#[test]
+fn test_central_moments_with_summation() {
+    let a = Array::random(1_000, Uniform::new(-1., 1.));
+    assert_eq!(a.central_moments_with(5, &Naive), a.central_moments(5));
+    let expected = a.central_moments(5).unwrap();
+    for moments in &[
+        a.central_moments_with(5, &Pairwise).unwrap(),
+        a.central_moments_with(5, &Kahan).unwrap(),
+    ] {
+        for (&moment, &expected) in moments.iter().zip(&expected) {
+            assert_abs_diff_eq!(moment, expected, epsilon = 1e-12);
+        }
+    }
+    let empty: Array1<f64> = array![];
+    assert_eq!(empty.central_moments_with(2, &Kahan), Err(EmptyInput));
+}
+
+#[test]
+fn test_sem_and_mean_confidence_interval() {
+    let a: Array1<f64> = array![4., 6., 5., 7., 3.];
+    // s² = 2.5, n = 5
+    assert_abs_diff_eq!(a.sem().unwrap(), 0.5f64.sqrt(), epsilon = 1e-12);
+    // t = 2.776445 (0.975 quantile, 4 degrees of freedom)
+    let (lower, upper) = a.mean_confidence_interval(0.95).unwrap();
+    assert_abs_diff_eq!(lower, 5. - 2.776445 * 0.5f64.sqrt(), epsilon = 1e-6);
+    assert_abs_diff_eq!(upper, 5. + 2.776445 * 0.5f64.sqrt(), epsilon = 1e-6);
+    // t = 4.604095 (0.995 quantile, 4 degrees of freedom)
+    let (lower, upper) = a.mean_confidence_interval(0.99).unwrap();
+    assert_abs_diff_eq!(upper - lower, 2. * 4.604095 * 0.5f64.sqrt(), epsilon = 1e-6);
+
+    assert_eq!(
+        array![1.].sem(),
+        Err(EstimatorError::NotEnoughElements { min_len: 2, len: 1 })
+    );
+    let empty: Array1<f64> = array![];
+    assert_eq!(
+        empty.mean_confidence_interval(0.95),
+        Err(ConfidenceIntervalError::EmptyInput)
+    );
+    assert_eq!(
+        array![1.].mean_confidence_interval(0.95),
+        Err(ConfidenceIntervalError::NotEnoughElements { min_len: 2, len: 1 })
+    );
+}
+
+#[test]
+fn test_mean_confidence_interval_with_invalid_level() {
+    let a = array![1., 2., 3.];
+    let ones = FrequencyWeights::new(array![1., 1., 1.]);
+    for &level in &[0., 1., -0.5, 1.5, f64::NAN] {
+        assert_eq!(
+            a.mean_confidence_interval(level),
+            Err(ConfidenceIntervalError::InvalidLevel)
+        );
+        assert_eq!(
+            a.weighted_mean_confidence_interval(&ones, level),
+            Err(ConfidenceIntervalError::InvalidLevel)
+        );
+    }
+}
+
+#[test]
+fn test_sem_axis() {
+    let a = Array::random((4, 7), Uniform::new(-1., 1.));
+    let sem = a.sem_axis(Axis(1)).unwrap();
+    for (lane, &lane_sem) in a.outer_iter().zip(&sem) {
+        assert_abs_diff_eq!(lane.sem().unwrap(), lane_sem, epsilon = 1e-12);
+    }
+    assert_eq!(
+        Array2::<f64>::zeros((1, 3)).sem_axis(Axis(0)),
+        Err(EstimatorError::NotEnoughElements { min_len: 2, len: 1 })
+    );
+}
+
+#[test]
+fn test_weighted_sem_and_mean_confidence_interval() {
+    let a: Array1<f64> = array![4., 6., 5., 7., 3.];
+    let ones = Array1::<f64>::ones(5);
+    assert_abs_diff_eq!(
+        a.weighted_sem(&ones).unwrap(),
+        a.sem().unwrap(),
+        epsilon = 1e-12
+    );
+    let (lower, upper) = a.mean_confidence_interval(0.9).unwrap();
+    let (weighted_lower, weighted_upper) = a.weighted_mean_confidence_interval(&ones, 0.9).unwrap();
+    assert_abs_diff_eq!(lower, weighted_lower, epsilon = 1e-12);
+    assert_abs_diff_eq!(upper, weighted_upper, epsilon = 1e-12);
+
+    // Frequency weights are equivalent to repeated observations
+    let counts = array![1., 3., 2.];
+    let expanded: Array1<f64> = array![1., 2., 2., 2., 5., 5.];
+    let values = array![1., 2., 5.];
+    assert_abs_diff_eq!(
+        values
+            .weighted_sem(&FrequencyWeights::new(counts.view()))
+            .unwrap(),
+        expanded.sem().unwrap(),
+        epsilon = 1e-12
+    );
+    let (lower, upper) = expanded.mean_confidence_interval(0.95).unwrap();
+    let (weighted_lower, weighted_upper) = values
+        .weighted_mean_confidence_interval(&counts, 0.95)
+        .unwrap();
+    assert_abs_diff_eq!(lower, weighted_lower, epsilon = 1e-12);
+    assert_abs_diff_eq!(upper, weighted_upper, epsilon = 1e-12);
+
+    // Probability weights use the number of weights as effective length
+    let w = array![0.1, 0.5, 0.2, 0.9, 0.3];
+    let expected = a
+        .weighted_std(&ProbabilityWeights::new(w.view()), 1.)
+        .unwrap()
+        / 5f64.sqrt();
+    assert_abs_diff_eq!(
+        a.weighted_sem(&ProbabilityWeights::new(w.view())).unwrap(),
+        expected,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_cumulants() {
+    let a: Array1<f64> = Array::random(50, Uniform::new(-5., 5.));
+    let central_moments = a.central_moments(6).unwrap();
+    let cumulants = a.cumulants(6).unwrap();
+    let (m2, m3, m4, m5, m6) = (
+        central_moments[2],
+        central_moments[3],
+        central_moments[4],
+        central_moments[5],
+        central_moments[6],
+    );
+    let expected = array![
+        0.,
+        a.mean().unwrap(),
+        m2,
+        m3,
+        m4 - 3. * m2.powi(2),
+        m5 - 10. * m3 * m2,
+        m6 - 15. * m4 * m2 - 10. * m3.powi(2) + 30. * m2.powi(3),
+    ];
+    assert_abs_diff_eq!(Array1::from(cumulants), expected, epsilon = 1e-9);
+    assert_eq!(a.cumulants(0).unwrap(), vec![0.]);
+
+    let empty: Array1<f64> = array![];
+    assert_eq!(empty.cumulants(2), Err(EmptyInput));
+    assert_eq!(
+        Array2::<f64>::zeros((2, 0)).cumulants_axis(Axis(1), 2),
+        Err(EmptyInput)
+    );
+}
+
+#[test]
+fn test_cumulants_axis_eq_lane_cumulants() {
+    let a: Array2<f64> = Array::random((4, 7), Uniform::new(-1., 1.));
+    for axis in 0..2 {
+        let cumulants = a.cumulants_axis(Axis(axis), 5).unwrap();
+        assert_eq!(cumulants.len_of(Axis(axis)), 6);
+        for (lane, cumulants) in a
+            .lanes(Axis(axis))
+            .into_iter()
+            .zip(cumulants.lanes(Axis(axis)))
+        {
+            assert_eq!(cumulants.to_vec(), lane.cumulants(5).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_k_statistic() {
+    let a = array![1., 2., 3., 6.];
+    assert_abs_diff_eq!(a.k_statistic(1).unwrap(), 3.);
+    assert_abs_diff_eq!(a.k_statistic(2).unwrap(), a.var(1.), epsilon = 1e-12);
+    assert_abs_diff_eq!(a.k_statistic(3).unwrap(), 12., epsilon = 1e-12);
+    assert_abs_diff_eq!(a.k_statistic(4).unwrap(), 98. / 3., epsilon = 1e-12);
+
+    let b: Array2<f64> = Array::random((5, 6), Uniform::new(-1., 1.));
+    for order in 1..=4 {
+        let k = b.k_statistic_axis(Axis(1), order).unwrap();
+        for (row, &k) in b.outer_iter().zip(&k) {
+            assert_eq!(k, row.k_statistic(order).unwrap());
+        }
+    }
+
+    assert_eq!(
+        array![1., 2., 3.].k_statistic(4),
+        Err(EstimatorError::NotEnoughElements { min_len: 4, len: 3 })
+    );
+    assert_eq!(
+        Array1::<f64>::zeros(0).k_statistic(1),
+        Err(EstimatorError::EmptyInput)
+    );
+    assert_eq!(
+        b.slice(s![..2, ..]).k_statistic_axis(Axis(0), 3),
+        Err(EstimatorError::NotEnoughElements { min_len: 3, len: 2 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_k_statistic_with_invalid_order() {
+    array![1., 2., 3., 4., 5.].k_statistic(5).unwrap();
+}
+
+#[test]
+fn test_l_moments() {
+    let a: Array1<f64> = Array::random(12, Uniform::new(-5., 5.));
+    let l_moments = a.l_moments(4).unwrap();
+    let n = a.len();
+
+    // λ₂ is half the mean absolute difference between two elements, and λ₃
+    // the mean of (x₃:₃ - 2x₂:₃ + x₁:₃) / 3 over all the triples of elements
+    let mut abs_differences = 0.;
+    let mut triples = 0.;
+    for i in 0..n {
+        for j in i + 1..n {
+            abs_differences += (a[i] - a[j]).abs();
+            for k in j + 1..n {
+                let mut triple = [a[i], a[j], a[k]];
+                triple.sort_by(|x, y| x.partial_cmp(y).unwrap());
+                triples += (triple[2] - 2. * triple[1] + triple[0]) / 3.;
+            }
+        }
+    }
+    let n_pairs = (n * (n - 1) / 2) as f64;
+    let n_triples = (n * (n - 1) * (n - 2) / 6) as f64;
+    assert_abs_diff_eq!(l_moments[0], a.mean().unwrap(), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        l_moments[1],
+        abs_differences / n_pairs / 2.,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(l_moments[2], triples / n_triples, epsilon = 1e-12);
+    assert_eq!(a.l_scale().unwrap(), l_moments[1]);
+    assert_abs_diff_eq!(a.l_skewness().unwrap(), l_moments[2] / l_moments[1]);
+    assert_abs_diff_eq!(a.l_kurtosis().unwrap(), l_moments[3] / l_moments[1]);
+}
+
+#[test]
+fn test_l_moments_of_uniform_sample() {
+    // For the uniform spacing, λ₂ = (n+1)/6 and the odd L-moments vanish
+    let a: Array1<f64> = Array::range(1., 11., 1.);
+    let l_moments = a.l_moments(5).unwrap();
+    assert_abs_diff_eq!(l_moments[0], 5.5, epsilon = 1e-12);
+    assert_abs_diff_eq!(l_moments[1], 11. / 6., epsilon = 1e-12);
+    assert_abs_diff_eq!(l_moments[2], 0., epsilon = 1e-12);
+    assert_abs_diff_eq!(l_moments[4], 0., epsilon = 1e-12);
+}
+
+#[test]
+fn test_l_moments_with_nan_and_errors() {
+    let a = array![3., f64::NAN, 1.];
+    assert!(a.l_moments(3).unwrap().iter().all(|x| x.is_nan()));
+    assert!(a.l_scale().unwrap().is_nan());
+
+    assert_eq!(
+        array![1., 2., 3.].l_kurtosis(),
+        Err(EstimatorError::NotEnoughElements { min_len: 4, len: 3 })
+    );
+    assert_eq!(
+        Array1::<f64>::zeros(0).l_scale(),
+        Err(EstimatorError::EmptyInput)
+    );
+    assert_eq!(
+        Array2::<f64>::zeros((3, 2)).l_moments_axis(Axis(1), 3),
+        Err(EstimatorError::NotEnoughElements { min_len: 3, len: 2 })
+    );
+}
+
+#[test]
+fn test_l_moments_axis_eq_lane_l_moments() {
+    let a: Array2<f64> = Array::random((5, 8), Uniform::new(-1., 1.));
+    for axis in 0..2 {
+        let l_moments = a.l_moments_axis(Axis(axis), 4).unwrap();
+        assert_eq!(l_moments.len_of(Axis(axis)), 4);
+        for (lane, l_moments) in a
+            .lanes(Axis(axis))
+            .into_iter()
+            .zip(l_moments.lanes(Axis(axis)))
+        {
+            assert_eq!(l_moments.to_vec(), lane.l_moments(4).unwrap());
+        }
+        let l_scales = a.l_scale_axis(Axis(axis)).unwrap();
+        let l_skewnesses = a.l_skewness_axis(Axis(axis)).unwrap();
+        let l_kurtoses = a.l_kurtosis_axis(Axis(axis)).unwrap();
+        for (i, lane) in a.lanes(Axis(axis)).into_iter().enumerate() {
+            assert_eq!(l_scales[i], lane.l_scale().unwrap());
+            assert_eq!(l_skewnesses[i], lane.l_skewness().unwrap());
+            assert_eq!(l_kurtoses[i], lane.l_kurtosis().unwrap());
+        }
+    }
+    assert_eq!(
+        Array2::<f64>::zeros((5, 3)).l_kurtosis_axis(Axis(1)),
+        Err(EstimatorError::NotEnoughElements { min_len: 4, len: 3 })
+    );
+    assert_eq!(
+        Array2::<f64>::zeros((0, 3)).l_skewness_axis(Axis(0)),
+        Err(EstimatorError::EmptyInput)
+    );
+}
+
+#[test]
+fn test_skipnan_eq_statistics_of_non_nan_values() {
+    let mut a: Array2<f64> = Array::random((4, 9), Uniform::new(-1., 1.));
+    a[(0, 3)] = f64::NAN;
+    a[(2, 0)] = f64::NAN;
+    a[(2, 8)] = f64::NAN;
+    let weights: Array1<f64> = Array::random(9, Uniform::new(0.5, 1.));
+    let not_nan = |row: ndarray::ArrayView1<'_, f64>| -> Array1<f64> {
+        row.iter().cloned().filter(|x| !x.is_nan()).collect()
+    };
+
+    let means = a.mean_axis_skipnan(Axis(1)).unwrap();
+    let vars = a.var_axis_skipnan(Axis(1), 1.).unwrap();
+    let stds = a.std_axis_skipnan(Axis(1), 0.).unwrap();
+    let weighted_means = a.weighted_mean_axis_skipnan(Axis(1), &weights).unwrap();
+    let central_moments = a.central_moments_axis_skipnan(Axis(1), 4).unwrap();
+    for (i, row) in a.outer_iter().enumerate() {
+        let values = not_nan(row);
+        let row_weights: Array1<f64> = row
+            .iter()
+            .zip(&weights)
+            .filter(|(x, _)| !x.is_nan())
+            .map(|(_, &w)| w)
+            .collect();
+        assert_abs_diff_eq!(means[i], values.mean().unwrap(), epsilon = 1e-12);
+        assert_abs_diff_eq!(vars[i], values.var(1.), epsilon = 1e-12);
+        assert_abs_diff_eq!(stds[i], values.std(0.), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            weighted_means[i],
+            values.weighted_mean(&row_weights).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            central_moments.row(i),
+            Array1::from(values.central_moments(4).unwrap()),
+            epsilon = 1e-12
+        );
+        assert_eq!(row.mean_skipnan().unwrap(), means[i]);
+        assert_eq!(row.var_skipnan(1.).unwrap(), vars[i]);
+        assert_eq!(row.std_skipnan(0.).unwrap(), stds[i]);
+        assert_eq!(
+            row.weighted_mean_skipnan(&weights).unwrap(),
+            weighted_means[i]
+        );
+        assert_eq!(
+            row.central_moments_skipnan(4).unwrap(),
+            central_moments.row(i).to_vec()
+        );
+    }
+}
+
+#[test]
+fn test_skipnan_with_nan_weights_and_options() {
+    let a = array![1., 2., f64::NAN, 4.];
+    let weights = array![1., f64::NAN, 1., 3.];
+    assert_eq!(a.weighted_mean_skipnan(&weights), Ok(13. / 4.));
+    assert_eq!(
+        a.weighted_mean_skipnan(&array![1., 2.]),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![4],
+            second_shape: vec![2],
+        }))
+    );
+
+    let b = array![Some(n64(1.)), None, Some(n64(3.)), Some(n64(5.))];
+    assert_eq!(b.mean_skipnan(), Ok(Some(n64(3.))));
+    assert_eq!(b.var_skipnan(Some(n64(1.))), Ok(Some(n64(4.))));
+    assert_eq!(b.std_skipnan(Some(n64(1.))), Ok(Some(n64(2.))));
+    assert_eq!(
+        b.central_moments_skipnan(2),
+        Ok(vec![Some(n64(1.)), Some(n64(0.)), Some(n64(8. / 3.))])
+    );
+    let c = array![[Some(2), None], [Some(4), None]];
+    assert_eq!(c.mean_axis_skipnan(Axis(0)), Ok(array![Some(3), None]));
+
+    // The intermediate values aren't truncated for integers, only the results
+    let d = array![Some(1), Some(4), None, Some(7)];
+    assert_eq!(d.var_skipnan(Some(1)), Ok(Some(9)));
+    assert_eq!(d.std_skipnan(Some(1)), Ok(Some(3)));
+    assert_eq!(
+        d.central_moments_skipnan(3),
+        Ok(vec![Some(1), Some(0), Some(6), Some(0)])
+    );
+    let e = array![[Some(1i32), Some(4), None], [None, Some(2), Some(5)]];
+    assert_eq!(
+        e.var_axis_skipnan(Axis(1), Some(0)),
+        Ok(array![Some(2), Some(2)])
+    );
+}
+
+#[test]
+fn test_skipnan_with_all_nan_and_empty_arrays() {
+    let a = array![f64::NAN, f64::NAN];
+    assert!(a.mean_skipnan().unwrap().is_nan());
+    assert!(a.var_skipnan(0.).unwrap().is_nan());
+    assert!(a.std_skipnan(0.).unwrap().is_nan());
+    assert!(a.weighted_mean_skipnan(&array![1., 1.]).unwrap().is_nan());
+    assert!(a
+        .central_moments_skipnan(3)
+        .unwrap()
+        .iter()
+        .all(|x| x.is_nan()));
+    // A single value has no sample variance
+    assert!(array![f64::NAN, 1.].var_skipnan(1.).unwrap().is_nan());
+
+    let empty: Array1<f64> = array![];
+    assert_eq!(empty.mean_skipnan(), Err(EmptyInput));
+    assert_eq!(empty.var_skipnan(1.), Err(EmptyInput));
+    assert_eq!(empty.central_moments_skipnan(2), Err(EmptyInput));
+    assert_eq!(
+        empty.weighted_mean_skipnan(&empty),
+        Err(MultiInputError::EmptyInput)
+    );
+    let empty_axis: Array2<f64> = Array2::zeros((3, 0));
+    assert_eq!(empty_axis.std_axis_skipnan(Axis(1), 0.), Err(EmptyInput));
+    assert_eq!(
+        empty_axis.central_moments_axis_skipnan(Axis(1), 2),
+        Err(EmptyInput)
+    );
+}
+
+#[test]
+fn test_masked_eq_compacted() {
+    let a = array![1., -999., 3., 5.];
+    let mask = a.mapv(|x| x == -999.);
+    let compacted = array![1., 3., 5.];
+    assert_eq!(a.masked_mean(&mask), Ok(compacted.mean().unwrap()));
+    assert_abs_diff_eq!(a.masked_var(&mask, 1.).unwrap(), compacted.var(1.));
+    assert_abs_diff_eq!(a.masked_std(&mask, 0.).unwrap(), compacted.std(0.));
+
+    // Each lane has its own number of unmasked elements
+    let b: Array2<f64> = array![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]];
+    let mask = array![
+        [false, true, true],
+        [false, false, true],
+        [true, true, true]
+    ];
+    let means = b.masked_mean_axis(Axis(1), &mask).unwrap();
+    assert_eq!(means.slice(s![..2]), array![1., 4.5]);
+    assert!(means[2].is_nan());
+    let vars = b.masked_var_axis(Axis(1), &mask, 0.).unwrap();
+    assert_eq!(vars.slice(s![..2]), array![0., 0.25]);
+    assert!(vars[2].is_nan());
+    let stds = b.masked_std_axis(Axis(0), &mask, 1.).unwrap();
+    assert_abs_diff_eq!(stds.slice(s![..1]), array![4.5f64.sqrt()]);
+    // A single unmasked element has no sample variance
+    assert!(stds[1].is_nan() && stds[2].is_nan());
+}
+
+#[test]
+fn test_masked_with_empty_or_mismatched_mask() {
+    let a: Array2<f64> = array![[1., 2.], [3., 4.]];
+    assert_eq!(
+        a.masked_mean(&array![[false, true]]),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2, 2],
+            second_shape: vec![1, 2],
+        }))
+    );
+    assert!(a.masked_var(&a.mapv(|_| true), 0.).unwrap().is_nan());
+
+    let empty: Array1<f64> = array![];
+    assert_eq!(
+        empty.masked_mean(&array![]),
+        Err(MultiInputError::EmptyInput)
+    );
+    let empty_axis: Array2<f64> = Array2::zeros((3, 0));
+    assert_eq!(
+        empty_axis.masked_std_axis(Axis(1), &empty_axis.mapv(|_| false), 1.),
+        Err(MultiInputError::EmptyInput)
+    );
+}